@@ -9,13 +9,24 @@
 export * from './amountToUiAmount';
 export * from './approve';
 export * from './approveChecked';
+export * from './approveWithExpiry';
+export * from './batch';
+export * from './bestEffortBatch';
 export * from './burn';
 export * from './burnChecked';
 export * from './closeAccount';
+export * from './closeDelegationRecord';
 export * from './createAssociatedToken';
 export * from './createAssociatedTokenIdempotent';
+export * from './decreaseAllowance';
+export * from './decreaseAllowanceChecked';
 export * from './freezeAccount';
 export * from './getAccountDataSize';
+export * from './getBalance';
+export * from './getMintInfo';
+export * from './increaseAllowance';
+export * from './increaseAllowanceChecked';
+export * from './indexedBatch';
 export * from './initializeAccount';
 export * from './initializeAccount2';
 export * from './initializeAccount3';
@@ -24,13 +35,18 @@ export * from './initializeMint';
 export * from './initializeMint2';
 export * from './initializeMultisig';
 export * from './initializeMultisig2';
+export * from './initializeMultisig3';
 export * from './mintTo';
 export * from './mintToChecked';
+export * from './mintToMany';
 export * from './recoverNestedAssociatedToken';
 export * from './revoke';
 export * from './setAuthority';
+export * from './setMultisigSigners';
 export * from './syncNative';
 export * from './thawAccount';
 export * from './transfer';
 export * from './transferChecked';
+export * from './transferMany';
 export * from './uiAmountToAmount';
+export * from './withdrawExcessLamports';
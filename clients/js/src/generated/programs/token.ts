@@ -16,11 +16,22 @@ import {
   type ParsedAmountToUiAmountInstruction,
   type ParsedApproveCheckedInstruction,
   type ParsedApproveInstruction,
+  type ParsedApproveWithExpiryInstruction,
+  type ParsedBatchInstruction,
+  type ParsedBestEffortBatchInstruction,
   type ParsedBurnCheckedInstruction,
   type ParsedBurnInstruction,
   type ParsedCloseAccountInstruction,
+  type ParsedCloseDelegationRecordInstruction,
+  type ParsedDecreaseAllowanceCheckedInstruction,
+  type ParsedDecreaseAllowanceInstruction,
   type ParsedFreezeAccountInstruction,
   type ParsedGetAccountDataSizeInstruction,
+  type ParsedGetBalanceInstruction,
+  type ParsedGetMintInfoInstruction,
+  type ParsedIncreaseAllowanceCheckedInstruction,
+  type ParsedIncreaseAllowanceInstruction,
+  type ParsedIndexedBatchInstruction,
   type ParsedInitializeAccount2Instruction,
   type ParsedInitializeAccount3Instruction,
   type ParsedInitializeAccountInstruction,
@@ -28,16 +39,21 @@ import {
   type ParsedInitializeMint2Instruction,
   type ParsedInitializeMintInstruction,
   type ParsedInitializeMultisig2Instruction,
+  type ParsedInitializeMultisig3Instruction,
   type ParsedInitializeMultisigInstruction,
   type ParsedMintToCheckedInstruction,
   type ParsedMintToInstruction,
+  type ParsedMintToManyInstruction,
   type ParsedRevokeInstruction,
   type ParsedSetAuthorityInstruction,
+  type ParsedSetMultisigSignersInstruction,
   type ParsedSyncNativeInstruction,
   type ParsedThawAccountInstruction,
   type ParsedTransferCheckedInstruction,
   type ParsedTransferInstruction,
+  type ParsedTransferManyInstruction,
   type ParsedUiAmountToAmountInstruction,
+  type ParsedWithdrawExcessLamportsInstruction,
 } from '../instructions';
 
 export const TOKEN_PROGRAM_ADDRESS =
@@ -93,6 +109,22 @@ export enum TokenInstruction {
   InitializeImmutableOwner,
   AmountToUiAmount,
   UiAmountToAmount,
+  WithdrawExcessLamports,
+  TransferMany,
+  MintToMany,
+  GetBalance,
+  GetMintInfo,
+  InitializeMultisig3,
+  SetMultisigSigners,
+  ApproveWithExpiry,
+  IncreaseAllowance,
+  DecreaseAllowance,
+  IncreaseAllowanceChecked,
+  DecreaseAllowanceChecked,
+  CloseDelegationRecord,
+  BestEffortBatch,
+  IndexedBatch,
+  Batch,
 }
 
 export function identifyTokenInstruction(
@@ -174,6 +206,54 @@ export function identifyTokenInstruction(
   if (containsBytes(data, getU8Encoder().encode(24), 0)) {
     return TokenInstruction.UiAmountToAmount;
   }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return TokenInstruction.WithdrawExcessLamports;
+  }
+  if (containsBytes(data, getU8Encoder().encode(252), 0)) {
+    return TokenInstruction.TransferMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(251), 0)) {
+    return TokenInstruction.MintToMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(250), 0)) {
+    return TokenInstruction.GetBalance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(249), 0)) {
+    return TokenInstruction.GetMintInfo;
+  }
+  if (containsBytes(data, getU8Encoder().encode(248), 0)) {
+    return TokenInstruction.InitializeMultisig3;
+  }
+  if (containsBytes(data, getU8Encoder().encode(247), 0)) {
+    return TokenInstruction.SetMultisigSigners;
+  }
+  if (containsBytes(data, getU8Encoder().encode(246), 0)) {
+    return TokenInstruction.ApproveWithExpiry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(245), 0)) {
+    return TokenInstruction.IncreaseAllowance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(244), 0)) {
+    return TokenInstruction.DecreaseAllowance;
+  }
+  if (containsBytes(data, getU8Encoder().encode(243), 0)) {
+    return TokenInstruction.IncreaseAllowanceChecked;
+  }
+  if (containsBytes(data, getU8Encoder().encode(242), 0)) {
+    return TokenInstruction.DecreaseAllowanceChecked;
+  }
+  if (containsBytes(data, getU8Encoder().encode(241), 0)) {
+    return TokenInstruction.CloseDelegationRecord;
+  }
+  if (containsBytes(data, getU8Encoder().encode(253), 0)) {
+    return TokenInstruction.BestEffortBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(254), 0)) {
+    return TokenInstruction.IndexedBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(255), 0)) {
+    return TokenInstruction.Batch;
+  }
   throw new Error(
     'The provided instruction could not be identified as a token instruction.'
   );
@@ -256,4 +336,52 @@ export type ParsedTokenInstruction<
     } & ParsedAmountToUiAmountInstruction<TProgram>)
   | ({
       instructionType: TokenInstruction.UiAmountToAmount;
-    } & ParsedUiAmountToAmountInstruction<TProgram>);
+    } & ParsedUiAmountToAmountInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.WithdrawExcessLamports;
+    } & ParsedWithdrawExcessLamportsInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.TransferMany;
+    } & ParsedTransferManyInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.MintToMany;
+    } & ParsedMintToManyInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.GetBalance;
+    } & ParsedGetBalanceInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.GetMintInfo;
+    } & ParsedGetMintInfoInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.InitializeMultisig3;
+    } & ParsedInitializeMultisig3Instruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.SetMultisigSigners;
+    } & ParsedSetMultisigSignersInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.ApproveWithExpiry;
+    } & ParsedApproveWithExpiryInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.IncreaseAllowance;
+    } & ParsedIncreaseAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.DecreaseAllowance;
+    } & ParsedDecreaseAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.IncreaseAllowanceChecked;
+    } & ParsedIncreaseAllowanceCheckedInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.DecreaseAllowanceChecked;
+    } & ParsedDecreaseAllowanceCheckedInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.CloseDelegationRecord;
+    } & ParsedCloseDelegationRecordInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.BestEffortBatch;
+    } & ParsedBestEffortBatchInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.IndexedBatch;
+    } & ParsedIndexedBatchInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.Batch;
+    } & ParsedBatchInstruction<TProgram>);
@@ -0,0 +1,72 @@
+//! Types that cannot be generated from the IDL.
+
+use {
+    borsh::{
+        maybestd::io::{Error, ErrorKind, Read, Result, Write},
+        BorshDeserialize, BorshSerialize,
+    },
+    solana_program::pubkey::Pubkey,
+};
+
+/// Tag of a `COption` that contains a value.
+const SOME_TAG: [u8; 4] = [1, 0, 0, 0];
+
+/// Tag of a `COption` that does not contain a value.
+const NONE_TAG: [u8; 4] = [0; 4];
+
+/// An optional value with the layout of a `COption` in the token accounts.
+///
+/// The value is prefixed with a `u32` tag and always occupies its space, so a
+/// `None` is followed by zeroed bytes. Borsh options use a `u8` tag instead
+/// and omit absent values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct COption<T>(pub Option<T>);
+
+/// A `COption` of a public key.
+pub type COptionPubkey = COption<Pubkey>;
+
+/// A `COption` of a `u64`.
+pub type COptionU64 = COption<u64>;
+
+impl<T> From<Option<T>> for COption<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<COption<T>> for Option<T> {
+    fn from(value: COption<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: BorshSerialize + Default> BorshSerialize for COption<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match &self.0 {
+            Some(value) => {
+                writer.write_all(&SOME_TAG)?;
+                value.serialize(writer)
+            }
+            None => {
+                writer.write_all(&NONE_TAG)?;
+                T::default().serialize(writer)
+            }
+        }
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for COption<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut tag = [0; 4];
+        reader.read_exact(&mut tag)?;
+        // The value is present even when the option is empty.
+        let value = T::deserialize_reader(reader)?;
+
+        match tag {
+            SOME_TAG => Ok(Self(Some(value))),
+            NONE_TAG => Ok(Self(None)),
+            _ => Err(Error::new(ErrorKind::InvalidData, "invalid COption tag")),
+        }
+    }
+}
@@ -1,6 +1,7 @@
-/*
 mod generated;
+mod hooked;
 
-pub use generated::programs::TOKEN_ID as ID;
-pub use generated::*;
-*/
+pub use {
+    generated::{programs::TOKEN_ID as ID, *},
+    hooked::*,
+};
@@ -0,0 +1,912 @@
+//! Checks that the instructions of `program/idl.json` match the instructions of
+//! the `spl-token-interface` and `pinocchio-token-interface` crates.
+//!
+//! Each instruction of the IDL is encoded with sample arguments, following the
+//! type nodes of the IDL, and compared with the same instruction created by
+//! each crate, so any divergence in discriminators, account order,
+//! signer/writable flags or argument layouts fails.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+use {
+    core::mem::{size_of, transmute, MaybeUninit},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError},
+    pinocchio_token_interface::{
+        cpi::{
+            self, CpiAccounts, CpiData, CpiInstruction, MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN,
+        },
+        instruction as p_instruction,
+        state::delegation::ExpiryKind,
+    },
+    serde_json::{json, Value},
+    solana_pubkey::Pubkey,
+    spl_token_interface::instruction,
+    std::{collections::BTreeSet, str::FromStr},
+};
+
+/// Account meta of an instruction as `(pubkey, is_signer, is_writable)`.
+type Meta = ([u8; 32], bool, bool);
+
+/// Seeds of the keys of the accounts provided as remaining accounts, other
+/// than multisignature signers.
+const REMAINING_ACCOUNTS: [u8; 2] = [50, 51];
+
+/// Seeds of the keys of the signers of a multisignature authority.
+const MULTISIG_SIGNERS: [u8; 2] = [100, 101];
+
+const AMOUNT: u64 = 42;
+
+/// Amounts of `TransferMany` and `MintToMany`, one for each remaining account.
+const AMOUNTS: [u64; 2] = [42, 43];
+
+const DECIMALS: u8 = 6;
+
+const M: u8 = 2;
+
+const ALLOW_NESTED: bool = true;
+const EXPIRY: u64 = 1_000;
+
+const MINT_AUTHORITY: [u8; 32] = [20; 32];
+
+const FREEZE_AUTHORITY: [u8; 32] = [21; 32];
+
+const NEW_AUTHORITY: [u8; 32] = [22; 32];
+
+const OWNER: [u8; 32] = [23; 32];
+
+const UI_AMOUNT: &str = "1.5";
+
+/// Data of a batch with a `GetAccountDataSize` instruction on the first
+/// remaining account.
+const BATCH_DATA: [u8; 3] = [1, 1, 21];
+
+/// Data of an indexed batch with a `GetAccountDataSize` instruction on the
+/// first remaining account.
+const INDEXED_BATCH_DATA: [u8; 4] = [1, 1, 0, 21];
+
+fn idl() -> Value {
+    serde_json::from_str(include_str!("../../../program/idl.json")).unwrap()
+}
+
+fn instructions(idl: &Value) -> &[Value] {
+    idl["program"]["instructions"].as_array().unwrap()
+}
+
+fn program_id(idl: &Value) -> [u8; 32] {
+    Pubkey::from_str(idl["program"]["publicKey"].as_str().unwrap())
+        .unwrap()
+        .to_bytes()
+}
+
+fn pascal_case(name: &str) -> String {
+    name[..1].to_uppercase() + &name[1..]
+}
+
+/// Returns the name of the variant in the `Debug` output of an enum value.
+fn variant_name(value: &impl core::fmt::Debug) -> String {
+    let debug = format!("{value:?}");
+    debug.split([' ', '(']).next().unwrap().to_string()
+}
+
+fn discriminator(node: &Value) -> u8 {
+    let argument = node["arguments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|argument| argument["name"] == "discriminator")
+        .unwrap();
+    assert_eq!(argument["defaultValueStrategy"], "omitted");
+    argument["defaultValue"]["number"].as_u64().unwrap() as u8
+}
+
+/// Returns whether the instruction has an authority that can be a
+/// multisignature account.
+fn has_multisig_authority(node: &Value) -> bool {
+    node["accounts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|account| account["isSigner"] == "either")
+}
+
+/// Sample value of an argument of an instruction.
+fn sample(instruction: &str, argument: &str) -> Value {
+    match (instruction, argument) {
+        (_, "amount") => json!(AMOUNT),
+        (_, "amounts") => json!(AMOUNTS),
+        ("setMultisigSigners", "n") => json!(REMAINING_ACCOUNTS.len()),
+        (_, "decimals") => json!(DECIMALS),
+        (_, "m") => json!(M),
+        (_, "allowNested") => json!(ALLOW_NESTED),
+        (_, "mintAuthority") => json!(MINT_AUTHORITY),
+        (_, "freezeAuthority") => json!(FREEZE_AUTHORITY),
+        (_, "newAuthority") => json!(NEW_AUTHORITY),
+        (_, "owner") => json!(OWNER),
+        (_, "authorityType") => json!("closeAccount"),
+        (_, "expiryKind") => json!("unixTimestamp"),
+        (_, "expiry") => json!(EXPIRY),
+        (_, "uiAmount") => json!(UI_AMOUNT),
+        ("batch" | "bestEffortBatch", "data") => json!(BATCH_DATA),
+        ("indexedBatch", "data") => json!(INDEXED_BATCH_DATA),
+        _ => panic!("missing sample value for `{argument}` of `{instruction}`"),
+    }
+}
+
+/// Encodes `value` following the type node `ty`.
+fn encode(idl: &Value, ty: &Value, value: &Value, data: &mut Vec<u8>) {
+    match ty["kind"].as_str().unwrap() {
+        "numberTypeNode" => {
+            assert_eq!(ty["endian"], "le");
+            let len = match ty["format"].as_str().unwrap() {
+                "u8" => 1,
+                "u16" => 2,
+                "u32" => 4,
+                "u64" => 8,
+                format => panic!("unsupported number format `{format}`"),
+            };
+            data.extend_from_slice(&value.as_u64().unwrap().to_le_bytes()[..len]);
+        }
+        "booleanTypeNode" => {
+            let value = json!(value.as_bool().unwrap() as u8);
+            encode(idl, &ty["size"], &value, data);
+        }
+        "publicKeyTypeNode" => encode_bytes(value, data),
+        "optionTypeNode" => {
+            assert_eq!(ty["fixed"], false);
+            if value.is_null() {
+                encode(idl, &ty["prefix"], &json!(0), data);
+            } else {
+                encode(idl, &ty["prefix"], &json!(1), data);
+                encode(idl, &ty["item"], value, data);
+            }
+        }
+        "definedTypeLinkNode" => {
+            let defined_type = &idl["program"]["definedTypes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|defined_type| defined_type["name"] == ty["name"])
+                .unwrap()["type"];
+            assert_eq!(defined_type["kind"], "enumTypeNode");
+            let variant = defined_type["variants"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .position(|variant| variant["name"] == *value)
+                .unwrap();
+            encode(idl, &defined_type["size"], &json!(variant), data);
+        }
+        "stringTypeNode" => {
+            assert_eq!(ty["encoding"], "utf8");
+            data.extend_from_slice(value.as_str().unwrap().as_bytes());
+        }
+        "bytesTypeNode" => encode_bytes(value, data),
+        "arrayTypeNode" => {
+            assert_eq!(ty["count"]["kind"], "remainderCountNode");
+            for item in value.as_array().unwrap() {
+                encode(idl, &ty["item"], item, data);
+            }
+        }
+        kind => panic!("unsupported type node `{kind}`"),
+    }
+}
+
+fn encode_bytes(value: &Value, data: &mut Vec<u8>) {
+    data.extend(
+        value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|byte| byte.as_u64().unwrap() as u8),
+    );
+}
+
+/// Creates the accounts and data of an instruction of the IDL.
+///
+/// The `n`-th account has the key `[n + 1; 32]`, unless it defaults to a
+/// fixed public key.
+fn idl_instruction(idl: &Value, node: &Value, multisig: bool) -> (Vec<Meta>, Vec<u8>) {
+    let name = node["name"].as_str().unwrap();
+    let mut accounts = Vec::new();
+
+    for (index, account) in node["accounts"].as_array().unwrap().iter().enumerate() {
+        let key = match account["defaultValue"]["kind"].as_str() {
+            Some("publicKeyValueNode") => {
+                Pubkey::from_str(account["defaultValue"]["publicKey"].as_str().unwrap())
+                    .unwrap()
+                    .to_bytes()
+            }
+            _ => [index as u8 + 1; 32],
+        };
+        let is_signer = match &account["isSigner"] {
+            Value::Bool(is_signer) => *is_signer,
+            signer if signer == "either" => !multisig,
+            signer => panic!("invalid signer `{signer}` of `{name}`"),
+        };
+        accounts.push((key, is_signer, account["isWritable"].as_bool().unwrap()));
+    }
+
+    for remaining in node["remainingAccounts"].as_array().into_iter().flatten() {
+        let seeds: &[u8] = match remaining["value"]["name"].as_str().unwrap() {
+            "multiSigners" if multisig => &MULTISIG_SIGNERS,
+            "multiSigners" => &[],
+            "signers" | "destinations" | "tokens" | "newSigners" => &REMAINING_ACCOUNTS,
+            "currentSigners" => &MULTISIG_SIGNERS,
+            // Accounts of the batched `GetAccountDataSize` instruction.
+            "accounts" => &REMAINING_ACCOUNTS[..1],
+            value => panic!("unexpected remaining accounts `{value}` of `{name}`"),
+        };
+        let is_signer = remaining["isSigner"].as_bool().unwrap_or(false);
+        let is_writable = remaining["isWritable"].as_bool().unwrap_or(false);
+        accounts.extend(
+            seeds
+                .iter()
+                .map(|seed| ([*seed; 32], is_signer, is_writable)),
+        );
+    }
+
+    let mut data = Vec::new();
+
+    for argument in node["arguments"].as_array().unwrap() {
+        let value = if argument["defaultValueStrategy"] == "omitted" {
+            argument["defaultValue"]["number"].clone()
+        } else {
+            sample(name, argument["name"].as_str().unwrap())
+        };
+        encode(idl, &argument["type"], &value, &mut data);
+    }
+
+    (accounts, data)
+}
+
+/// Creates an instruction with the builders of `spl-token-interface`.
+fn interface_instruction(name: &str, multisig: bool) -> solana_instruction::Instruction {
+    let program_id = spl_token_interface::id();
+    let [account1, account2, account3, account4, account5] =
+        [1, 2, 3, 4, 5].map(|seed| Pubkey::from([seed; 32]));
+    let [remaining1, remaining2] = REMAINING_ACCOUNTS.map(|seed| Pubkey::from([seed; 32]));
+    let [mint_authority, freeze_authority, new_authority, owner] =
+        [MINT_AUTHORITY, FREEZE_AUTHORITY, NEW_AUTHORITY, OWNER].map(Pubkey::from);
+
+    let multisig_signers = MULTISIG_SIGNERS.map(|seed| Pubkey::from([seed; 32]));
+    let signers = if multisig {
+        multisig_signers.iter().collect()
+    } else {
+        vec![]
+    };
+    let destinations = [(&remaining1, AMOUNTS[0]), (&remaining2, AMOUNTS[1])];
+    let batched = || vec![instruction::get_account_data_size(&program_id, &remaining1).unwrap()];
+
+    match name {
+        "initializeMint" => instruction::initialize_mint(
+            &program_id,
+            &account1,
+            &mint_authority,
+            Some(&freeze_authority),
+            DECIMALS,
+        ),
+        "initializeAccount" => {
+            instruction::initialize_account(&program_id, &account1, &account2, &account3)
+        }
+        "initializeMultisig" => {
+            instruction::initialize_multisig(&program_id, &account1, &[&remaining1, &remaining2], M)
+        }
+        "transfer" => instruction::transfer(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "approve" => instruction::approve(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "revoke" => instruction::revoke(&program_id, &account1, &account2, &signers),
+        "setAuthority" => instruction::set_authority(
+            &program_id,
+            &account1,
+            Some(&new_authority),
+            instruction::AuthorityType::CloseAccount,
+            &account2,
+            &signers,
+        ),
+        "mintTo" => instruction::mint_to(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "burn" => instruction::burn(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "closeAccount" => {
+            instruction::close_account(&program_id, &account1, &account2, &account3, &signers)
+        }
+        "freezeAccount" => {
+            instruction::freeze_account(&program_id, &account1, &account2, &account3, &signers)
+        }
+        "thawAccount" => {
+            instruction::thaw_account(&program_id, &account1, &account2, &account3, &signers)
+        }
+        "transferChecked" => instruction::transfer_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "approveChecked" => instruction::approve_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "mintToChecked" => instruction::mint_to_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "burnChecked" => instruction::burn_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "initializeAccount2" => {
+            instruction::initialize_account2(&program_id, &account1, &account2, &owner)
+        }
+        "syncNative" => instruction::sync_native(&program_id, &account1),
+        "initializeAccount3" => {
+            instruction::initialize_account3(&program_id, &account1, &account2, &owner)
+        }
+        "initializeMultisig2" => instruction::initialize_multisig2(
+            &program_id,
+            &account1,
+            &[&remaining1, &remaining2],
+            M,
+        ),
+        "initializeMint2" => instruction::initialize_mint2(
+            &program_id,
+            &account1,
+            &mint_authority,
+            Some(&freeze_authority),
+            DECIMALS,
+        ),
+        "getAccountDataSize" => instruction::get_account_data_size(&program_id, &account1),
+        "initializeImmutableOwner" => {
+            instruction::initialize_immutable_owner(&program_id, &account1)
+        }
+        "amountToUiAmount" => instruction::amount_to_ui_amount(&program_id, &account1, AMOUNT),
+        "uiAmountToAmount" => instruction::ui_amount_to_amount(&program_id, &account1, UI_AMOUNT),
+        "withdrawExcessLamports" => instruction::withdraw_excess_lamports(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+        ),
+        "transferMany" => instruction::transfer_many(
+            &program_id,
+            &account1,
+            &account2,
+            &destinations,
+            &account3,
+            &signers,
+            DECIMALS,
+        ),
+        "getBalance" => instruction::get_balance(&program_id, &account1),
+        "getMintInfo" => instruction::get_mint_info(&program_id, &account1),
+        "initializeMultisig3" => instruction::initialize_multisig3(
+            &program_id,
+            &account1,
+            &[&remaining1, &remaining2],
+            M,
+            ALLOW_NESTED,
+        ),
+        "approveWithExpiry" => instruction::approve_with_expiry(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &account5,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+            spl_token_interface::state::Expiry::UnixTimestamp(EXPIRY as i64),
+        ),
+        "increaseAllowance" => instruction::increase_allowance(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "decreaseAllowance" => instruction::decrease_allowance(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &signers,
+            AMOUNT,
+        ),
+        "increaseAllowanceChecked" => instruction::increase_allowance_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "decreaseAllowanceChecked" => instruction::decrease_allowance_checked(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &signers,
+            AMOUNT,
+            DECIMALS,
+        ),
+        "closeDelegationRecord" => instruction::close_delegation_record(
+            &program_id,
+            &account1,
+            &account2,
+            &account3,
+            &account4,
+            &signers,
+        ),
+        "setMultisigSigners" => instruction::set_multisig_signers(
+            &program_id,
+            &account1,
+            &[&remaining1, &remaining2],
+            M,
+            &multisig_signers.iter().collect::<Vec<_>>(),
+        ),
+        "mintToMany" => instruction::mint_to_many(
+            &program_id,
+            &account1,
+            &destinations,
+            &account2,
+            &signers,
+            DECIMALS,
+        ),
+        "bestEffortBatch" => instruction::best_effort_batch(&program_id, &batched()),
+        "indexedBatch" => instruction::indexed_batch(&program_id, &batched()),
+        "batch" => instruction::batch(&program_id, &batched()),
+        _ => panic!("`{name}` is missing from spl-token-interface"),
+    }
+    .unwrap()
+}
+
+/// Backing memory of an `AccountInfo`, laid out as in the input buffer of a
+/// program.
+struct TestAccount([u64; 88 / size_of::<u64>()]);
+
+impl TestAccount {
+    fn new(key: [u8; 32]) -> Self {
+        let mut account = Self([0; 88 / size_of::<u64>()]);
+        // SAFETY: the header has 88 bytes.
+        let bytes =
+            unsafe { core::slice::from_raw_parts_mut(account.0.as_mut_ptr() as *mut u8, 88) };
+        // Not borrowed.
+        bytes[0] = u8::MAX;
+        bytes[8..40].copy_from_slice(&key);
+        account
+    }
+
+    fn info(&mut self) -> AccountInfo {
+        // SAFETY: `AccountInfo` is a pointer to the account header.
+        unsafe { transmute::<*mut u64, AccountInfo>(self.0.as_mut_ptr()) }
+    }
+}
+
+/// Writes an instruction of `pinocchio-token-interface`, returning its accounts
+/// and its data.
+fn write(instruction: &dyn CpiInstruction<'_>) -> Result<(Vec<Meta>, Vec<u8>), ProgramError> {
+    let mut metas = [const { MaybeUninit::uninit() }; MAX_DYNAMIC_ACCOUNTS];
+    let mut infos = [MaybeUninit::uninit(); MAX_DYNAMIC_ACCOUNTS];
+    let mut buffer = [MaybeUninit::uninit(); MAX_DYNAMIC_DATA_LEN];
+
+    let mut accounts = CpiAccounts::new(&mut metas, &mut infos);
+    let mut data = CpiData::new(&mut buffer);
+    instruction.write(&mut accounts, &mut data)?;
+
+    let accounts = accounts
+        .metas()
+        .iter()
+        .map(|meta| (*meta.pubkey, meta.is_signer, meta.is_writable))
+        .collect();
+
+    Ok((accounts, data.as_slice().to_vec()))
+}
+
+/// Creates an instruction with the CPI helpers of `pinocchio-token-interface`.
+fn p_interface_instruction(name: &str, multisig: bool) -> (Vec<Meta>, Vec<u8>) {
+    let rent_sysvar_id = Pubkey::from_str("SysvarRent111111111111111111111111111111111")
+        .unwrap()
+        .to_bytes();
+    let mut backing = [1, 2, 3, 4, 5]
+        .into_iter()
+        .chain(REMAINING_ACCOUNTS)
+        .chain(MULTISIG_SIGNERS)
+        .map(|seed| TestAccount::new([seed; 32]))
+        .chain([TestAccount::new(rent_sysvar_id)])
+        .collect::<Vec<_>>();
+    let infos = backing
+        .iter_mut()
+        .map(TestAccount::info)
+        .collect::<Vec<_>>();
+    let account = |key: [u8; 32]| infos.iter().find(|info| *info.key() == key).unwrap();
+
+    let [account1, account2, account3, account4, account5] =
+        [1, 2, 3, 4, 5].map(|seed| account([seed; 32]));
+    let remaining = REMAINING_ACCOUNTS.map(|seed| account([seed; 32]));
+    let rent_sysvar = account(rent_sysvar_id);
+    let signers = if multisig {
+        MULTISIG_SIGNERS.map(|seed| account([seed; 32])).to_vec()
+    } else {
+        vec![]
+    };
+    let batched = cpi::GetAccountDataSize { mint: remaining[0] };
+
+    match name {
+        "initializeMint" => write(&cpi::InitializeMint {
+            mint: account1,
+            rent_sysvar,
+            decimals: DECIMALS,
+            mint_authority: &MINT_AUTHORITY,
+            freeze_authority: Some(&FREEZE_AUTHORITY),
+        }),
+        "initializeAccount" => write(&cpi::InitializeAccount {
+            account: account1,
+            mint: account2,
+            owner: account3,
+            rent_sysvar,
+        }),
+        "initializeMultisig" => write(&cpi::InitializeMultisig {
+            multisig: account1,
+            rent_sysvar,
+            signers: &remaining,
+            m: M,
+        }),
+        "transfer" => write(&cpi::Transfer {
+            from: account1,
+            to: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "approve" => write(&cpi::Approve {
+            source: account1,
+            delegate: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "revoke" => write(&cpi::Revoke {
+            source: account1,
+            authority: account2,
+            multisig_signers: &signers,
+        }),
+        "setAuthority" => write(&cpi::SetAuthority {
+            account: account1,
+            authority: account2,
+            multisig_signers: &signers,
+            authority_type: p_instruction::AuthorityType::CloseAccount,
+            new_authority: Some(&NEW_AUTHORITY),
+        }),
+        "mintTo" => write(&cpi::MintTo {
+            mint: account1,
+            account: account2,
+            mint_authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "burn" => write(&cpi::Burn {
+            account: account1,
+            mint: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "closeAccount" => write(&cpi::CloseAccount {
+            account: account1,
+            destination: account2,
+            authority: account3,
+            multisig_signers: &signers,
+        }),
+        "freezeAccount" => write(&cpi::FreezeAccount {
+            account: account1,
+            mint: account2,
+            freeze_authority: account3,
+            multisig_signers: &signers,
+        }),
+        "thawAccount" => write(&cpi::ThawAccount {
+            account: account1,
+            mint: account2,
+            freeze_authority: account3,
+            multisig_signers: &signers,
+        }),
+        "transferChecked" => write(&cpi::TransferChecked {
+            from: account1,
+            mint: account2,
+            to: account3,
+            authority: account4,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "approveChecked" => write(&cpi::ApproveChecked {
+            source: account1,
+            mint: account2,
+            delegate: account3,
+            authority: account4,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "mintToChecked" => write(&cpi::MintToChecked {
+            mint: account1,
+            account: account2,
+            mint_authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "burnChecked" => write(&cpi::BurnChecked {
+            account: account1,
+            mint: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "initializeAccount2" => write(&cpi::InitializeAccount2 {
+            account: account1,
+            mint: account2,
+            rent_sysvar,
+            owner: &OWNER,
+        }),
+        "syncNative" => write(&cpi::SyncNative {
+            native_token: account1,
+        }),
+        "initializeAccount3" => write(&cpi::InitializeAccount3 {
+            account: account1,
+            mint: account2,
+            owner: &OWNER,
+        }),
+        "initializeMultisig2" => write(&cpi::InitializeMultisig2 {
+            multisig: account1,
+            signers: &remaining,
+            m: M,
+        }),
+        "initializeMint2" => write(&cpi::InitializeMint2 {
+            mint: account1,
+            decimals: DECIMALS,
+            mint_authority: &MINT_AUTHORITY,
+            freeze_authority: Some(&FREEZE_AUTHORITY),
+        }),
+        "getAccountDataSize" => write(&cpi::GetAccountDataSize { mint: account1 }),
+        "initializeImmutableOwner" => write(&cpi::InitializeImmutableOwner { account: account1 }),
+        "amountToUiAmount" => write(&cpi::AmountToUiAmount {
+            mint: account1,
+            amount: AMOUNT,
+        }),
+        "uiAmountToAmount" => write(&cpi::UiAmountToAmount {
+            mint: account1,
+            ui_amount: UI_AMOUNT,
+        }),
+        "withdrawExcessLamports" => write(&cpi::WithdrawExcessLamports {
+            source: account1,
+            destination: account2,
+            authority: account3,
+            multisig_signers: &signers,
+        }),
+        "transferMany" => write(&cpi::TransferMany {
+            from: account1,
+            mint: account2,
+            authority: account3,
+            to: &remaining,
+            multisig_signers: &signers,
+            amounts: &AMOUNTS,
+            decimals: DECIMALS,
+        }),
+        "getBalance" => write(&cpi::GetBalance { account: account1 }),
+        "getMintInfo" => write(&cpi::GetMintInfo { mint: account1 }),
+        "initializeMultisig3" => write(&cpi::InitializeMultisig3 {
+            multisig: account1,
+            signers: &remaining,
+            m: M,
+            allow_nested: ALLOW_NESTED,
+        }),
+        "approveWithExpiry" => write(&cpi::ApproveWithExpiry {
+            source: account1,
+            mint: account2,
+            delegation_record: account3,
+            delegate: account4,
+            authority: account5,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+            expiry_kind: ExpiryKind::UnixTimestamp,
+            expiry: EXPIRY,
+        }),
+        "increaseAllowance" => write(&cpi::IncreaseAllowance {
+            source: account1,
+            delegate: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "decreaseAllowance" => write(&cpi::DecreaseAllowance {
+            source: account1,
+            delegate: account2,
+            authority: account3,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+        }),
+        "increaseAllowanceChecked" => write(&cpi::IncreaseAllowanceChecked {
+            source: account1,
+            mint: account2,
+            delegate: account3,
+            authority: account4,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "decreaseAllowanceChecked" => write(&cpi::DecreaseAllowanceChecked {
+            source: account1,
+            mint: account2,
+            delegate: account3,
+            authority: account4,
+            multisig_signers: &signers,
+            amount: AMOUNT,
+            decimals: DECIMALS,
+        }),
+        "closeDelegationRecord" => write(&cpi::CloseDelegationRecord {
+            delegation_record: account1,
+            account: account2,
+            destination: account3,
+            authority: account4,
+            multisig_signers: &signers,
+        }),
+        "setMultisigSigners" => write(&cpi::SetMultisigSigners {
+            multisig: account1,
+            new_signers: &remaining,
+            m: M,
+            multisig_signers: &MULTISIG_SIGNERS.map(|seed| account([seed; 32])),
+        }),
+        "mintToMany" => write(&cpi::MintToMany {
+            mint: account1,
+            mint_authority: account2,
+            to: &remaining,
+            multisig_signers: &signers,
+            amounts: &AMOUNTS,
+            decimals: DECIMALS,
+        }),
+        "bestEffortBatch" => write(&cpi::BestEffortBatch {
+            instructions: &[&batched],
+        }),
+        "indexedBatch" => write(&cpi::IndexedBatch {
+            instructions: &[&batched],
+        }),
+        "batch" => write(&cpi::Batch {
+            instructions: &[&batched],
+        }),
+        _ => panic!("`{name}` is missing from pinocchio-token-interface"),
+    }
+    .unwrap()
+}
+
+#[test]
+fn test_idl_matches_interface() {
+    let idl = idl();
+
+    for node in instructions(&idl) {
+        let name = node["name"].as_str().unwrap();
+
+        for multisig in [false, true] {
+            if multisig && !has_multisig_authority(node) {
+                continue;
+            }
+
+            let (accounts, data) = idl_instruction(&idl, node, multisig);
+            let instruction = interface_instruction(name, multisig);
+
+            assert_eq!(instruction.program_id.to_bytes(), program_id(&idl));
+            assert_eq!(
+                instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| (meta.pubkey.to_bytes(), meta.is_signer, meta.is_writable))
+                    .collect::<Vec<_>>(),
+                accounts,
+                "accounts of `{name}`"
+            );
+            assert_eq!(instruction.data, data, "data of `{name}`");
+
+            // The data unpacks to the instruction of the same name and packs
+            // back to the same bytes.
+            let unpacked = instruction::TokenInstruction::unpack(&data).unwrap();
+            assert_eq!(variant_name(&unpacked), pascal_case(name));
+            assert_eq!(unpacked.pack(), data, "layout of `{name}`");
+        }
+    }
+}
+
+#[test]
+fn test_idl_matches_p_interface() {
+    let idl = idl();
+
+    for node in instructions(&idl) {
+        let name = node["name"].as_str().unwrap();
+
+        let instruction = p_instruction::TokenInstruction::try_from(discriminator(node)).unwrap();
+        assert_eq!(variant_name(&instruction), pascal_case(name));
+
+        for multisig in [false, true] {
+            if multisig && !has_multisig_authority(node) {
+                continue;
+            }
+
+            let (accounts, data) = idl_instruction(&idl, node, multisig);
+            assert_eq!(
+                p_interface_instruction(name, multisig),
+                (accounts, data.clone()),
+                "`{name}`"
+            );
+
+            let parsed = p_instruction::parse(&data).unwrap();
+            assert_eq!(variant_name(&parsed), pascal_case(name));
+        }
+    }
+}
+
+#[test]
+fn test_idl_covers_p_interface() {
+    let idl = idl();
+    let discriminators = instructions(&idl)
+        .iter()
+        .map(discriminator)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(discriminators.len(), instructions(&idl).len());
+
+    for discriminator in 0..=u8::MAX {
+        assert_eq!(
+            p_instruction::TokenInstruction::try_from(discriminator).is_ok(),
+            discriminators.contains(&discriminator),
+            "discriminator {discriminator}"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Checks that the generated client matches the `spl-token-interface` crate.
+
+use {
+    borsh::BorshDeserialize,
+    kaigan::types::RemainderVec,
+    solana_program::{instruction::Instruction, pubkey::Pubkey},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    spl_token_client::{
+        accounts::{Mint, Multisig, Token},
+        instructions::{
+            BatchBuilder, InitializeMintBuilder, SetAuthorityBuilder, TransferBuilder,
+            TransferCheckedBuilder, UiAmountToAmountBuilder, WithdrawExcessLamportsBuilder,
+        },
+        types::{AccountState, AuthorityType},
+        COption as ClientCOption,
+    },
+    spl_token_interface::{instruction, state},
+};
+
+type InterfacePubkey = solana_pubkey::Pubkey;
+
+/// Account meta of an instruction as `(pubkey, is_signer, is_writable)`.
+type Meta = ([u8; 32], bool, bool);
+
+fn key(seed: u8) -> Pubkey {
+    Pubkey::new_from_array([seed; 32])
+}
+
+fn interface_key(seed: u8) -> InterfacePubkey {
+    InterfacePubkey::new_from_array([seed; 32])
+}
+
+fn client_parts(instruction: Instruction) -> ([u8; 32], Vec<Meta>, Vec<u8>) {
+    (
+        instruction.program_id.to_bytes(),
+        instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey.to_bytes(), meta.is_signer, meta.is_writable))
+            .collect(),
+        instruction.data,
+    )
+}
+
+fn interface_parts(instruction: solana_instruction::Instruction) -> ([u8; 32], Vec<Meta>, Vec<u8>) {
+    (
+        instruction.program_id.to_bytes(),
+        instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey.to_bytes(), meta.is_signer, meta.is_writable))
+            .collect(),
+        instruction.data,
+    )
+}
+
+#[test]
+fn test_instructions() {
+    let program_id = spl_token_interface::id();
+    assert_eq!(spl_token_client::ID.to_bytes(), program_id.to_bytes());
+
+    let transfer = TransferBuilder::new()
+        .source(key(1))
+        .destination(key(2))
+        .authority(key(3), true)
+        .amount(42)
+        .instruction();
+    assert_eq!(
+        client_parts(transfer),
+        interface_parts(
+            instruction::transfer(
+                &program_id,
+                &interface_key(1),
+                &interface_key(2),
+                &interface_key(3),
+                &[],
+                42,
+            )
+            .unwrap()
+        )
+    );
+
+    // Multisignature authority.
+    let transfer_checked = TransferCheckedBuilder::new()
+        .source(key(1))
+        .mint(key(4))
+        .destination(key(2))
+        .authority(key(3), false)
+        .amount(42)
+        .decimals(9)
+        .add_remaining_account(solana_program::instruction::AccountMeta::new_readonly(
+            key(7),
+            true,
+        ))
+        .instruction();
+    assert_eq!(
+        client_parts(transfer_checked),
+        interface_parts(
+            instruction::transfer_checked(
+                &program_id,
+                &interface_key(1),
+                &interface_key(4),
+                &interface_key(2),
+                &interface_key(3),
+                &[&interface_key(7)],
+                42,
+                9,
+            )
+            .unwrap()
+        )
+    );
+
+    let initialize_mint = InitializeMintBuilder::new()
+        .mint(key(4))
+        .decimals(6)
+        .mint_authority(key(3))
+        .freeze_authority(key(5))
+        .instruction();
+    assert_eq!(
+        client_parts(initialize_mint),
+        interface_parts(
+            instruction::initialize_mint(
+                &program_id,
+                &interface_key(4),
+                &interface_key(3),
+                Some(&interface_key(5)),
+                6,
+            )
+            .unwrap()
+        )
+    );
+
+    let set_authority = SetAuthorityBuilder::new()
+        .owned(key(1))
+        .owner(key(3), true)
+        .authority_type(AuthorityType::CloseAccount)
+        .instruction();
+    assert_eq!(
+        client_parts(set_authority),
+        interface_parts(
+            instruction::set_authority(
+                &program_id,
+                &interface_key(1),
+                None,
+                instruction::AuthorityType::CloseAccount,
+                &interface_key(3),
+                &[],
+            )
+            .unwrap()
+        )
+    );
+
+    let ui_amount_to_amount = UiAmountToAmountBuilder::new()
+        .mint(key(4))
+        .ui_amount("1.5".into())
+        .instruction();
+    assert_eq!(
+        client_parts(ui_amount_to_amount),
+        interface_parts(
+            instruction::ui_amount_to_amount(&program_id, &interface_key(4), "1.5").unwrap()
+        )
+    );
+
+    let withdraw_excess_lamports = WithdrawExcessLamportsBuilder::new()
+        .source_account(key(1))
+        .destination_account(key(2))
+        .authority(key(3), true)
+        .instruction();
+    assert_eq!(
+        client_parts(withdraw_excess_lamports),
+        interface_parts(
+            instruction::withdraw_excess_lamports(
+                &program_id,
+                &interface_key(1),
+                &interface_key(2),
+                &interface_key(3),
+                &[],
+            )
+            .unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_batch() {
+    let program_id = spl_token_interface::id();
+    let batch = instruction::batch(
+        &program_id,
+        &[
+            instruction::transfer(
+                &program_id,
+                &interface_key(1),
+                &interface_key(2),
+                &interface_key(3),
+                &[],
+                42,
+            )
+            .unwrap(),
+            instruction::sync_native(&program_id, &interface_key(2)).unwrap(),
+        ],
+    )
+    .unwrap();
+
+    // The data of the batch follows the discriminator.
+    let data = RemainderVec::<u8>::try_from_slice(&batch.data[1..]).unwrap();
+    let accounts = batch
+        .accounts
+        .iter()
+        .map(|meta| solana_program::instruction::AccountMeta {
+            pubkey: Pubkey::new_from_array(meta.pubkey.to_bytes()),
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect::<Vec<_>>();
+
+    let client_batch = BatchBuilder::new()
+        .data(data)
+        .add_remaining_accounts(&accounts)
+        .instruction();
+    assert_eq!(client_parts(client_batch), interface_parts(batch));
+}
+
+#[test]
+fn test_accounts() {
+    let mut mint_data = [0; state::Mint::LEN];
+    state::Mint {
+        mint_authority: COption::Some(interface_key(3)),
+        supply: 1_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut mint_data);
+
+    let mint = Mint::from_bytes(&mint_data).unwrap();
+    assert_eq!(Mint::LEN, state::Mint::LEN);
+    assert_eq!(mint.mint_authority, ClientCOption(Some(key(3))));
+    assert_eq!(mint.supply, 1_000);
+    assert_eq!(mint.decimals, 6);
+    assert!(mint.is_initialized);
+    assert_eq!(mint.freeze_authority, ClientCOption(None));
+    assert_eq!(borsh::to_vec(&mint).unwrap(), mint_data);
+
+    let mut data = [0; state::Account::LEN];
+    state::Account {
+        mint: interface_key(4),
+        owner: interface_key(3),
+        amount: 42,
+        delegate: COption::Some(interface_key(5)),
+        state: state::AccountState::Frozen,
+        is_native: COption::Some(2_039_280),
+        delegated_amount: 7,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+
+    let token = Token::from_bytes(&data).unwrap();
+    assert_eq!(Token::LEN, state::Account::LEN);
+    assert_eq!(token.mint, key(4));
+    assert_eq!(token.owner, key(3));
+    assert_eq!(token.amount, 42);
+    assert_eq!(token.delegate, ClientCOption(Some(key(5))));
+    assert_eq!(token.state, AccountState::Frozen);
+    assert_eq!(token.is_native, ClientCOption(Some(2_039_280)));
+    assert_eq!(token.delegated_amount, 7);
+    assert_eq!(token.close_authority, ClientCOption(None));
+    assert_eq!(borsh::to_vec(&token).unwrap(), data);
+
+    let mut data = [0; state::Multisig::LEN];
+    let mut signers = [InterfacePubkey::default(); 11];
+    signers[0] = interface_key(1);
+    signers[1] = interface_key(2);
+    state::Multisig {
+        m: 1,
+        n: 2,
+        is_initialized: true,
+        signers,
+    }
+    .pack_into_slice(&mut data);
+
+    let multisig = Multisig::from_bytes(&data).unwrap();
+    assert_eq!(Multisig::LEN, state::Multisig::LEN);
+    assert_eq!((multisig.m, multisig.n), (1, 2));
+    assert!(multisig.is_initialized);
+    assert_eq!(&multisig.signers[..2], &[key(1), key(2)]);
+    assert_eq!(borsh::to_vec(&multisig).unwrap(), data);
+
+    // Invalid `COption` tag.
+    mint_data[..4].copy_from_slice(&[2, 0, 0, 0]);
+    assert!(Mint::from_bytes(&mint_data).is_err());
+}
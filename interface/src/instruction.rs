@@ -1,7 +1,7 @@
 //! Instruction types
 
 use {
-    crate::{check_program_account, error::TokenError},
+    crate::{check_program_account, error::TokenError, signer_plan::SignerPlan, state::Expiry},
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
     solana_program_option::COption,
@@ -14,6 +14,21 @@ use {
 pub const MIN_SIGNERS: usize = 1;
 /// Maximum number of multisignature signers (max N)
 pub const MAX_SIGNERS: usize = 11;
+/// Maximum number of large multisignature signers (max N)
+pub const MAX_LARGE_SIGNERS: usize = 32;
+/// Maximum depth of nested multisignature authorities (a direct member has
+/// depth 1). In a large multisig that allows nested members, a member multisig
+/// that does not sign is passed as a non-signer account followed by `m` signer
+/// accounts of its own, laid out the same way (see `nest_multisig_signers`)
+pub const MAX_MULTISIG_DEPTH: u8 = 2;
+/// Maximum number of accounts that each instruction of an `IndexedBatch` can
+/// reference, enough for a `TransferChecked` with all the signers of a large
+/// multisig. Instructions with longer account lists, e.g., a `TransferMany`
+/// with many destinations or an authority with nested multisig signers, need
+/// a `Batch` instead
+pub const MAX_INDEXED_ACCOUNTS: usize = 4 + MAX_LARGE_SIGNERS;
+/// Maximum number of instructions in a `BestEffortBatch`
+pub const MAX_BEST_EFFORT_INSTRUCTIONS: usize = 256;
 /// Serialized length of a `u64`, for unpacking
 const U64_BYTES: usize = 8;
 
@@ -467,8 +482,363 @@ pub enum TokenInstruction<'a> {
         /// The `ui_amount` of tokens to reformat.
         ui_amount: &'a str,
     },
-    // Any new variants also need to be added to program-2022 `TokenInstruction`, so that the
-    // latter remains a superset of this instruction set. New variants also need to be added to
+    /// This instruction is to be used to rescue SOL sent to any `TokenProgram`
+    /// owned account by sending them to any other account, leaving behind only
+    /// lamports for rent exemption.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` Source Account owned by the token program
+    ///   1. `[writable]` Destination account
+    ///   2. `[signer]` Authority
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` Source Account owned by the token program
+    ///   1. `[writable]` Destination account
+    ///   2. `[]` The source account's multisignature authority.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    WithdrawExcessLamports,
+    /// Transfers tokens from one account to multiple destination accounts,
+    /// asserting the token mint and decimals. This is equivalent to multiple
+    /// `TransferChecked` instructions from the same source account, but the
+    /// authority is only validated once and the source account is debited
+    /// once with the total amount.
+    ///
+    /// Amounts transferred to the source account itself count towards the
+    /// total amount validated, but are not moved.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///   3. ..`3+N` `[writable]` The N destination accounts.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. ..`3+N` `[writable]` The N destination accounts.
+    ///   4. ..`+M` `[signer]` M signer accounts, following the destination
+    ///      accounts.
+    TransferMany {
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// The amount of tokens to transfer to each destination account.
+        amounts: Vec<u64>,
+    },
+    /// Mints new tokens to multiple accounts, asserting the token decimals.
+    /// This is equivalent to multiple `MintToChecked` instructions for the same
+    /// mint, but the mint authority is only validated once and the mint supply
+    /// is updated once with the total amount.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The mint's minting authority.
+    ///   2. ..`2+N` `[writable]` The N accounts to mint tokens to.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature mint-tokens authority.
+    ///   2. ..`2+N` `[writable]` The N accounts to mint tokens to.
+    ///   3. ..`+M` `[signer]` M signer accounts, following the destination
+    ///      accounts.
+    MintToMany {
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// The amount of new tokens to mint to each account.
+        amounts: Vec<u64>,
+    },
+    /// Gets the amount, delegate, delegated amount and state of a token
+    /// account.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and is encoded
+    /// as:
+    ///
+    ///   * `[0..8]` The amount, as a little-endian `u64`.
+    ///   * `[8..41]` The delegate, as a `u8` tag (`0` or `1`) followed by the
+    ///     delegate, which is zeroed when there is no delegate.
+    ///   * `[41..49]` The delegated amount, as a little-endian `u64`.
+    ///   * `[49]` The account state.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The token account.
+    GetBalance,
+    /// Gets the supply, decimals and authorities of a mint.
+    ///
+    /// Return data can be fetched using `sol_get_return_data` and is encoded
+    /// as:
+    ///
+    ///   * `[0..8]` The supply, as a little-endian `u64`.
+    ///   * `[8]` The decimals.
+    ///   * `[9..42]` The mint authority, as a `u8` tag (`0` or `1`) followed by
+    ///     the authority, which is zeroed when there is no authority.
+    ///   * `[42..75]` The freeze authority, encoded as the mint authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The mint.
+    GetMintInfo,
+    /// Like [`InitializeMultisig2`], but initializes a large multisignature
+    /// account, which can have up to `MAX_LARGE_SIGNERS` signers.
+    ///
+    /// When `allow_nested` is set, a signer that is itself a multisig can
+    /// authorize without signing, through its own signers, up to
+    /// `MAX_MULTISIG_DEPTH` levels of nesting. It cannot be changed after the
+    /// initialization.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The large multisignature account to initialize.
+    ///   1. ..`1+N` `[]` The signer accounts, must equal to N where `1 <= N <=
+    ///      32`.
+    InitializeMultisig3 {
+        /// The number of signers (M) required to validate this multisignature
+        /// account.
+        m: u8,
+        /// Whether signers that are multisigs can authorize through their own
+        /// signers.
+        allow_nested: bool,
+    },
+    /// Replaces the signers and the number of required signers of a
+    /// multisignature account, authorized by M of its current signers.
+    ///
+    /// The account can be a `Multisig` or a `LargeMultisig`, which determines
+    /// the maximum number of new signers. Unlike the initialization, the new M
+    /// cannot be greater than the new N.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account.
+    ///   1. ..`1+N` `[]` The new signer accounts.
+    ///   2. ..`1+N+M` `[signer]` M of the current signer accounts.
+    SetMultisigSigners {
+        /// The number of new signers (M) required to validate the
+        /// multisignature account.
+        m: u8,
+        /// The number of new signer accounts (N).
+        n: u8,
+    },
+    /// Like `ApproveChecked`, but the delegation expires at a slot or unix
+    /// timestamp.
+    ///
+    /// The delegation is kept in a `DelegationRecord` account, which is set as
+    /// the delegate of the source account and must be uninitialized and rent
+    /// exempt, or already used for the source account. To transfer or burn
+    /// tokens, the record is passed as the authority, followed by the delegate
+    /// and its signers.
+    ///
+    /// The record is not cleared when the delegation is revoked or replaced,
+    /// so it can be reused for the same source account until it is closed with
+    /// `CloseDelegationRecord`, the only way to recover its lamports.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The delegation record.
+    ///   3. `[]` The delegate.
+    ///   4. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The delegation record.
+    ///   3. `[]` The delegate.
+    ///   4. `[]` The source account's multisignature owner.
+    ///   5. ..`5+M` `[signer]` M signer accounts
+    ApproveWithExpiry {
+        /// The amount of tokens the delegate is approved for.
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// The slot or unix timestamp at which the delegation expires.
+        expiry: Expiry,
+    },
+    /// Increases the amount of tokens the current delegate of the source
+    /// account is approved for.
+    ///
+    /// Unlike `Approve`, the allowance is adjusted relative to its current
+    /// value, so tokens spent by the delegate in between are not approved
+    /// again. Fails if the delegate of the source account is not the given
+    /// delegate.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts
+    IncreaseAllowance {
+        /// The amount of tokens to add to the allowance.
+        amount: u64,
+    },
+    /// Decreases the amount of tokens the current delegate of the source
+    /// account is approved for.
+    ///
+    /// Fails if the delegate of the source account is not the given delegate
+    /// or the allowance is less than the amount. The delegate is cleared when
+    /// the allowance reaches zero.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts
+    DecreaseAllowance {
+        /// The amount of tokens to remove from the allowance.
+        amount: u64,
+    },
+    /// Like `IncreaseAllowance`, but asserting the token mint and decimals.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts
+    IncreaseAllowanceChecked {
+        /// The amount of tokens to add to the allowance.
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+    /// Like `DecreaseAllowance`, but asserting the token mint and decimals.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts
+    DecreaseAllowanceChecked {
+        /// The amount of tokens to remove from the allowance.
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+    /// Closes a delegation record by transferring all its SOL to the
+    /// destination account.
+    ///
+    /// Only the owner of the token account of the delegation can close the
+    /// record. The delegation is revoked when the record is still the
+    /// delegate of the token account, which fails if it is frozen. Records
+    /// that are no longer the delegate, e.g., after a `Revoke`, are closed
+    /// without changing the token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The delegation record.
+    ///   1. `[writable]` The token account of the delegation.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The token account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The delegation record.
+    ///   1. `[writable]` The token account of the delegation.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The token account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts
+    CloseDelegationRecord,
+    /// Executes a batch of instructions, skipping the instructions that fail
+    /// without modifying any account. The instructions are specified using the
+    /// same layout as `Batch`.
+    ///
+    /// The return data is set to a bitmap with the result of each instruction,
+    /// where bit `i % 8` of byte `i / 8` is set when instruction `i` succeeded.
+    /// A batch can have at most `MAX_BEST_EFFORT_INSTRUCTIONS` instructions.
+    ///
+    /// A failed instruction is only skipped when the lamports and data of its
+    /// writable accounts are unchanged, which requires it to have at most 3
+    /// writable accounts and no writable large multisignature account, since
+    /// their data is not copied. Otherwise, the whole batch fails.
+    ///
+    /// A `BestEffortBatch` instruction cannot contain batch instructions, which
+    /// make the whole batch fail instead of being skipped.
+    BestEffortBatch {
+        /// The serialized instructions to execute, each one prefixed by its
+        /// header.
+        data: &'a [u8],
+    },
+    /// Executes a batch of instructions that reference their accounts by
+    /// index. The instructions to be executed are specified in sequence on
+    /// the instruction data. Each instruction provides:
+    ///   - `u8`: number of accounts
+    ///   - `u8`: instruction data length (includes the discriminator)
+    ///   - `[u8]`: index of each account in the accounts of the batch
+    ///   - `u8`: instruction discriminator
+    ///   - `[u8]`: instruction data
+    ///
+    /// Unlike `Batch`, the accounts are shared by all instructions, so
+    /// instructions can reference the same account without repeating it. Each
+    /// instruction can reference at most `MAX_INDEXED_ACCOUNTS` accounts, so
+    /// instructions with longer account lists must use a `Batch` instead.
+    ///
+    /// An `IndexedBatch` instruction cannot contain batch instructions.
+    IndexedBatch {
+        /// The serialized instructions to execute, each one prefixed by its
+        /// header and account indices.
+        data: &'a [u8],
+    },
+    /// Executes a batch of instructions. The instructions to be executed are
+    /// specified in sequence on the instruction data. Each instruction
+    /// provides:
+    ///   - `u8`: number of accounts
+    ///   - `u8`: instruction data length (includes the discriminator)
+    ///   - `u8`: instruction discriminator
+    ///   - `[u8]`: instruction data
+    ///
+    /// Accounts follow a similar pattern, where accounts for each instruction
+    /// are specified in sequence. Therefore, the number of accounts
+    /// expected by this instruction is variable, i.e., it depends on the
+    /// instructions provided.
+    ///
+    /// A `Batch` instruction cannot contain batch instructions.
+    Batch {
+        /// The serialized instructions to execute, each one prefixed by its
+        /// header.
+        data: &'a [u8],
+    },
+    // Variants with discriminators up to `44` are shared with program-2022 `TokenInstruction`,
+    // which must remain a superset of them. Program-2022 assigns its next discriminators
+    // upwards from `45`, so variants that only this program supports count down from `252`,
+    // right below the batch instructions. New variants also need to be added to
     // token/js/src/instructions/types.ts to maintain @solana/spl-token compatibility
 }
 impl<'a> TokenInstruction<'a> {
@@ -573,6 +943,65 @@ impl<'a> TokenInstruction<'a> {
                 let ui_amount = std::str::from_utf8(rest).map_err(|_| InvalidInstruction)?;
                 Self::UiAmountToAmount { ui_amount }
             }
+            38 => Self::WithdrawExcessLamports,
+            252 => {
+                let (decimals, amounts) = Self::unpack_decimals_amounts(rest)?;
+                Self::TransferMany { decimals, amounts }
+            }
+            251 => {
+                let (decimals, amounts) = Self::unpack_decimals_amounts(rest)?;
+                Self::MintToMany { decimals, amounts }
+            }
+            250 => Self::GetBalance,
+            249 => Self::GetMintInfo,
+            248 => {
+                let (&m, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let allow_nested = match rest.first() {
+                    Some(0) => false,
+                    Some(1) => true,
+                    _ => return Err(InvalidInstruction.into()),
+                };
+                Self::InitializeMultisig3 { m, allow_nested }
+            }
+            247 => {
+                let (&m, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let &n = rest.first().ok_or(InvalidInstruction)?;
+                Self::SetMultisigSigners { m, n }
+            }
+            246 => {
+                let (amount, decimals, rest) = Self::unpack_amount_decimals(rest)?;
+                let (&kind, rest) = rest.split_first().ok_or(InvalidInstruction)?;
+                let expiry = rest
+                    .get(..U64_BYTES)
+                    .and_then(|slice| slice.try_into().ok())
+                    .and_then(|value| Expiry::unpack(kind, value))
+                    .ok_or(InvalidInstruction)?;
+                Self::ApproveWithExpiry {
+                    amount,
+                    decimals,
+                    expiry,
+                }
+            }
+            245 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::IncreaseAllowance { amount }
+            }
+            244 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::DecreaseAllowance { amount }
+            }
+            243 => {
+                let (amount, decimals, _rest) = Self::unpack_amount_decimals(rest)?;
+                Self::IncreaseAllowanceChecked { amount, decimals }
+            }
+            242 => {
+                let (amount, decimals, _rest) = Self::unpack_amount_decimals(rest)?;
+                Self::DecreaseAllowanceChecked { amount, decimals }
+            }
+            241 => Self::CloseDelegationRecord,
+            253 => Self::BestEffortBatch { data: rest },
+            254 => Self::IndexedBatch { data: rest },
+            255 => Self::Batch { data: rest },
             _ => return Err(TokenError::InvalidInstruction.into()),
         })
     }
@@ -684,6 +1113,84 @@ impl<'a> TokenInstruction<'a> {
                 buf.push(24);
                 buf.extend_from_slice(ui_amount.as_bytes());
             }
+            &Self::WithdrawExcessLamports => {
+                buf.push(38);
+            }
+            Self::TransferMany { decimals, amounts } => {
+                buf.push(252);
+                buf.push(*decimals);
+                for amount in amounts {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            Self::MintToMany { decimals, amounts } => {
+                buf.push(251);
+                buf.push(*decimals);
+                for amount in amounts {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            &Self::GetBalance => {
+                buf.push(250);
+            }
+            &Self::GetMintInfo => {
+                buf.push(249);
+            }
+            &Self::InitializeMultisig3 { m, allow_nested } => {
+                buf.push(248);
+                buf.push(m);
+                buf.push(allow_nested as u8);
+            }
+            &Self::SetMultisigSigners { m, n } => {
+                buf.push(247);
+                buf.push(m);
+                buf.push(n);
+            }
+            &Self::ApproveWithExpiry {
+                amount,
+                decimals,
+                expiry,
+            } => {
+                buf.push(246);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(decimals);
+                let (kind, value) = expiry.pack();
+                buf.push(kind);
+                buf.extend_from_slice(&value);
+            }
+            &Self::IncreaseAllowance { amount } => {
+                buf.push(245);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::DecreaseAllowance { amount } => {
+                buf.push(244);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::IncreaseAllowanceChecked { amount, decimals } => {
+                buf.push(243);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(decimals);
+            }
+            &Self::DecreaseAllowanceChecked { amount, decimals } => {
+                buf.push(242);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(decimals);
+            }
+            &Self::CloseDelegationRecord => {
+                buf.push(241);
+            }
+            Self::BestEffortBatch { data } => {
+                buf.push(253);
+                buf.extend_from_slice(data);
+            }
+            Self::IndexedBatch { data } => {
+                buf.push(254);
+                buf.extend_from_slice(data);
+            }
+            Self::Batch { data } => {
+                buf.push(255);
+                buf.extend_from_slice(data);
+            }
         };
         buf
     }
@@ -734,6 +1241,18 @@ impl<'a> TokenInstruction<'a> {
         let (&decimals, rest) = rest.split_first().ok_or(TokenError::InvalidInstruction)?;
         Ok((amount, decimals, rest))
     }
+
+    fn unpack_decimals_amounts(input: &[u8]) -> Result<(u8, Vec<u64>), ProgramError> {
+        let (&decimals, rest) = input.split_first().ok_or(TokenError::InvalidInstruction)?;
+        if rest.is_empty() || rest.len() % U64_BYTES != 0 {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+        let amounts = rest
+            .chunks_exact(U64_BYTES)
+            .map(|amount| Self::unpack_u64(amount).map(|(amount, _)| amount))
+            .collect::<Result<_, _>>()?;
+        Ok((decimals, amounts))
+    }
 }
 
 /// Specifies the authority type for `SetAuthority` instructions
@@ -960,27 +1479,27 @@ pub fn initialize_multisig2(
     })
 }
 
-/// Creates a `Transfer` instruction.
-pub fn transfer(
+/// Creates a `InitializeMultisig3` instruction.
+pub fn initialize_multisig3(
     token_program_id: &Pubkey,
-    source_pubkey: &Pubkey,
-    destination_pubkey: &Pubkey,
-    authority_pubkey: &Pubkey,
+    multisig_pubkey: &Pubkey,
     signer_pubkeys: &[&Pubkey],
-    amount: u64,
+    m: u8,
+    allow_nested: bool,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(token_program_id)?;
-    let data = TokenInstruction::Transfer { amount }.pack();
+    if !is_valid_large_signer_index(m as usize)
+        || !is_valid_large_signer_index(signer_pubkeys.len())
+        || m as usize > signer_pubkeys.len()
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let data = TokenInstruction::InitializeMultisig3 { m, allow_nested }.pack();
 
-    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
-    accounts.push(AccountMeta::new(*source_pubkey, false));
-    accounts.push(AccountMeta::new(*destination_pubkey, false));
-    accounts.push(AccountMeta::new_readonly(
-        *authority_pubkey,
-        signer_pubkeys.is_empty(),
-    ));
+    let mut accounts = Vec::with_capacity(1 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*multisig_pubkey, false));
     for signer_pubkey in signer_pubkeys.iter() {
-        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, false));
     }
 
     Ok(Instruction {
@@ -990,20 +1509,32 @@ pub fn transfer(
     })
 }
 
-/// Creates an `Approve` instruction.
-pub fn approve(
+/// Creates an `ApproveWithExpiry` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn approve_with_expiry(
     token_program_id: &Pubkey,
     source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegation_record_pubkey: &Pubkey,
     delegate_pubkey: &Pubkey,
     owner_pubkey: &Pubkey,
     signer_pubkeys: &[&Pubkey],
     amount: u64,
+    decimals: u8,
+    expiry: Expiry,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(token_program_id)?;
-    let data = TokenInstruction::Approve { amount }.pack();
+    let data = TokenInstruction::ApproveWithExpiry {
+        amount,
+        decimals,
+        expiry,
+    }
+    .pack();
 
-    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    let mut accounts = Vec::with_capacity(5 + signer_pubkeys.len());
     accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*delegation_record_pubkey, false));
     accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
     accounts.push(AccountMeta::new_readonly(
         *owner_pubkey,
@@ -1020,18 +1551,21 @@ pub fn approve(
     })
 }
 
-/// Creates a `Revoke` instruction.
-pub fn revoke(
+/// Creates an `IncreaseAllowance` instruction.
+pub fn increase_allowance(
     token_program_id: &Pubkey,
     source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
     owner_pubkey: &Pubkey,
     signer_pubkeys: &[&Pubkey],
+    amount: u64,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(token_program_id)?;
-    let data = TokenInstruction::Revoke.pack();
+    let data = TokenInstruction::IncreaseAllowance { amount }.pack();
 
-    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
     accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
     accounts.push(AccountMeta::new_readonly(
         *owner_pubkey,
         signer_pubkeys.is_empty(),
@@ -1047,11 +1581,306 @@ pub fn revoke(
     })
 }
 
-/// Creates a `SetAuthority` instruction.
-pub fn set_authority(
+/// Creates a `DecreaseAllowance` instruction.
+pub fn decrease_allowance(
     token_program_id: &Pubkey,
-    owned_pubkey: &Pubkey,
-    new_authority_pubkey: Option<&Pubkey>,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::DecreaseAllowance { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `IncreaseAllowanceChecked` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn increase_allowance_checked(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::IncreaseAllowanceChecked { amount, decimals }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `DecreaseAllowanceChecked` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn decrease_allowance_checked(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::DecreaseAllowanceChecked { amount, decimals }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CloseDelegationRecord` instruction.
+pub fn close_delegation_record(
+    token_program_id: &Pubkey,
+    delegation_record_pubkey: &Pubkey,
+    account_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::CloseDelegationRecord.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*delegation_record_pubkey, false));
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetMultisigSigners` instruction.
+pub fn set_multisig_signers(
+    token_program_id: &Pubkey,
+    multisig_pubkey: &Pubkey,
+    new_signer_pubkeys: &[&Pubkey],
+    m: u8,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    if !is_valid_large_signer_index(m as usize)
+        || !is_valid_large_signer_index(new_signer_pubkeys.len())
+        || m as usize > new_signer_pubkeys.len()
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let data = TokenInstruction::SetMultisigSigners {
+        m,
+        n: new_signer_pubkeys.len() as u8,
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(1 + new_signer_pubkeys.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*multisig_pubkey, false));
+    for new_signer_pubkey in new_signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**new_signer_pubkey, false));
+    }
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetMultisigSigners` instruction signed by the signers of a
+/// [`SignerPlan`] of the multisig, including its nested signers.
+pub fn set_multisig_signers_with_plan(
+    token_program_id: &Pubkey,
+    multisig_pubkey: &Pubkey,
+    new_signer_pubkeys: &[&Pubkey],
+    m: u8,
+    plan: &SignerPlan,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = set_multisig_signers(
+        token_program_id,
+        multisig_pubkey,
+        new_signer_pubkeys,
+        m,
+        &plan.signer_pubkeys(),
+    )?;
+    plan.nest_signers(&mut instruction)?;
+    Ok(instruction)
+}
+
+/// Creates a `Transfer` instruction.
+pub fn transfer(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Transfer { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `Approve` instruction.
+pub fn approve(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Approve { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `Approve` instruction signed by the signers of a [`SignerPlan`]
+/// of the multisignature owner, including its nested signers.
+pub fn approve_with_plan(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    plan: &SignerPlan,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = approve(
+        token_program_id,
+        source_pubkey,
+        delegate_pubkey,
+        owner_pubkey,
+        &plan.signer_pubkeys(),
+        amount,
+    )?;
+    plan.nest_signers(&mut instruction)?;
+    Ok(instruction)
+}
+
+/// Creates a `Revoke` instruction.
+pub fn revoke(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Revoke.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetAuthority` instruction.
+pub fn set_authority(
+    token_program_id: &Pubkey,
+    owned_pubkey: &Pubkey,
+    new_authority_pubkey: Option<&Pubkey>,
     authority_type: AuthorityType,
     owner_pubkey: &Pubkey,
     signer_pubkeys: &[&Pubkey],
@@ -1262,6 +2091,118 @@ pub fn transfer_checked(
     })
 }
 
+/// Creates a `TransferChecked` instruction signed by the signers of a
+/// [`SignerPlan`] of the multisignature authority, including its nested
+/// signers.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_plan(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    plan: &SignerPlan,
+    amount: u64,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = transfer_checked(
+        token_program_id,
+        source_pubkey,
+        mint_pubkey,
+        destination_pubkey,
+        authority_pubkey,
+        &plan.signer_pubkeys(),
+        amount,
+        decimals,
+    )?;
+    plan.nest_signers(&mut instruction)?;
+    Ok(instruction)
+}
+
+/// Creates a `TransferMany` instruction.
+///
+/// Each destination is paired with the amount of tokens to transfer to it.
+pub fn transfer_many(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destinations: &[(&Pubkey, u64)],
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    if destinations.is_empty() {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+    let data = TokenInstruction::TransferMany {
+        decimals,
+        amounts: destinations.iter().map(|(_, amount)| *amount).collect(),
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(3 + destinations.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for (destination_pubkey, _) in destinations.iter() {
+        accounts.push(AccountMeta::new(**destination_pubkey, false));
+    }
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MintToMany` instruction.
+///
+/// Each destination is paired with the amount of tokens to mint to it.
+pub fn mint_to_many(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destinations: &[(&Pubkey, u64)],
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    if destinations.is_empty() {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+    let data = TokenInstruction::MintToMany {
+        decimals,
+        amounts: destinations.iter().map(|(_, amount)| *amount).collect(),
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(2 + destinations.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for (destination_pubkey, _) in destinations.iter() {
+        accounts.push(AccountMeta::new(**destination_pubkey, false));
+    }
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
 /// Creates an `ApproveChecked` instruction.
 #[allow(clippy::too_many_arguments)]
 pub fn approve_checked(
@@ -1381,60 +2322,440 @@ pub fn get_account_data_size(
 
     Ok(Instruction {
         program_id: *token_program_id,
-        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
-        data: TokenInstruction::GetAccountDataSize.pack(),
+        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
+        data: TokenInstruction::GetAccountDataSize.pack(),
+    })
+}
+
+/// Creates a `GetBalance` instruction
+pub fn get_balance(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*account_pubkey, false)],
+        data: TokenInstruction::GetBalance.pack(),
+    })
+}
+
+/// Creates a `GetMintInfo` instruction
+pub fn get_mint_info(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
+        data: TokenInstruction::GetMintInfo.pack(),
+    })
+}
+
+/// Creates a `InitializeImmutableOwner` instruction
+pub fn initialize_immutable_owner(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new(*account_pubkey, false)],
+        data: TokenInstruction::InitializeImmutableOwner.pack(),
+    })
+}
+
+/// Creates an `AmountToUiAmount` instruction
+pub fn amount_to_ui_amount(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
+        data: TokenInstruction::AmountToUiAmount { amount }.pack(),
+    })
+}
+
+/// Creates a `UiAmountToAmount` instruction
+pub fn ui_amount_to_amount(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    ui_amount: &str,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
+        data: TokenInstruction::UiAmountToAmount { ui_amount }.pack(),
+    })
+}
+
+/// Creates a `WithdrawExcessLamports` instruction.
+pub fn withdraw_excess_lamports(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::WithdrawExcessLamports.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
     })
 }
 
-/// Creates a `InitializeImmutableOwner` instruction
-pub fn initialize_immutable_owner(
+/// Creates a `Batch` instruction from a list of token instructions.
+///
+/// Every instruction must target the token program and cannot be a batch
+/// instruction itself. The number of accounts and the length of the data of
+/// each instruction must fit in a `u8`.
+pub fn batch(
     token_program_id: &Pubkey,
-    account_pubkey: &Pubkey,
+    instructions: &[Instruction],
 ) -> Result<Instruction, ProgramError> {
-    check_program_account(token_program_id)?;
-    Ok(Instruction {
-        program_id: *token_program_id,
-        accounts: vec![AccountMeta::new(*account_pubkey, false)],
-        data: TokenInstruction::InitializeImmutableOwner.pack(),
-    })
+    let mut builder = BatchBuilder::new(token_program_id)?;
+    for instruction in instructions {
+        builder.add(instruction)?;
+    }
+    builder.build()
 }
 
-/// Creates an `AmountToUiAmount` instruction
-pub fn amount_to_ui_amount(
+/// Creates a `BestEffortBatch` instruction from a list of token instructions.
+///
+/// Same as [`batch`], but instructions that fail without modifying their
+/// accounts are skipped. The result of each instruction can be read from the
+/// return data with [`unpack_best_effort_batch_results`].
+pub fn best_effort_batch(
     token_program_id: &Pubkey,
-    mint_pubkey: &Pubkey,
-    amount: u64,
+    instructions: &[Instruction],
 ) -> Result<Instruction, ProgramError> {
-    check_program_account(token_program_id)?;
-
-    Ok(Instruction {
-        program_id: *token_program_id,
-        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
-        data: TokenInstruction::AmountToUiAmount { amount }.pack(),
-    })
+    if instructions.len() > MAX_BEST_EFFORT_INSTRUCTIONS {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+    let mut builder = BatchBuilder::new(token_program_id)?;
+    for instruction in instructions {
+        builder.add(instruction)?;
+    }
+    builder.build_best_effort()
 }
 
-/// Creates a `UiAmountToAmount` instruction
-pub fn ui_amount_to_amount(
+/// Creates an `IndexedBatch` instruction from a list of token instructions.
+///
+/// The accounts of the instructions are deduplicated, keeping the strictest
+/// signer and writable flags of each account, and instructions reference them
+/// by index. Every instruction must target the token program, cannot be a
+/// batch instruction itself and can have at most `MAX_INDEXED_ACCOUNTS`
+/// accounts. The length of the data of each instruction must fit in a `u8`.
+pub fn indexed_batch(
     token_program_id: &Pubkey,
-    mint_pubkey: &Pubkey,
-    ui_amount: &str,
+    instructions: &[Instruction],
 ) -> Result<Instruction, ProgramError> {
     check_program_account(token_program_id)?;
+    if instructions.is_empty() {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let mut accounts: Vec<AccountMeta> = Vec::new();
+    let mut data = Vec::new();
+
+    for instruction in instructions {
+        if instruction.program_id != *token_program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let data_len =
+            u8::try_from(instruction.data.len()).map_err(|_| TokenError::InvalidInstruction)?;
+        if instruction.accounts.len() > MAX_INDEXED_ACCOUNTS
+            || is_batch_instruction(&instruction.data)?
+        {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        data.push(instruction.accounts.len() as u8);
+        data.push(data_len);
+
+        for meta in instruction.accounts.iter() {
+            let index = match accounts.iter().position(|a| a.pubkey == meta.pubkey) {
+                Some(index) => {
+                    accounts[index].is_signer |= meta.is_signer;
+                    accounts[index].is_writable |= meta.is_writable;
+                    index
+                }
+                None => {
+                    accounts.push(meta.clone());
+                    accounts.len() - 1
+                }
+            };
+            data.push(u8::try_from(index).map_err(|_| TokenError::InvalidInstruction)?);
+        }
+
+        data.extend_from_slice(&instruction.data);
+    }
 
     Ok(Instruction {
         program_id: *token_program_id,
-        accounts: vec![AccountMeta::new_readonly(*mint_pubkey, false)],
-        data: TokenInstruction::UiAmountToAmount { ui_amount }.pack(),
+        accounts,
+        data: TokenInstruction::IndexedBatch { data: &data }.pack(),
     })
 }
 
+/// Incrementally assembles a `Batch` instruction.
+///
+/// Instructions are appended in the order they will be executed. Each one
+/// contributes a header with its number of accounts and data length, followed
+/// by its data, and its accounts are appended to the flat account list of the
+/// batch.
+///
+/// Since the runtime merges the privileges of duplicated accounts in a
+/// transaction, every account meta referring to the same key is promoted to
+/// the strictest signer and writable flags among its occurrences when the
+/// batch is built.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchBuilder {
+    program_id: Pubkey,
+    accounts: Vec<AccountMeta>,
+    data: Vec<u8>,
+}
+
+impl BatchBuilder {
+    /// The size of the header of each instruction in a batch.
+    ///
+    /// The header consists of two `u8` values:
+    ///  * number of the accounts
+    ///  * length of the instruction data
+    pub const IX_HEADER_SIZE: usize = 2;
+
+    /// Creates an empty builder for the given token program.
+    pub fn new(token_program_id: &Pubkey) -> Result<Self, ProgramError> {
+        check_program_account(token_program_id)?;
+        Ok(Self {
+            program_id: *token_program_id,
+            accounts: Vec::new(),
+            data: Vec::new(),
+        })
+    }
+
+    /// Appends an instruction to the batch.
+    ///
+    /// Fails with `IncorrectProgramId` if the instruction does not target the
+    /// token program, and with `InvalidInstruction` if it is a batch
+    /// instruction, cannot be unpacked, or its number of accounts or data
+    /// length does not fit in a `u8`.
+    pub fn add(&mut self, instruction: &Instruction) -> Result<&mut Self, ProgramError> {
+        if instruction.program_id != self.program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let (account_count, data_len) = match (
+            u8::try_from(instruction.accounts.len()),
+            u8::try_from(instruction.data.len()),
+        ) {
+            (Ok(account_count), Ok(data_len)) => (account_count, data_len),
+            _ => return Err(TokenError::InvalidInstruction.into()),
+        };
+        if is_batch_instruction(&instruction.data)? {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        self.data.push(account_count);
+        self.data.push(data_len);
+        self.data.extend_from_slice(&instruction.data);
+        self.accounts.extend_from_slice(&instruction.accounts);
+
+        Ok(self)
+    }
+
+    /// Returns `true` if no instruction was added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Creates the `Batch` instruction.
+    ///
+    /// Fails with `InvalidInstruction` if the batch is empty.
+    pub fn build(&self) -> Result<Instruction, ProgramError> {
+        if self.is_empty() {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.merged_accounts(),
+            data: TokenInstruction::Batch { data: &self.data }.pack(),
+        })
+    }
+
+    /// Creates a `BestEffortBatch` instruction with the same instructions.
+    ///
+    /// Fails with `InvalidInstruction` if the batch is empty.
+    pub fn build_best_effort(&self) -> Result<Instruction, ProgramError> {
+        if self.is_empty() {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.merged_accounts(),
+            data: TokenInstruction::BestEffortBatch { data: &self.data }.pack(),
+        })
+    }
+
+    /// Returns the accounts of the batch with the privileges of duplicated
+    /// accounts merged.
+    fn merged_accounts(&self) -> Vec<AccountMeta> {
+        let mut accounts = self.accounts.clone();
+        for index in 0..accounts.len() {
+            let (is_signer, is_writable) = accounts
+                .iter()
+                .filter(|meta| meta.pubkey == accounts[index].pubkey)
+                .fold((false, false), |(is_signer, is_writable), meta| {
+                    (is_signer || meta.is_signer, is_writable || meta.is_writable)
+                });
+            accounts[index].is_signer = is_signer;
+            accounts[index].is_writable = is_writable;
+        }
+        accounts
+    }
+}
+
+/// Failure of an instruction of a batch instruction.
+///
+/// When an instruction of a batch fails, the token program sets the return
+/// data to:
+///  * `u8`: discriminator of the batch instruction
+///  * `u16`: index of the failed instruction in the batch (little-endian)
+///  * `u8`: discriminator of the failed instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchFailure {
+    /// Discriminator of the batch instruction.
+    pub batch_discriminator: u8,
+    /// Index of the failed instruction in the batch.
+    pub index: u16,
+    /// Discriminator of the failed instruction.
+    pub discriminator: u8,
+}
+
+impl BatchFailure {
+    /// Length of the return data describing a batch failure.
+    pub const LEN: usize = 4;
+
+    /// Unpacks the return data of a failed batch.
+    pub fn unpack(return_data: &[u8]) -> Result<Self, ProgramError> {
+        match *return_data {
+            [batch_discriminator @ (253..=255), index_lo, index_hi, discriminator] => Ok(Self {
+                batch_discriminator,
+                index: u16::from_le_bytes([index_lo, index_hi]),
+                discriminator,
+            }),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    /// Packs the batch failure into its return data representation.
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let [index_lo, index_hi] = self.index.to_le_bytes();
+        [
+            self.batch_discriminator,
+            index_lo,
+            index_hi,
+            self.discriminator,
+        ]
+    }
+}
+
+/// Unpacks the return data of a successful `BestEffortBatch` instruction into
+/// the result of each of its `instruction_count` instructions, where `true`
+/// means that the instruction succeeded and `false` that it was skipped.
+pub fn unpack_best_effort_batch_results(
+    return_data: &[u8],
+    instruction_count: usize,
+) -> Result<Vec<bool>, ProgramError> {
+    if instruction_count == 0
+        || instruction_count > MAX_BEST_EFFORT_INSTRUCTIONS
+        || return_data.len() != instruction_count.div_ceil(8)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok((0..instruction_count)
+        .map(|index| return_data[index / 8] & (1 << (index % 8)) != 0)
+        .collect())
+}
+
+/// Makes the signer `nested_multisig_pubkey` of an instruction with a
+/// multisignature authority authorize through its own signers, as a nested
+/// member of a large multisig that allows nested members.
+///
+/// The nested multisig becomes a non-signer account, followed by the
+/// `signer_pubkeys` of the `m` signers it requires. A signer of the nested
+/// multisig can in turn be nested by calling this function again, up to
+/// `MAX_MULTISIG_DEPTH` levels of nesting.
+pub fn nest_multisig_signers(
+    instruction: &mut Instruction,
+    nested_multisig_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<(), ProgramError> {
+    let position = instruction
+        .accounts
+        .iter()
+        .position(|meta| meta.is_signer && meta.pubkey == *nested_multisig_pubkey)
+        .ok_or(ProgramError::InvalidArgument)?;
+    if !is_valid_large_signer_index(signer_pubkeys.len()) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    instruction.accounts[position].is_signer = false;
+    instruction.accounts.splice(
+        position + 1..position + 1,
+        signer_pubkeys
+            .iter()
+            .map(|signer_pubkey| AccountMeta::new_readonly(**signer_pubkey, true)),
+    );
+    Ok(())
+}
+
+/// Checks whether the instruction data represents a `Batch`, `IndexedBatch` or
+/// `BestEffortBatch` instruction.
+fn is_batch_instruction(data: &[u8]) -> Result<bool, ProgramError> {
+    Ok(matches!(
+        TokenInstruction::unpack(data)?,
+        TokenInstruction::Batch { .. }
+            | TokenInstruction::IndexedBatch { .. }
+            | TokenInstruction::BestEffortBatch { .. }
+    ))
+}
+
 /// Utility function that checks index is between `MIN_SIGNERS` and
 /// `MAX_SIGNERS`
 pub fn is_valid_signer_index(index: usize) -> bool {
     (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
 }
 
+/// Utility function that checks index is between `MIN_SIGNERS` and
+/// `MAX_LARGE_SIGNERS`
+pub fn is_valid_large_signer_index(index: usize) -> bool {
+    (MIN_SIGNERS..=MAX_LARGE_SIGNERS).contains(&index)
+}
+
 #[cfg(test)]
 mod test {
     use {super::*, proptest::prelude::*};
@@ -1678,6 +2999,626 @@ mod test {
         assert_eq!(packed, expect);
         let unpacked = TokenInstruction::unpack(&expect).unwrap();
         assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::WithdrawExcessLamports;
+        let packed = check.pack();
+        let expect = vec![38u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::TransferMany {
+            decimals: 2,
+            amounts: vec![1, 258],
+        };
+        let packed = check.pack();
+        let expect = vec![252u8, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        // missing or partial amounts
+        assert!(TokenInstruction::unpack(&[252u8, 2]).is_err());
+        assert!(TokenInstruction::unpack(&[252u8, 2, 1, 0, 0, 0, 0, 0, 0, 0, 2]).is_err());
+
+        let check = TokenInstruction::MintToMany {
+            decimals: 2,
+            amounts: vec![1],
+        };
+        let packed = check.pack();
+        let expect = vec![251u8, 2, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::GetBalance;
+        let packed = check.pack();
+        let expect = vec![250u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::GetMintInfo;
+        let packed = check.pack();
+        let expect = vec![249u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMultisig3 {
+            m: 20,
+            allow_nested: true,
+        };
+        let packed = check.pack();
+        let expect = Vec::from([248u8, 20, 1]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+        assert_eq!(
+            TokenInstruction::unpack(&[248, 20, 2]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        let check = TokenInstruction::SetMultisigSigners { m: 2, n: 3 };
+        let packed = check.pack();
+        let expect = Vec::from([247u8, 2, 3]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveWithExpiry {
+            amount: 1,
+            decimals: 2,
+            expiry: Expiry::UnixTimestamp(-1),
+        };
+        let packed = check.pack();
+        let mut expect = Vec::from([246u8, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1]);
+        expect.extend_from_slice(&[255; 8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::IncreaseAllowance { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([245u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::DecreaseAllowance { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([244u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::IncreaseAllowanceChecked {
+            amount: 1,
+            decimals: 2,
+        };
+        let packed = check.pack();
+        let expect = Vec::from([243u8, 1, 0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::DecreaseAllowanceChecked {
+            amount: 1,
+            decimals: 2,
+        };
+        let packed = check.pack();
+        let expect = Vec::from([242u8, 1, 0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::CloseDelegationRecord;
+        let packed = check.pack();
+        let expect = Vec::from([241u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::BestEffortBatch {
+            data: &[3, 1, 9, 0, 1, 17],
+        };
+        let packed = check.pack();
+        let expect = vec![253u8, 3, 1, 9, 0, 1, 17];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::IndexedBatch {
+            data: &[3, 1, 0, 1, 0, 9, 1, 1, 1, 17],
+        };
+        let packed = check.pack();
+        let expect = vec![254u8, 3, 1, 0, 1, 0, 9, 1, 1, 1, 17];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Batch {
+            data: &[3, 1, 9, 0, 1, 17],
+        };
+        let packed = check.pack();
+        let expect = vec![255u8, 3, 1, 9, 0, 1, 17];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+    }
+
+    #[test]
+    fn test_batch() {
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let transfer_ix = transfer(&crate::id(), &source, &destination, &owner, &[], 1).unwrap();
+        let sync_native_ix = sync_native(&crate::id(), &destination).unwrap();
+
+        let batch_ix = batch(&crate::id(), &[transfer_ix.clone(), sync_native_ix.clone()]).unwrap();
+
+        let mut expect = vec![255u8, 3, 9];
+        expect.extend_from_slice(&transfer_ix.data);
+        expect.extend_from_slice(&[1, 1, 17]);
+        assert_eq!(batch_ix.data, expect);
+
+        let mut expect = transfer_ix.accounts.clone();
+        expect.extend_from_slice(&sync_native_ix.accounts);
+        assert_eq!(batch_ix.accounts, expect);
+
+        // empty batch
+        assert_eq!(
+            batch(&crate::id(), &[]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        // nested batch
+        assert_eq!(
+            batch(&crate::id(), &[batch_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        // instruction for a different program
+        let mut foreign_ix = sync_native_ix.clone();
+        foreign_ix.program_id = Pubkey::new_unique();
+        assert_eq!(
+            batch(&crate::id(), &[foreign_ix]),
+            Err(ProgramError::IncorrectProgramId)
+        );
+
+        // too many accounts
+        let mut oversized_ix = sync_native_ix;
+        oversized_ix.accounts = vec![AccountMeta::new(destination, false); 256];
+        assert_eq!(
+            batch(&crate::id(), &[oversized_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_indexed_batch() {
+        let mint = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination_a = Pubkey::new_unique();
+        let destination_b = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let transfer_a_ix = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination_a,
+            &owner,
+            &[],
+            1,
+            6,
+        )
+        .unwrap();
+        let transfer_b_ix = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination_b,
+            &owner,
+            &[],
+            2,
+            6,
+        )
+        .unwrap();
+
+        let batch_ix = indexed_batch(
+            &crate::id(),
+            &[transfer_a_ix.clone(), transfer_b_ix.clone()],
+        )
+        .unwrap();
+
+        let mut expect = vec![254u8, 4, 10, 0, 1, 2, 3];
+        expect.extend_from_slice(&transfer_a_ix.data);
+        expect.extend_from_slice(&[4, 10, 0, 1, 4, 3]);
+        expect.extend_from_slice(&transfer_b_ix.data);
+        assert_eq!(batch_ix.data, expect);
+
+        assert_eq!(
+            batch_ix.accounts,
+            vec![
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(destination_a, false),
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new(destination_b, false),
+            ]
+        );
+
+        // nested batch
+        assert_eq!(
+            indexed_batch(&crate::id(), &[batch_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        // as many accounts as the limit
+        let signers = vec![Pubkey::new_unique(); MAX_LARGE_SIGNERS];
+        let signers = signers.iter().collect::<Vec<_>>();
+        let multisig_ix = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination_a,
+            &owner,
+            &signers,
+            1,
+            6,
+        )
+        .unwrap();
+        assert_eq!(multisig_ix.accounts.len(), MAX_INDEXED_ACCOUNTS);
+        assert!(indexed_batch(&crate::id(), &[multisig_ix]).is_ok());
+
+        // too many accounts
+        let signers = vec![Pubkey::new_unique(); MAX_LARGE_SIGNERS + 1];
+        let signers = signers.iter().collect::<Vec<_>>();
+        let multisig_ix = transfer_checked(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination_a,
+            &owner,
+            &signers,
+            1,
+            6,
+        )
+        .unwrap();
+        assert_eq!(
+            indexed_batch(&crate::id(), &[multisig_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_batch_builder_merges_account_flags() {
+        let mint = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let mut builder = BatchBuilder::new(&crate::id()).unwrap();
+        assert!(builder.is_empty());
+
+        // `delegate` is read-only and not a signer on the approve, but signs the burn.
+        let approve_ix = approve(&crate::id(), &account, &delegate, &owner, &[], 10).unwrap();
+        let burn_ix = burn(&crate::id(), &account, &mint, &delegate, &[], 10).unwrap();
+        // `mint` is writable on the burn, but read-only here.
+        let amount_ix = amount_to_ui_amount(&crate::id(), &mint, 10).unwrap();
+
+        builder
+            .add(&approve_ix)
+            .unwrap()
+            .add(&burn_ix)
+            .unwrap()
+            .add(&amount_ix)
+            .unwrap();
+        assert!(!builder.is_empty());
+
+        let batch_ix = builder.build().unwrap();
+
+        let mut expect = vec![255u8, 3, 9];
+        expect.extend_from_slice(&approve_ix.data);
+        expect.extend_from_slice(&[3, 9]);
+        expect.extend_from_slice(&burn_ix.data);
+        expect.extend_from_slice(&[1, 9]);
+        expect.extend_from_slice(&amount_ix.data);
+        assert_eq!(batch_ix.data, expect);
+
+        assert_eq!(
+            batch_ix.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(delegate, true),
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new(account, false),
+                AccountMeta::new(mint, false),
+                AccountMeta::new_readonly(delegate, true),
+                AccountMeta::new(mint, false),
+            ]
+        );
+
+        // too much data
+        let mut oversized_ix = amount_ix;
+        oversized_ix.data.resize(256, 0);
+        assert_eq!(
+            builder.add(&oversized_ix),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_best_effort_batch() {
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let transfer_ix = transfer(&crate::id(), &source, &destination, &owner, &[], 1).unwrap();
+        let sync_native_ix = sync_native(&crate::id(), &destination).unwrap();
+
+        let batch_ix =
+            best_effort_batch(&crate::id(), &[transfer_ix.clone(), sync_native_ix.clone()])
+                .unwrap();
+
+        let mut expect = vec![253u8, 3, 9];
+        expect.extend_from_slice(&transfer_ix.data);
+        expect.extend_from_slice(&[1, 1, 17]);
+        assert_eq!(batch_ix.data, expect);
+
+        let mut expect = transfer_ix.accounts.clone();
+        expect.extend_from_slice(&sync_native_ix.accounts);
+        assert_eq!(batch_ix.accounts, expect);
+
+        // nested batch
+        assert_eq!(
+            batch(&crate::id(), &[batch_ix.clone()]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+        assert_eq!(
+            best_effort_batch(&crate::id(), &[batch_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        // too many instructions
+        assert_eq!(
+            best_effort_batch(
+                &crate::id(),
+                &vec![sync_native_ix; MAX_BEST_EFFORT_INSTRUCTIONS + 1]
+            ),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_best_effort_batch_results() {
+        assert_eq!(
+            unpack_best_effort_batch_results(&[0b0000_0101], 3),
+            Ok(vec![true, false, true])
+        );
+        assert_eq!(
+            unpack_best_effort_batch_results(&[0xff, 0b0000_0010], 10),
+            Ok(vec![
+                true, true, true, true, true, true, true, true, false, true
+            ])
+        );
+
+        // length mismatch
+        assert_eq!(
+            unpack_best_effort_batch_results(&[0xff, 0], 8),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            unpack_best_effort_batch_results(&[], 0),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_batch_failure() {
+        let failure = BatchFailure {
+            batch_discriminator: 255,
+            index: 258,
+            discriminator: 12,
+        };
+        let packed = failure.pack();
+        assert_eq!(packed, [255, 2, 1, 12]);
+        assert_eq!(BatchFailure::unpack(&packed), Ok(failure));
+
+        // not a batch
+        assert_eq!(
+            BatchFailure::unpack(&[12, 2, 1, 12]),
+            Err(ProgramError::InvalidArgument)
+        );
+        // `AmountToUiAmount` return data
+        assert_eq!(
+            BatchFailure::unpack(b"0.42"),
+            Err(ProgramError::InvalidArgument)
+        );
+        // `GetAccountDataSize` return data
+        assert_eq!(
+            BatchFailure::unpack(&165u64.to_le_bytes()),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_set_multisig_signers() {
+        let multisig = Pubkey::new_unique();
+        let new_signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let new_signers = new_signers.iter().collect::<Vec<_>>();
+        let signer = Pubkey::new_unique();
+
+        let instruction =
+            set_multisig_signers(&crate::id(), &multisig, &new_signers, 2, &[&signer]).unwrap();
+        assert_eq!(instruction.data, [247, 2, 2]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(multisig, false),
+                AccountMeta::new_readonly(*new_signers[0], false),
+                AccountMeta::new_readonly(*new_signers[1], false),
+                AccountMeta::new_readonly(signer, true),
+            ]
+        );
+
+        // unreachable threshold
+        assert_eq!(
+            set_multisig_signers(&crate::id(), &multisig, &new_signers, 3, &[&signer]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        // no new signers
+        assert_eq!(
+            set_multisig_signers(&crate::id(), &multisig, &[], 1, &[&signer]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn test_nest_multisig_signers() {
+        let [source, destination, owner, a, nested, b, c] = [(); 7].map(|_| Pubkey::new_unique());
+
+        let mut instruction = transfer(
+            &crate::id(),
+            &source,
+            &destination,
+            &owner,
+            &[&a, &nested],
+            1,
+        )
+        .unwrap();
+        nest_multisig_signers(&mut instruction, &nested, &[&b, &c]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new_readonly(a, true),
+                AccountMeta::new_readonly(nested, false),
+                AccountMeta::new_readonly(b, true),
+                AccountMeta::new_readonly(c, true),
+            ]
+        );
+
+        // already nested
+        assert_eq!(
+            nest_multisig_signers(&mut instruction, &nested, &[&b]),
+            Err(ProgramError::InvalidArgument)
+        );
+        // no signers
+        assert_eq!(
+            nest_multisig_signers(&mut instruction, &a, &[]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn test_approve_with_expiry() {
+        let [source, mint, record, delegate, owner] = [(); 5].map(|_| Pubkey::new_unique());
+
+        let instruction = approve_with_expiry(
+            &crate::id(),
+            &source,
+            &mint,
+            &record,
+            &delegate,
+            &owner,
+            &[],
+            5,
+            6,
+            Expiry::Slot(7),
+        )
+        .unwrap();
+        assert_eq!(
+            instruction.data,
+            [246, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(record, false),
+                AccountMeta::new_readonly(delegate, false),
+                AccountMeta::new_readonly(owner, true),
+            ]
+        );
+
+        // invalid expiry kind
+        let mut data = instruction.data;
+        data[10] = 2;
+        assert_eq!(
+            TokenInstruction::unpack(&data),
+            Err(TokenError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_close_delegation_record() {
+        let [record, account, destination, multisig, signer] =
+            [(); 5].map(|_| Pubkey::new_unique());
+
+        let instruction = close_delegation_record(
+            &crate::id(),
+            &record,
+            &account,
+            &destination,
+            &multisig,
+            &[&signer],
+        )
+        .unwrap();
+        assert_eq!(instruction.data, [241]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(record, false),
+                AccountMeta::new(account, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(multisig, false),
+                AccountMeta::new_readonly(signer, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adjust_allowance() {
+        let [source, mint, delegate, owner, signer] = [(); 5].map(|_| Pubkey::new_unique());
+
+        let instruction =
+            decrease_allowance(&crate::id(), &source, &delegate, &owner, &[&signer], 3).unwrap();
+        assert_eq!(instruction.data, [244, 3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(delegate, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new_readonly(signer, true),
+            ]
+        );
+
+        let instruction =
+            increase_allowance_checked(&crate::id(), &source, &mint, &delegate, &owner, &[], 3, 4)
+                .unwrap();
+        assert_eq!(instruction.data, [243, 3, 0, 0, 0, 0, 0, 0, 0, 4]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(delegate, false),
+                AccountMeta::new_readonly(owner, true),
+            ]
+        );
+
+        // missing decimals
+        assert_eq!(
+            TokenInstruction::unpack(&instruction.data[..9]),
+            Err(TokenError::InvalidInstruction.into())
+        );
     }
 
     #[test]
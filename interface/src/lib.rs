@@ -12,6 +12,7 @@ use {
 pub mod error;
 pub mod instruction;
 pub mod native_mint;
+pub mod signer_plan;
 pub mod state;
 
 solana_pubkey::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
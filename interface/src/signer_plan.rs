@@ -0,0 +1,396 @@
+//! Planning of the signers of a multisignature authority
+
+use {
+    crate::{
+        error::TokenError,
+        instruction::nest_multisig_signers,
+        state::{LargeMultisig, Multisig},
+    },
+    solana_instruction::Instruction,
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+};
+
+/// Signers selected to authorize an instruction on behalf of a multisig.
+///
+/// The plan is a minimal set of unique members of the multisig that reaches
+/// its threshold `m`, preferring the available keys. Its keys are passed to
+/// any instruction builder that takes `signer_pubkeys` with
+/// [`SignerPlan::signer_pubkeys`], or to the `*_with_plan` builders, e.g.
+/// [`transfer_checked_with_plan`](crate::instruction::transfer_checked_with_plan).
+///
+/// The planner only knows the keys of the members, so a member that is a
+/// multisig is planned as a key that must sign. To authorize through a nested
+/// multisig instead, include its key in the available keys, plan its own
+/// signers separately and attach them with [`SignerPlan::nest`]; the
+/// `*_with_plan` builders then lay them out with [`nest_multisig_signers`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignerPlan {
+    /// Available keys selected to sign, in the order of the multisig.
+    pub signers: Vec<Pubkey>,
+    /// Keys that are not available but must still sign to reach the
+    /// threshold, in the order of the multisig.
+    pub missing: Vec<Pubkey>,
+    /// Plans of the signers that authorize through their own signers as
+    /// nested multisigs, keyed by the nested multisig.
+    pub nested: Vec<(Pubkey, SignerPlan)>,
+}
+
+impl SignerPlan {
+    /// Plans the signers of a multisig from the available signing keys.
+    pub fn new(multisig: &Multisig, available: &[Pubkey]) -> Result<Self, ProgramError> {
+        Self::plan(
+            multisig.is_initialized,
+            multisig.m,
+            multisig.n,
+            &multisig.signers,
+            available,
+        )
+    }
+
+    /// Plans the signers of a large multisig from the available signing keys.
+    pub fn new_large(multisig: &LargeMultisig, available: &[Pubkey]) -> Result<Self, ProgramError> {
+        Self::plan(
+            multisig.is_initialized,
+            multisig.m,
+            multisig.n,
+            &multisig.signers,
+            available,
+        )
+    }
+
+    /// Makes the planned signer `nested_multisig_pubkey` authorize through the
+    /// signers of its own `plan`, as a nested member of a large multisig that
+    /// allows nested members.
+    pub fn nest(
+        &mut self,
+        nested_multisig_pubkey: &Pubkey,
+        plan: SignerPlan,
+    ) -> Result<(), ProgramError> {
+        if !self.signers.contains(nested_multisig_pubkey)
+            || self
+                .nested
+                .iter()
+                .any(|(key, _)| key == nested_multisig_pubkey)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.nested.push((*nested_multisig_pubkey, plan));
+        Ok(())
+    }
+
+    /// Returns `true` if all the signers of the plan, including the ones of
+    /// its nested plans, are available.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.nested.iter().all(|(_, plan)| plan.is_complete())
+    }
+
+    /// Returns the keys of all the signers of the plan, the available ones
+    /// followed by the missing ones, as expected by the `signer_pubkeys` of
+    /// the instruction builders.
+    pub fn signer_pubkeys(&self) -> Vec<&Pubkey> {
+        let mut signer_pubkeys = Vec::with_capacity(self.signers.len() + self.missing.len());
+        signer_pubkeys.extend(self.signers.iter());
+        signer_pubkeys.extend(self.missing.iter());
+        signer_pubkeys
+    }
+
+    /// Lays out the signers of the nested plans in an instruction built with
+    /// [`SignerPlan::signer_pubkeys`].
+    pub fn nest_signers(&self, instruction: &mut Instruction) -> Result<(), ProgramError> {
+        for (nested_multisig_pubkey, plan) in self.nested.iter() {
+            nest_multisig_signers(instruction, nested_multisig_pubkey, &plan.signer_pubkeys())?;
+            plan.nest_signers(instruction)?;
+        }
+        Ok(())
+    }
+
+    fn plan(
+        is_initialized: bool,
+        m: u8,
+        n: u8,
+        signers: &[Pubkey],
+        available: &[Pubkey],
+    ) -> Result<Self, ProgramError> {
+        if !is_initialized {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        let members = signers
+            .get(..n as usize)
+            .ok_or(TokenError::InvalidNumberOfProvidedSigners)?;
+        if m == 0 || m > n {
+            return Err(TokenError::InvalidNumberOfRequiredSigners.into());
+        }
+
+        // A key repeated in the multisig counts once for each of its
+        // positions, so keys with more positions are selected first.
+        let mut candidates: Vec<(usize, &Pubkey, usize)> = Vec::with_capacity(members.len());
+        for (position, key) in members.iter().enumerate() {
+            match candidates.iter_mut().find(|(_, k, _)| *k == key) {
+                Some((_, _, count)) => *count += 1,
+                None => candidates.push((position, key, 1)),
+            }
+        }
+        candidates.sort_by(|(a_position, _, a_count), (b_position, _, b_count)| {
+            b_count.cmp(a_count).then(a_position.cmp(b_position))
+        });
+
+        let mut selected = Vec::with_capacity(m as usize);
+        let mut required = m as usize;
+        // Available keys are selected before the missing ones.
+        for is_available in [true, false] {
+            for (position, key, count) in candidates.iter() {
+                if required == 0 {
+                    break;
+                }
+                if available.contains(key) == is_available {
+                    selected.push((*position, **key, is_available));
+                    required = required.saturating_sub(*count);
+                }
+            }
+        }
+        selected.sort_by_key(|(position, _, _)| *position);
+
+        let (signers, missing): (Vec<_>, Vec<_>) = selected
+            .into_iter()
+            .partition(|(_, _, is_available)| *is_available);
+
+        Ok(Self {
+            signers: signers.into_iter().map(|(_, key, _)| key).collect(),
+            missing: missing.into_iter().map(|(_, key, _)| key).collect(),
+            nested: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::instruction::{
+            transfer, transfer_checked_with_plan, MAX_LARGE_SIGNERS, MAX_SIGNERS,
+        },
+    };
+
+    fn multisig(m: u8, keys: &[Pubkey]) -> Multisig {
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        signers[..keys.len()].copy_from_slice(keys);
+        Multisig {
+            m,
+            n: keys.len() as u8,
+            is_initialized: true,
+            signers,
+        }
+    }
+
+    #[test]
+    fn test_complete_plan() {
+        let keys = [(); 5].map(|_| Pubkey::new_unique());
+        let multisig = multisig(2, &keys);
+
+        // more keys than required, in any order
+        let plan = SignerPlan::new(
+            &multisig,
+            &[keys[4], Pubkey::new_unique(), keys[1], keys[3]],
+        )
+        .unwrap();
+        assert_eq!(
+            plan,
+            SignerPlan {
+                signers: vec![keys[1], keys[3]],
+                missing: vec![],
+                nested: vec![],
+            }
+        );
+        assert!(plan.is_complete());
+
+        // duplicated available keys are selected once
+        let plan = SignerPlan::new(&multisig, &[keys[2], keys[2], keys[0]]).unwrap();
+        assert_eq!(plan.signers, vec![keys[0], keys[2]]);
+        assert!(plan.is_complete());
+    }
+
+    #[test]
+    fn test_partial_plan() {
+        let keys = [(); 5].map(|_| Pubkey::new_unique());
+        let multisig = multisig(3, &keys);
+
+        let plan = SignerPlan::new(&multisig, &[keys[3]]).unwrap();
+        assert_eq!(
+            plan,
+            SignerPlan {
+                signers: vec![keys[3]],
+                missing: vec![keys[0], keys[1]],
+                nested: vec![],
+            }
+        );
+        assert!(!plan.is_complete());
+
+        let plan = SignerPlan::new(&multisig, &[]).unwrap();
+        assert!(plan.signers.is_empty());
+        assert_eq!(plan.missing, keys[..3]);
+    }
+
+    #[test]
+    fn test_repeated_member() {
+        let keys = [(); 3].map(|_| Pubkey::new_unique());
+        let multisig = multisig(2, &[keys[0], keys[1], keys[2], keys[1]]);
+
+        // a key with two positions reaches the threshold alone
+        let plan = SignerPlan::new(&multisig, &[]).unwrap();
+        assert_eq!(plan.missing, vec![keys[1]]);
+
+        let plan = SignerPlan::new(&multisig, &[keys[0], keys[1], keys[2]]).unwrap();
+        assert_eq!(plan.signers, vec![keys[1]]);
+    }
+
+    #[test]
+    fn test_large_plan() {
+        let keys = [(); MAX_LARGE_SIGNERS].map(|_| Pubkey::new_unique());
+        let multisig = LargeMultisig {
+            m: 20,
+            n: MAX_LARGE_SIGNERS as u8,
+            is_initialized: true,
+            signers: keys,
+            allow_nested: false,
+        };
+
+        let plan = SignerPlan::new_large(&multisig, &keys[12..]).unwrap();
+        assert_eq!(plan.signers, keys[12..]);
+        assert!(plan.is_complete());
+
+        let plan = SignerPlan::new_large(&multisig, &keys[..10]).unwrap();
+        assert_eq!(plan.signers, keys[..10]);
+        assert_eq!(plan.missing, keys[10..20]);
+    }
+
+    #[test]
+    fn test_invalid_multisig() {
+        let keys = [(); 3].map(|_| Pubkey::new_unique());
+
+        let mut uninitialized = multisig(2, &keys);
+        uninitialized.is_initialized = false;
+        assert_eq!(
+            SignerPlan::new(&uninitialized, &keys),
+            Err(ProgramError::UninitializedAccount)
+        );
+
+        assert_eq!(
+            SignerPlan::new(&multisig(0, &keys), &keys),
+            Err(TokenError::InvalidNumberOfRequiredSigners.into())
+        );
+        assert_eq!(
+            SignerPlan::new(&multisig(4, &keys), &keys),
+            Err(TokenError::InvalidNumberOfRequiredSigners.into())
+        );
+
+        let mut invalid_n = multisig(2, &keys);
+        invalid_n.n = MAX_SIGNERS as u8 + 1;
+        assert_eq!(
+            SignerPlan::new(&invalid_n, &keys),
+            Err(TokenError::InvalidNumberOfProvidedSigners.into())
+        );
+    }
+
+    #[test]
+    fn test_plan_signer_pubkeys() {
+        let keys = [(); 3].map(|_| Pubkey::new_unique());
+        let multisig_pubkey = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let plan = SignerPlan::new(&multisig(2, &keys), &[keys[2]]).unwrap();
+        assert_eq!(plan.signer_pubkeys(), vec![&keys[2], &keys[0]]);
+
+        let instruction = transfer(
+            &crate::id(),
+            &source,
+            &destination,
+            &multisig_pubkey,
+            &plan.signer_pubkeys(),
+            1,
+        )
+        .unwrap();
+        let signers = instruction
+            .accounts
+            .iter()
+            .filter(|meta| meta.is_signer)
+            .map(|meta| meta.pubkey)
+            .collect::<Vec<_>>();
+        assert_eq!(signers, vec![keys[2], keys[0]]);
+    }
+
+    #[test]
+    fn test_plan_nested_member() {
+        let keys = [(); 3].map(|_| Pubkey::new_unique());
+        let nested_keys = [(); 2].map(|_| Pubkey::new_unique());
+        let [nested_pubkey, multisig_pubkey, source, mint, destination] =
+            [(); 5].map(|_| Pubkey::new_unique());
+        let outer = multisig(2, &[keys[0], nested_pubkey, keys[1]]);
+        let nested = multisig(1, &nested_keys);
+
+        // the nested multisig is planned as a key that must sign
+        let plan = SignerPlan::new(&outer, &[keys[1]]).unwrap();
+        assert_eq!(plan.missing, vec![keys[0]]);
+        let plan = SignerPlan::new(&outer, &[nested_pubkey]).unwrap();
+        assert_eq!(plan.missing, vec![keys[0]]);
+
+        // with its key available, it is nested through its own plan
+        let mut plan = SignerPlan::new(&outer, &[nested_pubkey, keys[1]]).unwrap();
+        assert_eq!(plan.signers, vec![nested_pubkey, keys[1]]);
+        let nested_plan = SignerPlan::new(&nested, &[nested_keys[1]]).unwrap();
+        assert!(nested_plan.is_complete());
+        plan.nest(&nested_pubkey, nested_plan).unwrap();
+        assert!(plan.is_complete());
+
+        let instruction = transfer_checked_with_plan(
+            &crate::id(),
+            &source,
+            &mint,
+            &destination,
+            &multisig_pubkey,
+            &plan,
+            1,
+            2,
+        )
+        .unwrap();
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == nested_pubkey && !meta.is_signer));
+        let signers = instruction
+            .accounts
+            .iter()
+            .filter(|meta| meta.is_signer)
+            .map(|meta| meta.pubkey)
+            .collect::<Vec<_>>();
+        assert_eq!(signers, vec![nested_keys[1], keys[1]]);
+    }
+
+    #[test]
+    fn test_nest_plan() {
+        let keys = [(); 3].map(|_| Pubkey::new_unique());
+        let nested_keys = [(); 2].map(|_| Pubkey::new_unique());
+        let nested = multisig(2, &nested_keys);
+
+        // only an available signer of the plan can be nested, once
+        let mut plan = SignerPlan::new(&multisig(2, &keys), &[keys[1]]).unwrap();
+        let nested_plan = SignerPlan::new(&nested, &[nested_keys[0]]).unwrap();
+        assert_eq!(
+            plan.nest(&keys[0], nested_plan.clone()),
+            Err(ProgramError::InvalidArgument)
+        );
+        plan.nest(&keys[1], nested_plan.clone()).unwrap();
+        assert_eq!(
+            plan.nest(&keys[1], nested_plan),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        // a nested plan with missing signers makes the plan incomplete
+        let mut plan = SignerPlan::new(&multisig(1, &keys), &[keys[2]]).unwrap();
+        assert!(plan.is_complete());
+        plan.nest(&keys[2], SignerPlan::new(&nested, &[]).unwrap())
+            .unwrap();
+        assert!(!plan.is_complete());
+    }
+}
@@ -1,7 +1,7 @@
 //! State transition types
 
 use {
-    crate::instruction::MAX_SIGNERS,
+    crate::instruction::{MAX_LARGE_SIGNERS, MAX_SIGNERS},
     arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
     num_enum::TryFromPrimitive,
     solana_program_error::ProgramError,
@@ -249,6 +249,184 @@ impl Pack for Multisig {
     }
 }
 
+/// Large multisignature data.
+///
+/// Has the same layout as [`Multisig`], but with room for up to
+/// `MAX_LARGE_SIGNERS` signers, followed by whether nested multisig members
+/// are allowed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LargeMultisig {
+    /// Number of signers required
+    pub m: u8,
+    /// Number of valid signers
+    pub n: u8,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// Signer public keys
+    pub signers: [Pubkey; MAX_LARGE_SIGNERS],
+    /// Is `true` if a signer that is itself a multisig can authorize without
+    /// signing, through its own signers
+    pub allow_nested: bool,
+}
+impl Sealed for LargeMultisig {}
+impl IsInitialized for LargeMultisig {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for LargeMultisig {
+    const LEN: usize = 1028;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 1028];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (m, n, is_initialized, signers_flat, allow_nested) =
+            array_refs![src, 1, 1, 1, 32 * MAX_LARGE_SIGNERS, 1];
+        let mut result = LargeMultisig {
+            m: m[0],
+            n: n[0],
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            signers: [Pubkey::new_from_array([0u8; 32]); MAX_LARGE_SIGNERS],
+            allow_nested: match allow_nested {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+        };
+        for (src, dst) in signers_flat.chunks(32).zip(result.signers.iter_mut()) {
+            *dst = Pubkey::try_from(src).map_err(|_| ProgramError::InvalidAccountData)?;
+        }
+        Ok(result)
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 1028];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (m, n, is_initialized, signers_flat, allow_nested) =
+            mut_array_refs![dst, 1, 1, 1, 32 * MAX_LARGE_SIGNERS, 1];
+        *m = [self.m];
+        *n = [self.n];
+        *is_initialized = [self.is_initialized as u8];
+        *allow_nested = [self.allow_nested as u8];
+        for (i, src) in self.signers.iter().enumerate() {
+            let dst_array = array_mut_ref![signers_flat, 32 * i, 32];
+            dst_array.copy_from_slice(src.as_ref());
+        }
+    }
+}
+
+impl From<Multisig> for LargeMultisig {
+    fn from(multisig: Multisig) -> Self {
+        let mut signers = [Pubkey::default(); MAX_LARGE_SIGNERS];
+        signers[..MAX_SIGNERS].copy_from_slice(&multisig.signers);
+        LargeMultisig {
+            m: multisig.m,
+            n: multisig.n,
+            is_initialized: multisig.is_initialized,
+            signers,
+            allow_nested: false,
+        }
+    }
+}
+
+/// Expiry of a delegation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expiry {
+    /// The delegation expires at a slot
+    Slot(u64),
+    /// The delegation expires at a unix timestamp
+    UnixTimestamp(i64),
+}
+impl Default for Expiry {
+    fn default() -> Self {
+        Expiry::Slot(0)
+    }
+}
+impl Expiry {
+    /// Unpacks an expiry from its kind and little-endian value
+    pub fn unpack(kind: u8, value: [u8; 8]) -> Option<Self> {
+        match kind {
+            0 => Some(Expiry::Slot(u64::from_le_bytes(value))),
+            1 => Some(Expiry::UnixTimestamp(i64::from_le_bytes(value))),
+            _ => None,
+        }
+    }
+    /// Packs an expiry into its kind and little-endian value
+    pub fn pack(&self) -> (u8, [u8; 8]) {
+        match self {
+            Expiry::Slot(slot) => (0, slot.to_le_bytes()),
+            Expiry::UnixTimestamp(unix_timestamp) => (1, unix_timestamp.to_le_bytes()),
+        }
+    }
+    /// Checks if the expiry has been reached at the given slot and unix
+    /// timestamp
+    pub fn is_expired(&self, slot: u64, unix_timestamp: i64) -> bool {
+        match self {
+            Expiry::Slot(expiry) => slot >= *expiry,
+            Expiry::UnixTimestamp(expiry) => unix_timestamp >= *expiry,
+        }
+    }
+}
+
+/// Delegation record of a delegation with an expiry, initialized by
+/// `ApproveWithExpiry`.
+///
+/// The key of the record is set as the delegate of the token account, so the
+/// layout of [`Account`] is unchanged.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DelegationRecord {
+    /// The token account of the delegation
+    pub account: Pubkey,
+    /// The delegate that can use the delegation
+    pub delegate: Pubkey,
+    /// The expiry of the delegation
+    pub expiry: Expiry,
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+}
+impl Sealed for DelegationRecord {}
+impl IsInitialized for DelegationRecord {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for DelegationRecord {
+    const LEN: usize = 74;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 74];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account, delegate, expiry, expiry_kind, is_initialized) =
+            array_refs![src, 32, 32, 8, 1, 1];
+        Ok(DelegationRecord {
+            account: Pubkey::new_from_array(*account),
+            delegate: Pubkey::new_from_array(*delegate),
+            expiry: Expiry::unpack(expiry_kind[0], *expiry)
+                .ok_or(ProgramError::InvalidAccountData)?,
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 74];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account, delegate, expiry, expiry_kind, is_initialized) =
+            mut_array_refs![dst, 32, 32, 8, 1, 1];
+        account.copy_from_slice(self.account.as_ref());
+        delegate.copy_from_slice(self.delegate.as_ref());
+        let (kind, value) = self.expiry.pack();
+        *expiry = value;
+        *expiry_kind = [kind];
+        *is_initialized = [self.is_initialized as u8];
+    }
+}
+
 // Helpers
 fn pack_coption_key(src: &COption<Pubkey>, dst: &mut [u8; 36]) {
     let (tag, body) = mut_array_refs![dst, 4, 32];
@@ -401,6 +579,68 @@ mod tests {
         assert_eq!(multisig, ProgramError::InvalidAccountData);
     }
 
+    #[test]
+    fn test_large_multisig_pack_unpack() {
+        let mut multisig = LargeMultisig {
+            m: 20,
+            n: MAX_LARGE_SIGNERS as u8,
+            is_initialized: true,
+            allow_nested: true,
+            ..LargeMultisig::default()
+        };
+        for (i, signer) in multisig.signers.iter_mut().enumerate() {
+            *signer = Pubkey::new_from_array([i as u8; 32]);
+        }
+
+        let mut packed = vec![0; LargeMultisig::get_packed_len()];
+        LargeMultisig::pack(multisig, &mut packed).unwrap();
+        assert_eq!(packed[..3], [20, 32, 1]);
+        assert_eq!(
+            packed[LargeMultisig::LEN - 33..LargeMultisig::LEN - 1],
+            [31; 32]
+        );
+        assert_eq!(packed[LargeMultisig::LEN - 1], 1);
+        let unpacked = LargeMultisig::unpack(&packed).unwrap();
+        assert_eq!(unpacked, multisig);
+
+        packed[LargeMultisig::LEN - 1] = 2;
+        let err = LargeMultisig::unpack_from_slice(&packed).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+
+        packed[LargeMultisig::LEN - 1] = 1;
+        packed[2] = 2;
+        let err = LargeMultisig::unpack_from_slice(&packed).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn test_delegation_record_pack_unpack() {
+        let record = DelegationRecord {
+            account: Pubkey::new_from_array([1; 32]),
+            delegate: Pubkey::new_from_array([2; 32]),
+            expiry: Expiry::UnixTimestamp(-1),
+            is_initialized: true,
+        };
+
+        let mut packed = vec![0; DelegationRecord::get_packed_len()];
+        DelegationRecord::pack(record, &mut packed).unwrap();
+        assert_eq!(
+            packed[64..],
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 1, 1]
+        );
+        let unpacked = DelegationRecord::unpack(&packed).unwrap();
+        assert_eq!(unpacked, record);
+
+        assert!(!Expiry::Slot(10).is_expired(9, i64::MAX));
+        assert!(Expiry::Slot(10).is_expired(10, 0));
+        assert!(Expiry::UnixTimestamp(-1).is_expired(0, -1));
+
+        // invalid expiry kind
+        packed[72] = 2;
+        let err = DelegationRecord::unpack_from_slice(&packed).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+    }
+
     #[test]
     fn test_unpack_coption_key() {
         let src: [u8; 36] = [0; 36];
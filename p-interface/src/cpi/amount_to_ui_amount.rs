@@ -0,0 +1,42 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Converts an amount of tokens to a `UiAmount` string, which is set as the
+/// return data.
+///
+/// See [`TokenInstruction::AmountToUiAmount`] for the accounts and data
+/// expected.
+pub struct AmountToUiAmount<'a> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountInfo,
+    /// The amount of tokens to reformat.
+    pub amount: u64,
+}
+
+impl AmountToUiAmount<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 9>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for AmountToUiAmount<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, false, false)?;
+
+        data.write(&[TokenInstruction::AmountToUiAmount as u8])?;
+        data.write(&self.amount.to_le_bytes())
+    }
+}
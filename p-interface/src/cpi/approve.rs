@@ -0,0 +1,48 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Approves a delegate.
+///
+/// See [`TokenInstruction::Approve`] for the accounts and data expected.
+pub struct Approve<'a, 'b> {
+    /// The source account.
+    pub source: &'a AccountInfo,
+    /// The delegate.
+    pub delegate: &'a AccountInfo,
+    /// The source account's owner.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens the delegate is approved for.
+    pub amount: u64,
+}
+
+impl Approve<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 9>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for Approve<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.source, true, false)?;
+        accounts.push(self.delegate, false, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::Approve as u8])?;
+        data.write(&self.amount.to_le_bytes())
+    }
+}
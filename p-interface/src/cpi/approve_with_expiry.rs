@@ -0,0 +1,64 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::{instruction::TokenInstruction, state::delegation::ExpiryKind},
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Approves a delegate until an expiry, asserting the token mint and
+/// decimals.
+///
+/// See [`TokenInstruction::ApproveWithExpiry`] for the accounts and data
+/// expected.
+pub struct ApproveWithExpiry<'a, 'b> {
+    /// The source account.
+    pub source: &'a AccountInfo,
+    /// The token mint.
+    pub mint: &'a AccountInfo,
+    /// The delegation record.
+    pub delegation_record: &'a AccountInfo,
+    /// The delegate.
+    pub delegate: &'a AccountInfo,
+    /// The source account's owner.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens the delegate is approved for.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The kind of expiry.
+    pub expiry_kind: ExpiryKind,
+    /// The expiry slot, or unix timestamp as an `i64`.
+    pub expiry: u64,
+}
+
+impl ApproveWithExpiry<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 5 + MAX_MULTISIG_SIGNERS }, 19>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for ApproveWithExpiry<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.source, true, false)?;
+        accounts.push(self.mint, false, false)?;
+        accounts.push(self.delegation_record, true, false)?;
+        accounts.push(self.delegate, false, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::ApproveWithExpiry as u8])?;
+        data.write(&self.amount.to_le_bytes())?;
+        data.write(&[self.decimals, self.expiry_kind as u8])?;
+        data.write(&self.expiry.to_le_bytes())
+    }
+}
@@ -0,0 +1,267 @@
+use {
+    super::{
+        invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_DYNAMIC_ACCOUNTS,
+        MAX_DYNAMIC_DATA_LEN,
+    },
+    crate::instruction::{TokenInstruction, IX_HEADER_SIZE, MAX_INDEXED_ACCOUNTS},
+    core::mem::MaybeUninit,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        program_error::ProgramError,
+        ProgramResult,
+    },
+};
+
+/// Executes a batch of instructions, failing on the first error.
+///
+/// See [`TokenInstruction::Batch`] for the accounts and data expected.
+pub struct Batch<'a, 'b> {
+    /// The instructions to execute, which cannot be batch instructions.
+    pub instructions: &'b [&'b dyn CpiInstruction<'a>],
+}
+
+impl Batch<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for Batch<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        write_batch(TokenInstruction::Batch, self.instructions, accounts, data)
+    }
+}
+
+/// Executes a batch of instructions, skipping the instructions that fail
+/// without modifying any account.
+///
+/// See [`TokenInstruction::BestEffortBatch`] for the accounts and data
+/// expected.
+pub struct BestEffortBatch<'a, 'b> {
+    /// The instructions to execute, which cannot be batch instructions.
+    pub instructions: &'b [&'b dyn CpiInstruction<'a>],
+}
+
+impl BestEffortBatch<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for BestEffortBatch<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        write_batch(
+            TokenInstruction::BestEffortBatch,
+            self.instructions,
+            accounts,
+            data,
+        )
+    }
+}
+
+/// Executes a batch of instructions that reference their accounts by index,
+/// failing on the first error.
+///
+/// Accounts referenced by several instructions are only included once in the
+/// accounts of the batch.
+///
+/// See [`TokenInstruction::IndexedBatch`] for the accounts and data expected.
+pub struct IndexedBatch<'a, 'b> {
+    /// The instructions to execute, which cannot be batch instructions.
+    pub instructions: &'b [&'b dyn CpiInstruction<'a>],
+}
+
+impl IndexedBatch<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for IndexedBatch<'a, '_> {
+    #[allow(clippy::arithmetic_side_effects)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        data.write(&[TokenInstruction::IndexedBatch as u8])?;
+
+        for instruction in self.instructions {
+            let mut metas = [const { MaybeUninit::<AccountMeta>::uninit() }; MAX_INDEXED_ACCOUNTS];
+            let mut infos = [MaybeUninit::<&AccountInfo>::uninit(); MAX_INDEXED_ACCOUNTS];
+            let mut instruction_accounts = CpiAccounts::new(&mut metas, &mut infos);
+
+            let header = data.len();
+            data.write(&[0; IX_HEADER_SIZE])?;
+            instruction.write(&mut instruction_accounts, data)?;
+            write_header(data, header, instruction_accounts.len())?;
+
+            // The index of each account precedes the instruction data.
+            for (position, (meta, account)) in instruction_accounts
+                .metas()
+                .iter()
+                .zip(instruction_accounts.infos())
+                .enumerate()
+            {
+                let index = accounts.push_unique(account, meta.is_writable, meta.is_signer)?;
+                data.insert(
+                    header + IX_HEADER_SIZE + position,
+                    &[u8::try_from(index).map_err(|_| ProgramError::InvalidArgument)?],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the accounts and instruction data of a `Batch` or
+/// `BestEffortBatch` instruction.
+#[allow(clippy::arithmetic_side_effects)]
+fn write_batch<'a>(
+    batch: TokenInstruction,
+    instructions: &[&dyn CpiInstruction<'a>],
+    accounts: &mut CpiAccounts<'a, '_>,
+    data: &mut CpiData<'_>,
+) -> ProgramResult {
+    data.write(&[batch as u8])?;
+
+    for instruction in instructions {
+        let header = data.len();
+        let accounts_len = accounts.len();
+
+        data.write(&[0; IX_HEADER_SIZE])?;
+        instruction.write(accounts, data)?;
+        write_header(data, header, accounts.len() - accounts_len)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the header of the instruction of a batch at `offset`, given its
+/// number of accounts. The instruction data follows the header.
+#[allow(clippy::arithmetic_side_effects)]
+fn write_header(data: &mut CpiData<'_>, offset: usize, accounts_len: usize) -> ProgramResult {
+    let data_len = data.len() - offset - IX_HEADER_SIZE;
+
+    data.set(
+        offset,
+        u8::try_from(accounts_len).map_err(|_| ProgramError::InvalidArgument)?,
+    );
+    data.set(
+        offset + 1,
+        u8::try_from(data_len).map_err(|_| ProgramError::InvalidArgument)?,
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            cpi::{tests::write, Transfer, TransferChecked},
+            state::tests::TestAccount,
+        },
+    };
+
+    #[test]
+    fn test_batch() {
+        let [mut source, mut mint, mut destination, mut owner] = [1, 2, 3, 4].map(TestAccount::new);
+        let (source, mint, destination, owner) =
+            (source.info(), mint.info(), destination.info(), owner.info());
+
+        let transfer = Transfer {
+            from: &source,
+            to: &destination,
+            authority: &owner,
+            multisig_signers: &[],
+            amount: 1,
+        };
+        let transfer_checked = TransferChecked {
+            from: &source,
+            mint: &mint,
+            to: &destination,
+            authority: &owner,
+            multisig_signers: &[],
+            amount: 2,
+            decimals: 6,
+        };
+
+        let (accounts, data) = write(&Batch {
+            instructions: &[&transfer, &transfer_checked],
+        })
+        .unwrap();
+
+        assert_eq!(
+            accounts,
+            [
+                (1, true, false),
+                (3, true, false),
+                (4, false, true),
+                (1, true, false),
+                (2, false, false),
+                (3, true, false),
+                (4, false, true),
+            ]
+        );
+        assert_eq!(
+            data,
+            [
+                255, // Batch
+                3, 9, 3, 1, 0, 0, 0, 0, 0, 0, 0, // Transfer
+                4, 10, 12, 2, 0, 0, 0, 0, 0, 0, 0, 6, // TransferChecked
+            ]
+        );
+
+        let (accounts, data) = write(&IndexedBatch {
+            instructions: &[&transfer, &transfer_checked],
+        })
+        .unwrap();
+
+        assert_eq!(
+            accounts,
+            [
+                (1, true, false),
+                (3, true, false),
+                (4, false, true),
+                (2, false, false),
+            ]
+        );
+        assert_eq!(
+            data,
+            [
+                254, // IndexedBatch
+                3, 9, 0, 1, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, // Transfer
+                4, 10, 0, 3, 1, 2, 12, 2, 0, 0, 0, 0, 0, 0, 0, 6, // TransferChecked
+            ]
+        );
+    }
+}
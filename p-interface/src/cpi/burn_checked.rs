@@ -0,0 +1,52 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Burns tokens by removing them from an account, asserting the token mint
+/// and decimals.
+///
+/// See [`TokenInstruction::BurnChecked`] for the accounts and data expected.
+pub struct BurnChecked<'a, 'b> {
+    /// The account to burn from.
+    pub account: &'a AccountInfo,
+    /// The token mint.
+    pub mint: &'a AccountInfo,
+    /// The account's owner/delegate.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens to burn.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl BurnChecked<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 10>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for BurnChecked<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.mint, true, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::BurnChecked as u8])?;
+        data.write(&self.amount.to_le_bytes())?;
+        data.write(&[self.decimals])
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Closes an account by transferring all its SOL to the destination account.
+///
+/// See [`TokenInstruction::CloseAccount`] for the accounts and data expected.
+pub struct CloseAccount<'a, 'b> {
+    /// The account to close.
+    pub account: &'a AccountInfo,
+    /// The destination account.
+    pub destination: &'a AccountInfo,
+    /// The account's owner.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+}
+
+impl CloseAccount<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for CloseAccount<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.destination, true, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::CloseAccount as u8])
+    }
+}
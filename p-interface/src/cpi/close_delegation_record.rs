@@ -0,0 +1,50 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Closes a delegation record by transferring all its SOL to the destination
+/// account.
+///
+/// See [`TokenInstruction::CloseDelegationRecord`] for the accounts and data
+/// expected.
+pub struct CloseDelegationRecord<'a, 'b> {
+    /// The delegation record.
+    pub delegation_record: &'a AccountInfo,
+    /// The token account of the delegation.
+    pub account: &'a AccountInfo,
+    /// The destination account.
+    pub destination: &'a AccountInfo,
+    /// The token account's owner.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+}
+
+impl CloseDelegationRecord<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 4 + MAX_MULTISIG_SIGNERS }, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for CloseDelegationRecord<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.delegation_record, true, false)?;
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.destination, true, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::CloseDelegationRecord as u8])
+    }
+}
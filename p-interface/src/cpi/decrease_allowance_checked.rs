@@ -0,0 +1,56 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Decreases the allowance of the current delegate, asserting the token mint
+/// and decimals.
+///
+/// See [`TokenInstruction::DecreaseAllowanceChecked`] for the accounts and data
+/// expected.
+pub struct DecreaseAllowanceChecked<'a, 'b> {
+    /// The source account.
+    pub source: &'a AccountInfo,
+    /// The token mint.
+    pub mint: &'a AccountInfo,
+    /// The delegate.
+    pub delegate: &'a AccountInfo,
+    /// The source account's owner.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens to remove from the allowance.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl DecreaseAllowanceChecked<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 4 + MAX_MULTISIG_SIGNERS }, 10>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for DecreaseAllowanceChecked<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.source, true, false)?;
+        accounts.push(self.mint, false, false)?;
+        accounts.push(self.delegate, false, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::DecreaseAllowanceChecked as u8])?;
+        data.write(&self.amount.to_le_bytes())?;
+        data.write(&[self.decimals])
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Freezes an initialized account using the mint's freeze authority.
+///
+/// See [`TokenInstruction::FreezeAccount`] for the accounts and data expected.
+pub struct FreezeAccount<'a, 'b> {
+    /// The account to freeze.
+    pub account: &'a AccountInfo,
+    /// The token mint.
+    pub mint: &'a AccountInfo,
+    /// The mint freeze authority.
+    pub freeze_authority: &'a AccountInfo,
+    /// The signer accounts when `freeze_authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+}
+
+impl FreezeAccount<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for FreezeAccount<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.mint, false, false)?;
+        accounts.push_authority(self.freeze_authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::FreezeAccount as u8])
+    }
+}
@@ -0,0 +1,39 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Gets the required size of an account for the given mint, which is set as
+/// the return data.
+///
+/// See [`TokenInstruction::GetAccountDataSize`] for the accounts and data
+/// expected.
+pub struct GetAccountDataSize<'a> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountInfo,
+}
+
+impl GetAccountDataSize<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for GetAccountDataSize<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, false, false)?;
+
+        data.write(&[TokenInstruction::GetAccountDataSize as u8])
+    }
+}
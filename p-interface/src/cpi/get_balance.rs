@@ -0,0 +1,37 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Gets the balance of a token account, which is set as the return data.
+///
+/// See [`TokenInstruction::GetBalance`] for the accounts expected.
+pub struct GetBalance<'a> {
+    /// The token account.
+    pub account: &'a AccountInfo,
+}
+
+impl GetBalance<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for GetBalance<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, false, false)?;
+
+        data.write(&[TokenInstruction::GetBalance as u8])
+    }
+}
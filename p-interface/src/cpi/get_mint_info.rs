@@ -0,0 +1,38 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Gets the supply, decimals and authorities of a mint, which are set as the
+/// return data.
+///
+/// See [`TokenInstruction::GetMintInfo`] for the accounts expected.
+pub struct GetMintInfo<'a> {
+    /// The mint.
+    pub mint: &'a AccountInfo,
+}
+
+impl GetMintInfo<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for GetMintInfo<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, false, false)?;
+
+        data.write(&[TokenInstruction::GetMintInfo as u8])
+    }
+}
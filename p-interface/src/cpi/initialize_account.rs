@@ -0,0 +1,47 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Initializes a new account to hold tokens.
+///
+/// See [`TokenInstruction::InitializeAccount`] for the accounts and data
+/// expected.
+pub struct InitializeAccount<'a> {
+    /// The account to initialize.
+    pub account: &'a AccountInfo,
+    /// The mint this account will be associated with.
+    pub mint: &'a AccountInfo,
+    /// The new account's owner/multisignature.
+    pub owner: &'a AccountInfo,
+    /// Rent sysvar.
+    pub rent_sysvar: &'a AccountInfo,
+}
+
+impl InitializeAccount<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<4, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeAccount<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.mint, false, false)?;
+        accounts.push(self.owner, false, false)?;
+        accounts.push(self.rent_sysvar, false, false)?;
+
+        data.write(&[TokenInstruction::InitializeAccount as u8])
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Initializes a new account to hold tokens, with the owner passed in the
+/// instruction data and without requiring the Rent sysvar.
+///
+/// See [`TokenInstruction::InitializeAccount3`] for the accounts and data
+/// expected.
+pub struct InitializeAccount3<'a> {
+    /// The account to initialize.
+    pub account: &'a AccountInfo,
+    /// The mint this account will be associated with.
+    pub mint: &'a AccountInfo,
+    /// The new account's owner/multisignature.
+    pub owner: &'a Pubkey,
+}
+
+impl InitializeAccount3<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<2, 33>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeAccount3<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push(self.mint, false, false)?;
+
+        data.write(&[TokenInstruction::InitializeAccount3 as u8])?;
+        data.write(self.owner)
+    }
+}
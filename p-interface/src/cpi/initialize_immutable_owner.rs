@@ -0,0 +1,38 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Initializes the Immutable Owner extension for the given token account.
+///
+/// See [`TokenInstruction::InitializeImmutableOwner`] for the accounts and data
+/// expected.
+pub struct InitializeImmutableOwner<'a> {
+    /// The account to initialize.
+    pub account: &'a AccountInfo,
+}
+
+impl InitializeImmutableOwner<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeImmutableOwner<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+
+        data.write(&[TokenInstruction::InitializeImmutableOwner as u8])
+    }
+}
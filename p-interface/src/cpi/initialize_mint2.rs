@@ -0,0 +1,47 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, OPTION_PUBKEY_LEN},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Initializes a new mint without requiring the Rent sysvar.
+///
+/// See [`TokenInstruction::InitializeMint2`] for the accounts and data
+/// expected.
+pub struct InitializeMint2<'a> {
+    /// The mint to initialize.
+    pub mint: &'a AccountInfo,
+    /// The number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The authority/multisignature to mint tokens.
+    pub mint_authority: &'a Pubkey,
+    /// The freeze authority/multisignature of the mint.
+    pub freeze_authority: Option<&'a Pubkey>,
+}
+
+impl InitializeMint2<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, { 34 + OPTION_PUBKEY_LEN }>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeMint2<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, true, false)?;
+
+        data.write(&[TokenInstruction::InitializeMint2 as u8])?;
+        data.write(&[self.decimals])?;
+        data.write(self.mint_authority)?;
+        data.write_option_pubkey(self.freeze_authority)
+    }
+}
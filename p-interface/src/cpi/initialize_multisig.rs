@@ -0,0 +1,50 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Initializes a multisignature account with N provided signers.
+///
+/// See [`TokenInstruction::InitializeMultisig`] for the accounts and data
+/// expected.
+pub struct InitializeMultisig<'a, 'b> {
+    /// The multisignature account to initialize.
+    pub multisig: &'a AccountInfo,
+    /// Rent sysvar.
+    pub rent_sysvar: &'a AccountInfo,
+    /// The signer accounts, where `1 <= N <= 11`.
+    pub signers: &'b [&'a AccountInfo],
+    /// The number of signers (M) required to validate this multisignature
+    /// account.
+    pub m: u8,
+}
+
+impl InitializeMultisig<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 2 + MAX_MULTISIG_SIGNERS }, 2>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeMultisig<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.multisig, true, false)?;
+        accounts.push(self.rent_sysvar, false, false)?;
+        // The signers do not need to sign the initialization.
+        self.signers
+            .iter()
+            .try_for_each(|signer| accounts.push(signer, false, false))?;
+
+        data.write(&[TokenInstruction::InitializeMultisig as u8, self.m])
+    }
+}
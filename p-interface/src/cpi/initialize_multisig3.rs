@@ -0,0 +1,55 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Initializes a large multisignature account with N provided signers,
+/// without requiring the Rent sysvar.
+///
+/// See [`TokenInstruction::InitializeMultisig3`] for the accounts and data
+/// expected.
+pub struct InitializeMultisig3<'a, 'b> {
+    /// The multisignature account to initialize.
+    pub multisig: &'a AccountInfo,
+    /// The signer accounts, where `1 <= N <= 32`.
+    pub signers: &'b [&'a AccountInfo],
+    /// The number of signers (M) required to validate this multisignature
+    /// account.
+    pub m: u8,
+    /// Whether signers that are multisigs can authorize through their own
+    /// signers.
+    pub allow_nested: bool,
+}
+
+impl InitializeMultisig3<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 1 + MAX_MULTISIG_SIGNERS }, 2>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for InitializeMultisig3<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.multisig, true, false)?;
+        // The signers do not need to sign the initialization.
+        self.signers
+            .iter()
+            .try_for_each(|signer| accounts.push(signer, false, false))?;
+
+        data.write(&[
+            TokenInstruction::InitializeMultisig3 as u8,
+            self.m,
+            self.allow_nested as u8,
+        ])
+    }
+}
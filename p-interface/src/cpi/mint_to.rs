@@ -0,0 +1,48 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Mints new tokens to an account.
+///
+/// See [`TokenInstruction::MintTo`] for the accounts and data expected.
+pub struct MintTo<'a, 'b> {
+    /// The mint.
+    pub mint: &'a AccountInfo,
+    /// The account to mint tokens to.
+    pub account: &'a AccountInfo,
+    /// The mint's minting authority.
+    pub mint_authority: &'a AccountInfo,
+    /// The signer accounts when `mint_authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of new tokens to mint.
+    pub amount: u64,
+}
+
+impl MintTo<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 9>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for MintTo<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, true, false)?;
+        accounts.push(self.account, true, false)?;
+        accounts.push_authority(self.mint_authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::MintTo as u8])?;
+        data.write(&self.amount.to_le_bytes())
+    }
+}
@@ -0,0 +1,68 @@
+use {
+    super::{
+        invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_DYNAMIC_ACCOUNTS,
+        MAX_DYNAMIC_DATA_LEN,
+    },
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError, ProgramResult,
+    },
+};
+
+/// Mints new tokens to multiple accounts, asserting the token decimals.
+///
+/// Each account receives the amount at the same position in `amounts`, so
+/// both slices must have the same length.
+///
+/// See [`TokenInstruction::MintToMany`] for the accounts and data expected.
+pub struct MintToMany<'a, 'b> {
+    /// The mint.
+    pub mint: &'a AccountInfo,
+    /// The mint's minting authority.
+    pub mint_authority: &'a AccountInfo,
+    /// The accounts to mint tokens to.
+    pub to: &'b [&'a AccountInfo],
+    /// The signer accounts when `mint_authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of new tokens to mint to each account.
+    pub amounts: &'b [u64],
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl MintToMany<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for MintToMany<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        if self.to.len() != self.amounts.len() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        accounts.push(self.mint, true, false)?;
+        accounts.push(self.mint_authority, false, self.multisig_signers.is_empty())?;
+        self.to
+            .iter()
+            .try_for_each(|to| accounts.push(to, true, false))?;
+        // The signer accounts follow the destination accounts.
+        accounts.push_signers(self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::MintToMany as u8, self.decimals])?;
+        self.amounts
+            .iter()
+            .try_for_each(|amount| data.write(&amount.to_le_bytes()))
+    }
+}
@@ -0,0 +1,447 @@
+//! Cross-program invocation helpers.
+//!
+//! Each instruction of the token program is represented by a struct holding
+//! its accounts and arguments, which can be invoked with `invoke` or
+//! `invoke_signed`. Instructions with an authority accept the signer accounts
+//! of a multisignature authority in `multisig_signers`; when it is empty, the
+//! authority itself must sign. A nested multisig member that does not sign is
+//! included in `multisig_signers`, followed by its own signer accounts.
+//!
+//! The accounts and instruction data are written to buffers on the stack, so
+//! no allocation is required.
+
+mod amount_to_ui_amount;
+mod approve;
+mod approve_checked;
+mod approve_with_expiry;
+mod batch;
+mod burn;
+mod burn_checked;
+mod close_account;
+mod close_delegation_record;
+mod decrease_allowance;
+mod decrease_allowance_checked;
+mod freeze_account;
+mod get_account_data_size;
+mod get_balance;
+mod get_mint_info;
+mod increase_allowance;
+mod increase_allowance_checked;
+mod initialize_account;
+mod initialize_account2;
+mod initialize_account3;
+mod initialize_immutable_owner;
+mod initialize_mint;
+mod initialize_mint2;
+mod initialize_multisig;
+mod initialize_multisig2;
+mod initialize_multisig3;
+mod mint_to;
+mod mint_to_checked;
+mod mint_to_many;
+mod revoke;
+mod set_authority;
+mod set_multisig_signers;
+mod sync_native;
+mod thaw_account;
+mod transfer;
+mod transfer_checked;
+mod transfer_many;
+mod ui_amount_to_amount;
+mod withdraw_excess_lamports;
+
+use {
+    crate::state::{
+        multisig::{LargeMultisig, Multisig, MAX_LARGE_SIGNERS},
+        Transmutable,
+    },
+    core::mem::MaybeUninit,
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::invoke_signed_with_bounds,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+pub use {
+    amount_to_ui_amount::*, approve::*, approve_checked::*, approve_with_expiry::*, batch::*,
+    burn::*, burn_checked::*, close_account::*, close_delegation_record::*, decrease_allowance::*,
+    decrease_allowance_checked::*, freeze_account::*, get_account_data_size::*, get_balance::*,
+    get_mint_info::*, increase_allowance::*, increase_allowance_checked::*, initialize_account::*,
+    initialize_account2::*, initialize_account3::*, initialize_immutable_owner::*,
+    initialize_mint::*, initialize_mint2::*, initialize_multisig::*, initialize_multisig2::*,
+    initialize_multisig3::*, mint_to::*, mint_to_checked::*, mint_to_many::*, revoke::*,
+    set_authority::*, set_multisig_signers::*, sync_native::*, thaw_account::*, transfer::*,
+    transfer_checked::*, transfer_many::*, ui_amount_to_amount::*, withdraw_excess_lamports::*,
+};
+
+/// Maximum number of accounts of an instruction with a variable number of
+/// accounts, i.e., `TransferMany`, `MintToMany` and batch instructions.
+pub const MAX_DYNAMIC_ACCOUNTS: usize = 32;
+
+/// Maximum length of the instruction data of an instruction with variable
+/// length data, i.e., `UiAmountToAmount`, `TransferMany`, `MintToMany` and
+/// batch instructions.
+pub const MAX_DYNAMIC_DATA_LEN: usize = 512;
+
+/// Maximum number of signer accounts of a multisignature authority, which
+/// can be a large multisignature.
+const MAX_MULTISIG_SIGNERS: usize = MAX_LARGE_SIGNERS as usize;
+
+/// Length of an `Option<Pubkey>` encoded in the instruction data when it is
+/// present.
+const OPTION_PUBKEY_LEN: usize = 1 + core::mem::size_of::<Pubkey>();
+
+/// An instruction of the token program that can be invoked through a
+/// cross-program invocation.
+///
+/// Besides being invoked on their own, instructions can be included in a
+/// batch instruction.
+pub trait CpiInstruction<'a> {
+    /// Writes the accounts and instruction data of the instruction.
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult;
+}
+
+/// Accounts of an instruction, written to a buffer.
+pub struct CpiAccounts<'a, 'b> {
+    metas: &'b mut [MaybeUninit<AccountMeta<'a>>],
+    infos: &'b mut [MaybeUninit<&'a AccountInfo>],
+    len: usize,
+}
+
+impl<'a, 'b> CpiAccounts<'a, 'b> {
+    /// Creates an empty list of accounts backed by the given buffers.
+    ///
+    /// The capacity is the length of the shortest buffer.
+    #[inline(always)]
+    pub fn new(
+        metas: &'b mut [MaybeUninit<AccountMeta<'a>>],
+        infos: &'b mut [MaybeUninit<&'a AccountInfo>],
+    ) -> Self {
+        Self {
+            metas,
+            infos,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of accounts written.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no account was written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes an account, returning [`ProgramError::InvalidArgument`] when
+    /// the buffers are full.
+    #[allow(clippy::arithmetic_side_effects)]
+    #[inline(always)]
+    pub fn push(
+        &mut self,
+        account: &'a AccountInfo,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> ProgramResult {
+        if self.len >= self.metas.len() || self.len >= self.infos.len() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.metas[self.len].write(AccountMeta::new(account.key(), is_writable, is_signer));
+        self.infos[self.len].write(account);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Writes an authority followed by the signer accounts of a
+    /// multisignature authority.
+    ///
+    /// The authority is a signer only when `multisig_signers` is empty.
+    #[inline(always)]
+    pub fn push_authority(
+        &mut self,
+        authority: &'a AccountInfo,
+        multisig_signers: &[&'a AccountInfo],
+    ) -> ProgramResult {
+        self.push(authority, false, multisig_signers.is_empty())?;
+        self.push_signers(multisig_signers)
+    }
+
+    /// Writes the signer accounts of a multisignature authority.
+    ///
+    /// A multisig account of the token program that did not sign is a nested
+    /// member, which authorizes through the signer accounts that follow it, so
+    /// it is written as a non-signer.
+    #[inline(always)]
+    pub fn push_signers(&mut self, multisig_signers: &[&'a AccountInfo]) -> ProgramResult {
+        multisig_signers
+            .iter()
+            .try_for_each(|signer| self.push(signer, false, !is_nested_multisig(signer)))
+    }
+
+    /// Writes an account unless it was already written, returning its
+    /// position in the accounts. When the account was already written, it
+    /// becomes writable or signer if requested.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn push_unique(
+        &mut self,
+        account: &'a AccountInfo,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> Result<usize, ProgramError> {
+        if let Some(position) = self
+            .metas()
+            .iter()
+            .position(|meta| meta.pubkey == account.key())
+        {
+            // SAFETY: `position` is lower than the number of accounts written.
+            let meta = unsafe { self.metas[position].assume_init_mut() };
+            meta.is_writable |= is_writable;
+            meta.is_signer |= is_signer;
+            return Ok(position);
+        }
+
+        self.push(account, is_writable, is_signer)?;
+        Ok(self.len - 1)
+    }
+
+    /// Returns the account metas written.
+    #[inline(always)]
+    pub fn metas(&self) -> &[AccountMeta<'a>] {
+        // SAFETY: the first `len` account metas were written.
+        unsafe { core::slice::from_raw_parts(self.metas.as_ptr() as *const _, self.len) }
+    }
+
+    /// Returns the account infos written.
+    #[inline(always)]
+    pub fn infos(&self) -> &[&'a AccountInfo] {
+        // SAFETY: the first `len` account infos were written.
+        unsafe { core::slice::from_raw_parts(self.infos.as_ptr() as *const _, self.len) }
+    }
+}
+
+/// Instruction data, written to a buffer.
+pub struct CpiData<'b> {
+    data: &'b mut [MaybeUninit<u8>],
+    len: usize,
+}
+
+impl<'b> CpiData<'b> {
+    /// Creates empty instruction data backed by the given buffer.
+    #[inline(always)]
+    pub fn new(data: &'b mut [MaybeUninit<u8>]) -> Self {
+        Self { data, len: 0 }
+    }
+
+    /// Returns the number of bytes written.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no byte was written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes `bytes`, returning [`ProgramError::InvalidArgument`] when the
+    /// buffer is full.
+    #[inline(always)]
+    pub fn write(&mut self, bytes: &[u8]) -> ProgramResult {
+        let end = self
+            .len
+            .checked_add(bytes.len())
+            .filter(|end| *end <= self.data.len())
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // SAFETY: `data` has at least `end` bytes and `MaybeUninit<u8>` has
+        // the same layout as `u8`.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                self.data.as_mut_ptr().add(self.len) as *mut u8,
+                bytes.len(),
+            );
+        }
+        self.len = end;
+
+        Ok(())
+    }
+
+    /// Writes an optional public key, encoded as a `u8` tag followed by the
+    /// public key when it is present.
+    #[inline(always)]
+    pub fn write_option_pubkey(&mut self, pubkey: Option<&Pubkey>) -> ProgramResult {
+        match pubkey {
+            Some(pubkey) => {
+                self.write(&[1])?;
+                self.write(pubkey)
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    /// Overwrites the byte at `offset`, which must have been written.
+    fn set(&mut self, offset: usize, value: u8) {
+        self.data[offset].write(value);
+    }
+
+    /// Inserts `bytes` at `offset`, moving the bytes written after it.
+    fn insert(&mut self, offset: usize, bytes: &[u8]) -> ProgramResult {
+        self.write(bytes)?;
+        self.data[offset..self.len].rotate_right(bytes.len());
+        Ok(())
+    }
+
+    /// Returns the bytes written.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: the first `len` bytes were written.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const u8, self.len) }
+    }
+}
+
+/// Checks whether a signer account of a multisignature authority is a nested
+/// multisig, i.e., a multisig account of the token program that did not sign.
+#[inline(always)]
+fn is_nested_multisig(account: &AccountInfo) -> bool {
+    !account.is_signer()
+        && account.is_owned_by(&crate::program::ID)
+        && matches!(account.data_len(), Multisig::LEN | LargeMultisig::LEN)
+}
+
+/// Invokes an instruction with at most `ACCOUNTS` accounts and `DATA_LEN`
+/// bytes of instruction data.
+#[inline(always)]
+fn invoke_signed<'a, const ACCOUNTS: usize, const DATA_LEN: usize>(
+    instruction: &impl CpiInstruction<'a>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut metas = [const { MaybeUninit::<AccountMeta>::uninit() }; ACCOUNTS];
+    let mut infos = [MaybeUninit::<&AccountInfo>::uninit(); ACCOUNTS];
+    let mut data = [MaybeUninit::<u8>::uninit(); DATA_LEN];
+
+    let mut accounts = CpiAccounts::new(&mut metas, &mut infos);
+    let mut data = CpiData::new(&mut data);
+    instruction.write(&mut accounts, &mut data)?;
+
+    invoke_signed_with_bounds::<ACCOUNTS>(
+        &Instruction {
+            program_id: &crate::program::ID,
+            data: data.as_slice(),
+            accounts: accounts.metas(),
+        },
+        accounts.infos(),
+        signers,
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    extern crate std;
+
+    use {super::*, crate::state::tests::TestAccount, std::vec::Vec};
+
+    /// Account meta of a test account as `(key[0], is_writable, is_signer)`.
+    pub(crate) type TestMeta = (u8, bool, bool);
+
+    /// Writes an instruction, returning its accounts and its data.
+    pub(crate) fn write(
+        instruction: &dyn CpiInstruction<'_>,
+    ) -> Result<(Vec<TestMeta>, Vec<u8>), ProgramError> {
+        let mut metas = [const { MaybeUninit::<AccountMeta>::uninit() }; MAX_DYNAMIC_ACCOUNTS];
+        let mut infos = [MaybeUninit::<&AccountInfo>::uninit(); MAX_DYNAMIC_ACCOUNTS];
+        let mut buffer = [MaybeUninit::<u8>::uninit(); MAX_DYNAMIC_DATA_LEN];
+
+        let mut accounts = CpiAccounts::new(&mut metas, &mut infos);
+        let mut data = CpiData::new(&mut buffer);
+        instruction.write(&mut accounts, &mut data)?;
+
+        let accounts = accounts
+            .metas()
+            .iter()
+            .zip(accounts.infos())
+            .map(|(meta, info)| {
+                assert_eq!(meta.pubkey, info.key());
+                (meta.pubkey[0], meta.is_writable, meta.is_signer)
+            })
+            .collect();
+
+        Ok((accounts, data.as_slice().to_vec()))
+    }
+
+    #[test]
+    fn test_transfer_with_multisig() {
+        let [mut from, mut to, mut authority, mut signer1, mut signer2] =
+            [1, 2, 3, 4, 5].map(TestAccount::new);
+        let (signer1, signer2) = (signer1.info(), signer2.info());
+
+        let (accounts, data) = write(&Transfer {
+            from: &from.info(),
+            to: &to.info(),
+            authority: &authority.info(),
+            multisig_signers: &[&signer1, &signer2],
+            amount: 42,
+        })
+        .unwrap();
+
+        assert_eq!(
+            accounts,
+            [
+                (1, true, false),
+                (2, true, false),
+                (3, false, false),
+                (4, false, true),
+                (5, false, true),
+            ]
+        );
+        assert_eq!(data, [3, 42, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_transfer_with_nested_multisig() {
+        let [mut from, mut to, mut authority, mut signer1, mut signer2] =
+            [1, 2, 3, 4, 5].map(TestAccount::new);
+        let mut nested = TestAccount::with_data(&crate::program::ID, &[0; Multisig::LEN]);
+        let (signer1, nested, signer2) = (signer1.info(), nested.info(), signer2.info());
+
+        let (accounts, _) = write(&Transfer {
+            from: &from.info(),
+            to: &to.info(),
+            authority: &authority.info(),
+            multisig_signers: &[&signer1, &nested, &signer2],
+            amount: 42,
+        })
+        .unwrap();
+
+        assert_eq!(
+            accounts,
+            [
+                (1, true, false),
+                (2, true, false),
+                (3, false, false),
+                (4, false, true),
+                (0, false, false),
+                (5, false, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_overflow() {
+        let mut buffer = [MaybeUninit::<u8>::uninit(); 2];
+        let mut data = CpiData::new(&mut buffer);
+
+        assert_eq!(data.write(&[1, 2, 3]), Err(ProgramError::InvalidArgument));
+        assert!(data.write(&[1, 2]).is_ok());
+        assert_eq!(data.as_slice(), &[1, 2]);
+    }
+}
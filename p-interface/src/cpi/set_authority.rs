@@ -0,0 +1,53 @@
+use {
+    super::{
+        invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS,
+        OPTION_PUBKEY_LEN,
+    },
+    crate::instruction::{AuthorityType, TokenInstruction},
+    pinocchio::{account_info::AccountInfo, instruction::Signer, pubkey::Pubkey, ProgramResult},
+};
+
+/// Sets a new authority of a mint or account.
+///
+/// See [`TokenInstruction::SetAuthority`] for the accounts and data expected.
+pub struct SetAuthority<'a, 'b> {
+    /// The mint or account to change the authority of.
+    pub account: &'a AccountInfo,
+    /// The current authority of the mint or account.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The type of authority to update.
+    pub authority_type: AuthorityType,
+    /// The new authority.
+    pub new_authority: Option<&'a Pubkey>,
+}
+
+impl SetAuthority<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 2 + MAX_MULTISIG_SIGNERS }, { 2 + OPTION_PUBKEY_LEN }>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for SetAuthority<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.account, true, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[
+            TokenInstruction::SetAuthority as u8,
+            self.authority_type.clone() as u8,
+        ])?;
+        data.write_option_pubkey(self.new_authority)
+    }
+}
@@ -0,0 +1,55 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError, ProgramResult,
+    },
+};
+
+/// Replaces the signers of a multisignature account, authorized by M of its
+/// current signers.
+///
+/// See [`TokenInstruction::SetMultisigSigners`] for the accounts and data
+/// expected.
+pub struct SetMultisigSigners<'a, 'b> {
+    /// The multisignature account.
+    pub multisig: &'a AccountInfo,
+    /// The new signer accounts.
+    pub new_signers: &'b [&'a AccountInfo],
+    /// The number of new signers (M) required to validate the multisignature
+    /// account.
+    pub m: u8,
+    /// M of the current signer accounts.
+    pub multisig_signers: &'b [&'a AccountInfo],
+}
+
+impl SetMultisigSigners<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 1 + 2 * MAX_MULTISIG_SIGNERS }, 3>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for SetMultisigSigners<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        let n = u8::try_from(self.new_signers.len()).map_err(|_| ProgramError::InvalidArgument)?;
+
+        accounts.push(self.multisig, true, false)?;
+        // The new signers do not need to sign.
+        self.new_signers
+            .iter()
+            .try_for_each(|signer| accounts.push(signer, false, false))?;
+        accounts.push_signers(self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::SetMultisigSigners as u8, self.m, n])
+    }
+}
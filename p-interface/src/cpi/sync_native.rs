@@ -0,0 +1,37 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Updates the amount of a native token account based on its lamports.
+///
+/// See [`TokenInstruction::SyncNative`] for the accounts and data expected.
+pub struct SyncNative<'a> {
+    /// The native token account to sync with its underlying lamports.
+    pub native_token: &'a AccountInfo,
+}
+
+impl SyncNative<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, 1>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for SyncNative<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.native_token, true, false)?;
+
+        data.write(&[TokenInstruction::SyncNative as u8])
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_MULTISIG_SIGNERS},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Transfers tokens from one account to another either directly or via a
+/// delegate.
+///
+/// See [`TokenInstruction::Transfer`] for the accounts and data expected.
+pub struct Transfer<'a, 'b> {
+    /// The source account.
+    pub from: &'a AccountInfo,
+    /// The destination account.
+    pub to: &'a AccountInfo,
+    /// The source account's owner/delegate.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens to transfer.
+    pub amount: u64,
+}
+
+impl Transfer<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<{ 3 + MAX_MULTISIG_SIGNERS }, 9>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for Transfer<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.from, true, false)?;
+        accounts.push(self.to, true, false)?;
+        accounts.push_authority(self.authority, self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::Transfer as u8])?;
+        data.write(&self.amount.to_le_bytes())
+    }
+}
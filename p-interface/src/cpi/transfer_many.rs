@@ -0,0 +1,72 @@
+use {
+    super::{
+        invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_DYNAMIC_ACCOUNTS,
+        MAX_DYNAMIC_DATA_LEN,
+    },
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError, ProgramResult,
+    },
+};
+
+/// Transfers tokens from one account to multiple destination accounts,
+/// asserting the token mint and decimals.
+///
+/// Each destination account receives the amount at the same position in
+/// `amounts`, so both slices must have the same length.
+///
+/// See [`TokenInstruction::TransferMany`] for the accounts and data expected.
+pub struct TransferMany<'a, 'b> {
+    /// The source account.
+    pub from: &'a AccountInfo,
+    /// The token mint.
+    pub mint: &'a AccountInfo,
+    /// The source account's owner/delegate.
+    pub authority: &'a AccountInfo,
+    /// The destination accounts.
+    pub to: &'b [&'a AccountInfo],
+    /// The signer accounts when `authority` is a multisignature.
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// The amount of tokens to transfer to each destination account.
+    pub amounts: &'b [u64],
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl TransferMany<'_, '_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<MAX_DYNAMIC_ACCOUNTS, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for TransferMany<'a, '_> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        if self.to.len() != self.amounts.len() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        accounts.push(self.from, true, false)?;
+        accounts.push(self.mint, false, false)?;
+        accounts.push(self.authority, false, self.multisig_signers.is_empty())?;
+        self.to
+            .iter()
+            .try_for_each(|to| accounts.push(to, true, false))?;
+        // The signer accounts follow the destination accounts.
+        accounts.push_signers(self.multisig_signers)?;
+
+        data.write(&[TokenInstruction::TransferMany as u8, self.decimals])?;
+        self.amounts
+            .iter()
+            .try_for_each(|amount| data.write(&amount.to_le_bytes()))
+    }
+}
@@ -0,0 +1,42 @@
+use {
+    super::{invoke_signed, CpiAccounts, CpiData, CpiInstruction, MAX_DYNAMIC_DATA_LEN},
+    crate::instruction::TokenInstruction,
+    pinocchio::{account_info::AccountInfo, instruction::Signer, ProgramResult},
+};
+
+/// Converts a `UiAmount` of tokens to a raw amount, which is set as the return
+/// data.
+///
+/// See [`TokenInstruction::UiAmountToAmount`] for the accounts and data
+/// expected.
+pub struct UiAmountToAmount<'a> {
+    /// The mint to calculate for.
+    pub mint: &'a AccountInfo,
+    /// The `ui_amount` of tokens to reformat.
+    pub ui_amount: &'a str,
+}
+
+impl UiAmountToAmount<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the signer seeds of program derived
+    /// addresses.
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        invoke_signed::<1, MAX_DYNAMIC_DATA_LEN>(self, signers)
+    }
+}
+
+impl<'a> CpiInstruction<'a> for UiAmountToAmount<'a> {
+    #[inline(always)]
+    fn write(&self, accounts: &mut CpiAccounts<'a, '_>, data: &mut CpiData<'_>) -> ProgramResult {
+        accounts.push(self.mint, false, false)?;
+
+        data.write(&[TokenInstruction::UiAmountToAmount as u8])?;
+        data.write(self.ui_amount.as_bytes())
+    }
+}
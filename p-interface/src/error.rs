@@ -128,3 +128,32 @@ impl TryFrom<u32> for TokenError {
         }
     }
 }
+
+/// Errors that may be returned when decoding the instructions of a `Batch`
+/// instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchError {
+    /// Instruction data ends before the header of the next instruction.
+    TruncatedHeader,
+    /// Instruction header declares an empty instruction data.
+    EmptyInstruction,
+    /// Instruction data is shorter than the length declared in the header.
+    TruncatedData,
+    /// Fewer accounts remain than the number declared in the header.
+    NotEnoughAccountKeys,
+    /// Instruction discriminator is not valid.
+    InvalidDiscriminator,
+    /// Instruction is a `Batch` instruction.
+    NestedBatch,
+}
+
+impl From<BatchError> for ProgramError {
+    /// Converts the error into the one returned by the token program when
+    /// processing the malformed batch.
+    fn from(e: BatchError) -> Self {
+        match e {
+            BatchError::NotEnoughAccountKeys => ProgramError::NotEnoughAccountKeys,
+            _ => TokenError::InvalidInstruction.into(),
+        }
+    }
+}
@@ -1,6 +1,54 @@
 //! Instruction types.
 
-use {crate::error::TokenError, pinocchio::program_error::ProgramError};
+use {
+    crate::{
+        error::{BatchError, TokenError},
+        state::{delegation::ExpiryKind, multisig::MAX_LARGE_SIGNERS},
+    },
+    core::str::from_utf8,
+    pinocchio::{
+        program_error::ProgramError,
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// The size of the header of each instruction in a `Batch` instruction.
+///
+/// The header of each instruction consists of two `u8` values:
+///  * number of the accounts
+///  * length of the instruction data
+pub const IX_HEADER_SIZE: usize = 2;
+
+/// The length of the return data set when an instruction of a `Batch`,
+/// `IndexedBatch` or `BestEffortBatch` instruction fails the batch.
+///
+/// The return data consists of:
+///  * `u8`: discriminator of the batch instruction
+///  * `u16`: index of the failed instruction in the batch (little-endian)
+///  * `u8`: discriminator of the failed instruction
+pub const BATCH_FAILURE_LEN: usize = 4;
+
+/// Maximum number of instructions in a `BestEffortBatch` instruction.
+///
+/// The result of each instruction is reported in a bitmap of 256 bits (32
+/// bytes), whose size was chosen for the return data of the batch; this is
+/// the number of instructions that the bitmap can represent.
+pub const MAX_BEST_EFFORT_INSTRUCTIONS: usize = 256;
+
+/// Maximum number of writable accounts that an instruction of a
+/// `BestEffortBatch` instruction can have in order to be skipped on failure.
+pub const MAX_BEST_EFFORT_WRITABLE_ACCOUNTS: usize = 3;
+
+/// Maximum number of accounts that each instruction of an `IndexedBatch`
+/// instruction can reference.
+///
+/// This is the number of accounts of a `TransferChecked` instruction with a
+/// large multisignature authority using all signers. The accounts are copied
+/// to the stack, so the limit is not sized for the longest valid account
+/// lists: `TransferMany` and `MintToMany` with many destinations,
+/// `SetMultisigSigners` with many new signers, or authorities with nested
+/// multisig signers can exceed it and need a [`TokenInstruction::Batch`].
+pub const MAX_INDEXED_ACCOUNTS: usize = 4 + MAX_LARGE_SIGNERS as usize;
 
 /// Instructions supported by the token program.
 #[repr(u8)]
@@ -498,6 +546,362 @@ pub enum TokenInstruction {
     ///   3. `..+M` `[signer]` M signer accounts.
     WithdrawExcessLamports = 38,
 
+    /// Transfers tokens from one account to multiple destination accounts,
+    /// asserting the token mint and decimals. This is equivalent to multiple
+    /// `TransferChecked` instructions from the same source account, but the
+    /// authority is only validated once and the source account is debited
+    /// once with the total amount.
+    ///
+    /// The number of destination accounts is given by the number of amounts
+    /// in the instruction data. Amounts transferred to the source account
+    /// itself count towards the total amount validated, but are not moved.
+    ///
+    /// Note that discriminators `25..=44`, and the ones Token-2022 assigns
+    /// next, are reserved for the instructions of the Token-2022 program, so
+    /// instructions only supported by this program count down from `252`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The source account's owner/delegate.
+    ///   3. `..3+N` `[writable]` The N destination accounts.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The source account's multisignature owner/delegate.
+    ///   3. `..3+N` `[writable]` The N destination accounts.
+    ///   4. `..+M` `[signer]` M signer accounts, following the destination
+    ///      accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    ///   - `[u64]` The amount of tokens to transfer to each destination.
+    TransferMany = 252,
+
+    /// Mints new tokens to multiple accounts, asserting the token decimals.
+    /// This is equivalent to multiple `MintToChecked` instructions for the same
+    /// mint, but the mint authority is only validated once and the mint supply
+    /// is updated once with the total amount.
+    ///
+    /// The number of destination accounts is given by the number of amounts
+    /// in the instruction data.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The mint's minting authority.
+    ///   2. `..2+N` `[writable]` The N accounts to mint tokens to.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature mint-tokens authority.
+    ///   2. `..2+N` `[writable]` The N accounts to mint tokens to.
+    ///   3. `..+M` `[signer]` M signer accounts, following the destination
+    ///      accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    ///   - `[u64]` The amount of new tokens to mint to each account.
+    MintToMany = 251,
+
+    /// Gets the balance of a token account, which is set as the return data.
+    ///
+    /// The account must be an initialized token account owned by the token
+    /// program. The return data has the layout described by
+    /// [`Balance`](crate::query::Balance).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The token account.
+    GetBalance = 250,
+
+    /// Gets the supply, decimals and authorities of a mint, which are set as
+    /// the return data.
+    ///
+    /// The account must be an initialized mint owned by the token program. The
+    /// return data has the layout described by
+    /// [`MintInfo`](crate::query::MintInfo).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The mint.
+    GetMintInfo = 249,
+
+    /// Like [`InitializeMultisig2`], but initializes a large multisignature
+    /// account with up to [`MAX_LARGE_SIGNERS`] signers.
+    ///
+    /// The account must have the length of a
+    /// [`LargeMultisig`](crate::state::multisig::LargeMultisig). When nested
+    /// members are allowed, a signer that is itself a multisig can authorize
+    /// without signing: it is then passed as a non-signer account followed by
+    /// M signer accounts of its own, laid out the same way, up to
+    /// [`MAX_MULTISIG_DEPTH`](crate::state::multisig::MAX_MULTISIG_DEPTH)
+    /// levels of nesting.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account to initialize.
+    ///   1. `..+N` `[]` The signer accounts, must equal to N where `1 <= N <=
+    ///      32`.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` The number of signers (M) required to validate this
+    ///     multisignature account.
+    ///   - `u8` Whether nested members are allowed, either `0` or `1`.
+    InitializeMultisig3 = 248,
+
+    /// Replaces the signers and the number of required signers of a
+    /// multisignature account, authorized by M of its current signers.
+    ///
+    /// The account can be a [`Multisig`](crate::state::multisig::Multisig)
+    /// or a [`LargeMultisig`](crate::state::multisig::LargeMultisig), which
+    /// determines the maximum number of new signers. Unlike the
+    /// initialization, the new M cannot be greater than the new N.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account.
+    ///   1. `..+N` `[]` The new signer accounts.
+    ///   2. `..+M` `[signer]` M of the current signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u8` The number of new signers (M) required to validate the
+    ///     multisignature account.
+    ///   - `u8` The number of new signer accounts (N).
+    SetMultisigSigners = 247,
+
+    /// Approves a delegate until an expiry slot or unix timestamp, asserting
+    /// the token mint and decimals.
+    ///
+    /// The delegation is kept in a delegation record account, whose key is set
+    /// as the delegate of the source account, so the layout of the source
+    /// account is unchanged. The record must have the length of a
+    /// [`DelegationRecord`](crate::state::delegation::DelegationRecord) and
+    /// be either uninitialized and rent exempt, or already used for the
+    /// source account.
+    ///
+    /// The record is not cleared when the delegation is revoked or replaced,
+    /// so it can be reused for the same source account until it is closed
+    /// with [`CloseDelegationRecord`], the only way to recover its lamports.
+    ///
+    /// To transfer or burn tokens, the record is passed as the authority,
+    /// followed by the delegate, laid out as a single or multisignature
+    /// authority. The delegation cannot be used once the slot or unix
+    /// timestamp of the `Clock` sysvar reaches the expiry.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The delegation record.
+    ///   3. `[]` The delegate.
+    ///   4. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The delegation record.
+    ///   3. `[]` The delegate.
+    ///   4. `[]` The source account's multisignature owner.
+    ///   5. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens the delegate is approved for.
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    ///   - `u8` The kind of expiry, `0` for a slot and `1` for a unix
+    ///     timestamp.
+    ///   - `u64` The expiry slot, or `i64` expiry unix timestamp.
+    ApproveWithExpiry = 246,
+
+    /// Increases the amount of tokens the current delegate of the source
+    /// account is approved for.
+    ///
+    /// Unlike [`Approve`], the allowance is adjusted relative to its current
+    /// value, so tokens spent by the delegate in between are not approved
+    /// again. The instruction fails if the delegate of the source account is
+    /// not the given delegate.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to add to the allowance.
+    IncreaseAllowance = 245,
+
+    /// Decreases the amount of tokens the current delegate of the source
+    /// account is approved for.
+    ///
+    /// The instruction fails if the delegate of the source account is not the
+    /// given delegate or the allowance is less than the amount. The delegate
+    /// is cleared when the allowance reaches zero.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to remove from the allowance.
+    DecreaseAllowance = 244,
+
+    /// Like [`IncreaseAllowance`], but asserting the token mint and decimals.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to add to the allowance.
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    IncreaseAllowanceChecked = 243,
+
+    /// Like [`DecreaseAllowance`], but asserting the token mint and decimals.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens to remove from the allowance.
+    ///   - `u8` Expected number of base 10 digits to the right of the decimal
+    ///     place.
+    DecreaseAllowanceChecked = 242,
+
+    /// Closes a delegation record by transferring all its SOL to the
+    /// destination account.
+    ///
+    /// Only the owner of the token account of the delegation can close the
+    /// record. The delegation is revoked when the record is still the
+    /// delegate of the token account, which fails if it is frozen. Records
+    /// that are no longer the delegate, e.g., after a `Revoke`, are closed
+    /// without changing the token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The delegation record.
+    ///   1. `[writable]` The token account of the delegation.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The token account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The delegation record.
+    ///   1. `[writable]` The token account of the delegation.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The token account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    CloseDelegationRecord = 241,
+
+    /// Executes a batch of instructions that reference their accounts by
+    /// index. The instructions to be executed are specified in sequence on
+    /// the instruction data. Each instruction provides:
+    ///   - `u8`: number of accounts
+    ///   - `u8`: instruction data length (includes the discriminator)
+    ///   - `[u8]`: index of each account in the accounts of the batch
+    ///   - `u8`: instruction discriminator
+    ///   - `[u8]`: instruction data
+    ///
+    /// Unlike [`Batch`], the accounts are shared by all instructions, so
+    /// instructions can reference the same account without repeating it. Each
+    /// instruction can reference at most [`MAX_INDEXED_ACCOUNTS`] accounts, so
+    /// instructions with longer account lists must use a [`Batch`] instead.
+    ///
+    /// Note that it is not sound to have an `IndexedBatch` instruction that
+    /// contains a `batch` instruction; an error will be raised when this is
+    /// detected.
+    IndexedBatch = 254,
+
+    /// Executes a batch of instructions, skipping the instructions that fail
+    /// without modifying any account. The instructions are specified using the
+    /// same layout as [`Batch`].
+    ///
+    /// The return data is set to a bitmap with the result of each instruction,
+    /// where bit `i % 8` of byte `i / 8` is set when instruction `i` succeeded.
+    /// The bitmap has one byte for every 8 instructions of the batch, with at
+    /// most [`MAX_BEST_EFFORT_INSTRUCTIONS`] instructions.
+    ///
+    /// Since the runtime does not revert the changes of an instruction that
+    /// fails inside the batch, an instruction is only skipped when the
+    /// lamports and data of its writable accounts are the same as before it
+    /// was processed. Therefore, the batch still fails when:
+    ///   - an instruction fails after modifying one of its accounts, e.g., the
+    ///     lamports of a native account overflow after the tokens are moved;
+    ///   - a failed instruction has more than
+    ///     [`MAX_BEST_EFFORT_WRITABLE_ACCOUNTS`] writable accounts, since their
+    ///     state cannot be compared;
+    ///   - a failed instruction has a writable large multisignature account,
+    ///     since its data is not copied;
+    ///   - the header of an instruction is malformed or there are not enough
+    ///     accounts for an instruction;
+    ///   - an instruction is a batch instruction.
+    ///
+    /// The data is only compared for accounts owned by the token program with
+    /// the length of a token account, mint, multisig or delegation record,
+    /// since the program cannot modify the data of any other account.
+    ///
+    /// Note that it is not sound to have a `BestEffortBatch` instruction that
+    /// contains a `batch` instruction; the whole batch fails when this is
+    /// detected.
+    BestEffortBatch = 253,
+
     /// Executes a batch of instructions. The instructions to be executed are
     /// specified in sequence on the instruction data. Each instruction
     /// provides:
@@ -519,8 +923,10 @@ pub enum TokenInstruction {
     /// other `batch` instruction; an error will be raised when this is
     /// detected.
     Batch = 255,
-    // Any new variants also need to be added to program-2022 `TokenInstruction`, so that the
-    // latter remains a superset of this instruction set. New variants also need to be added to
+    // Variants with discriminators up to `44` are shared with program-2022 `TokenInstruction`,
+    // which must remain a superset of them. Program-2022 assigns its next discriminators
+    // upwards from `45`, so variants that only this program supports count down from `252`,
+    // right below the batch instructions. New variants also need to be added to
     // token/js/src/instructions/types.ts to maintain @solana/spl-token compatibility
 }
 
@@ -530,7 +936,9 @@ impl TryFrom<u8> for TokenInstruction {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             // SAFETY: `value` is guaranteed to be in the range of the enum variants.
-            0..=24 | 38 | 255 => Ok(unsafe { core::mem::transmute::<u8, TokenInstruction>(value) }),
+            0..=24 | 38 | 241..=255 => {
+                Ok(unsafe { core::mem::transmute::<u8, TokenInstruction>(value) })
+            }
             _ => Err(TokenError::InvalidInstruction.into()),
         }
     }
@@ -564,10 +972,473 @@ impl TryFrom<u8> for AuthorityType {
     }
 }
 
+/// Returns an iterator over the instructions of a `Batch` instruction.
+///
+/// The `instruction_data` is the data following the `Batch` discriminator and
+/// `accounts` are the accounts of the `Batch` instruction, e.g., `AccountMeta`s
+/// or `AccountInfo`s. Each item yields the instruction, its accounts and its
+/// data without the discriminator.
+///
+/// The instructions are validated in the same order as the token program does,
+/// so the first error yielded converts into the error that the program would
+/// return when processing the batch. The iterator stops after an error.
+pub fn decode_batch<'a, T>(
+    instruction_data: &'a [u8],
+    accounts: &'a [T],
+) -> BatchInstructions<'a, T> {
+    BatchInstructions {
+        accounts,
+        instruction_data,
+        done: false,
+    }
+}
+
+/// Iterator over the instructions of a `Batch` instruction.
+///
+/// This is created by [`decode_batch`].
+pub struct BatchInstructions<'a, T> {
+    accounts: &'a [T],
+    instruction_data: &'a [u8],
+    done: bool,
+}
+
+impl<'a, T> BatchInstructions<'a, T> {
+    fn decode_next(&mut self) -> Result<(TokenInstruction, &'a [T], &'a [u8]), BatchError> {
+        let [expected_accounts, data_len, remaining @ ..] = self.instruction_data else {
+            return Err(BatchError::TruncatedHeader);
+        };
+        let expected_accounts = *expected_accounts as usize;
+        let data_len = *data_len as usize;
+
+        if data_len == 0 {
+            return Err(BatchError::EmptyInstruction);
+        }
+        if remaining.len() < data_len {
+            return Err(BatchError::TruncatedData);
+        }
+        if self.accounts.len() < expected_accounts {
+            return Err(BatchError::NotEnoughAccountKeys);
+        }
+
+        let (ix_data, remaining) = remaining.split_at(data_len);
+        let (ix_accounts, accounts) = self.accounts.split_at(expected_accounts);
+
+        let (discriminator, ix_data) = ix_data.split_at(1);
+        let instruction = TokenInstruction::try_from(discriminator[0])
+            .map_err(|_| BatchError::InvalidDiscriminator)?;
+
+        if matches!(
+            instruction,
+            TokenInstruction::Batch
+                | TokenInstruction::IndexedBatch
+                | TokenInstruction::BestEffortBatch
+        ) {
+            return Err(BatchError::NestedBatch);
+        }
+
+        self.accounts = accounts;
+        self.instruction_data = remaining;
+
+        Ok((instruction, ix_accounts, ix_data))
+    }
+}
+
+impl<'a, T> Iterator for BatchInstructions<'a, T> {
+    type Item = Result<(TokenInstruction, &'a [T], &'a [u8]), BatchError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.decode_next();
+        self.done = result.is_err() || self.instruction_data.is_empty();
+
+        Some(result)
+    }
+}
+
+/// An instruction of the token program with its decoded instruction data.
+///
+/// This is created by [`parse`]. Fields borrow from the instruction data, so
+/// parsing does not copy pubkeys or amounts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsedInstruction<'a> {
+    /// See [`TokenInstruction::InitializeMint`].
+    InitializeMint {
+        decimals: u8,
+        mint_authority: &'a Pubkey,
+        freeze_authority: Option<&'a Pubkey>,
+    },
+    /// See [`TokenInstruction::InitializeAccount`].
+    InitializeAccount,
+    /// See [`TokenInstruction::InitializeMultisig`].
+    InitializeMultisig { m: u8 },
+    /// See [`TokenInstruction::Transfer`].
+    Transfer { amount: u64 },
+    /// See [`TokenInstruction::Approve`].
+    Approve { amount: u64 },
+    /// See [`TokenInstruction::Revoke`].
+    Revoke,
+    /// See [`TokenInstruction::SetAuthority`].
+    SetAuthority {
+        authority_type: AuthorityType,
+        new_authority: Option<&'a Pubkey>,
+    },
+    /// See [`TokenInstruction::MintTo`].
+    MintTo { amount: u64 },
+    /// See [`TokenInstruction::Burn`].
+    Burn { amount: u64 },
+    /// See [`TokenInstruction::CloseAccount`].
+    CloseAccount,
+    /// See [`TokenInstruction::FreezeAccount`].
+    FreezeAccount,
+    /// See [`TokenInstruction::ThawAccount`].
+    ThawAccount,
+    /// See [`TokenInstruction::TransferChecked`].
+    TransferChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::ApproveChecked`].
+    ApproveChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::MintToChecked`].
+    MintToChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::BurnChecked`].
+    BurnChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::InitializeAccount2`].
+    InitializeAccount2 { owner: &'a Pubkey },
+    /// See [`TokenInstruction::SyncNative`].
+    SyncNative,
+    /// See [`TokenInstruction::InitializeAccount3`].
+    InitializeAccount3 { owner: &'a Pubkey },
+    /// See [`TokenInstruction::InitializeMultisig2`].
+    InitializeMultisig2 { m: u8 },
+    /// See [`TokenInstruction::InitializeMint2`].
+    InitializeMint2 {
+        decimals: u8,
+        mint_authority: &'a Pubkey,
+        freeze_authority: Option<&'a Pubkey>,
+    },
+    /// See [`TokenInstruction::GetAccountDataSize`].
+    GetAccountDataSize,
+    /// See [`TokenInstruction::InitializeImmutableOwner`].
+    InitializeImmutableOwner,
+    /// See [`TokenInstruction::AmountToUiAmount`].
+    AmountToUiAmount { amount: u64 },
+    /// See [`TokenInstruction::UiAmountToAmount`].
+    UiAmountToAmount { ui_amount: &'a str },
+    /// See [`TokenInstruction::WithdrawExcessLamports`].
+    WithdrawExcessLamports,
+    /// See [`TokenInstruction::TransferMany`].
+    TransferMany { decimals: u8, amounts: Amounts<'a> },
+    /// See [`TokenInstruction::MintToMany`].
+    MintToMany { decimals: u8, amounts: Amounts<'a> },
+    /// See [`TokenInstruction::GetBalance`].
+    GetBalance,
+    /// See [`TokenInstruction::GetMintInfo`].
+    GetMintInfo,
+    /// See [`TokenInstruction::InitializeMultisig3`].
+    InitializeMultisig3 { m: u8, allow_nested: bool },
+    /// See [`TokenInstruction::SetMultisigSigners`].
+    SetMultisigSigners { m: u8, n: u8 },
+    /// See [`TokenInstruction::ApproveWithExpiry`].
+    ApproveWithExpiry {
+        amount: u64,
+        decimals: u8,
+        expiry_kind: ExpiryKind,
+        expiry: u64,
+    },
+    /// See [`TokenInstruction::IncreaseAllowance`].
+    IncreaseAllowance { amount: u64 },
+    /// See [`TokenInstruction::DecreaseAllowance`].
+    DecreaseAllowance { amount: u64 },
+    /// See [`TokenInstruction::IncreaseAllowanceChecked`].
+    IncreaseAllowanceChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::DecreaseAllowanceChecked`].
+    DecreaseAllowanceChecked { amount: u64, decimals: u8 },
+    /// See [`TokenInstruction::CloseDelegationRecord`].
+    CloseDelegationRecord,
+    /// See [`TokenInstruction::IndexedBatch`].
+    ///
+    /// The data of the instructions of the batch is not parsed.
+    IndexedBatch { data: &'a [u8] },
+    /// See [`TokenInstruction::BestEffortBatch`].
+    ///
+    /// The instructions of the batch can be decoded with [`decode_batch`].
+    BestEffortBatch { data: &'a [u8] },
+    /// See [`TokenInstruction::Batch`].
+    ///
+    /// The instructions of the batch can be decoded with [`decode_batch`].
+    Batch { data: &'a [u8] },
+}
+
+/// Little-endian `u64` amounts of a `TransferMany` or `MintToMany`
+/// instruction, borrowed from the instruction data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Amounts<'a>(&'a [u8]);
+
+impl<'a> Amounts<'a> {
+    /// Returns the number of amounts.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len() / U64_BYTES
+    }
+
+    /// Returns `true` if there are no amounts.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the amount at `index`, if any.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<u64> {
+        let offset = index.checked_mul(U64_BYTES)?;
+        self.0
+            .get(offset..offset.checked_add(U64_BYTES)?)
+            .map(read_u64)
+    }
+
+    /// Returns an iterator over the amounts.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = u64> + 'a {
+        self.0.chunks_exact(U64_BYTES).map(read_u64)
+    }
+}
+
+/// Parses the data of an instruction of the token program, including its
+/// discriminator.
+///
+/// The parser rejects data that the token program always rejects, e.g., an
+/// unknown discriminator or data that is too short. Data accepted by the
+/// parser can still be rejected by the program, e.g., a batch whose
+/// instructions are invalid. As in the program, bytes following the expected
+/// data are ignored.
+pub fn parse(data: &[u8]) -> Result<ParsedInstruction<'_>, TokenError> {
+    let [discriminator, data @ ..] = data else {
+        return Err(TokenError::InvalidInstruction);
+    };
+
+    let instruction =
+        TokenInstruction::try_from(*discriminator).map_err(|_| TokenError::InvalidInstruction)?;
+
+    Ok(match instruction {
+        TokenInstruction::InitializeMint => {
+            let (decimals, mint_authority, freeze_authority) = parse_initialize_mint(data)?;
+            ParsedInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            }
+        }
+        TokenInstruction::InitializeAccount => ParsedInstruction::InitializeAccount,
+        TokenInstruction::InitializeMultisig => {
+            ParsedInstruction::InitializeMultisig { m: parse_u8(data)? }
+        }
+        TokenInstruction::Transfer => ParsedInstruction::Transfer {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::Approve => ParsedInstruction::Approve {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::Revoke => ParsedInstruction::Revoke,
+        TokenInstruction::SetAuthority => {
+            let [authority_type, new_authority @ ..] = data else {
+                return Err(TokenError::InvalidInstruction);
+            };
+            ParsedInstruction::SetAuthority {
+                authority_type: AuthorityType::try_from(*authority_type)
+                    .map_err(|_| TokenError::InvalidInstruction)?,
+                new_authority: parse_option_pubkey(new_authority)?,
+            }
+        }
+        TokenInstruction::MintTo => ParsedInstruction::MintTo {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::Burn => ParsedInstruction::Burn {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::CloseAccount => ParsedInstruction::CloseAccount,
+        TokenInstruction::FreezeAccount => ParsedInstruction::FreezeAccount,
+        TokenInstruction::ThawAccount => ParsedInstruction::ThawAccount,
+        TokenInstruction::TransferChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::TransferChecked { amount, decimals }
+        }
+        TokenInstruction::ApproveChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::ApproveChecked { amount, decimals }
+        }
+        TokenInstruction::MintToChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::MintToChecked { amount, decimals }
+        }
+        TokenInstruction::BurnChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::BurnChecked { amount, decimals }
+        }
+        TokenInstruction::InitializeAccount2 => ParsedInstruction::InitializeAccount2 {
+            owner: parse_pubkey(data)?,
+        },
+        TokenInstruction::SyncNative => ParsedInstruction::SyncNative,
+        TokenInstruction::InitializeAccount3 => ParsedInstruction::InitializeAccount3 {
+            owner: parse_pubkey(data)?,
+        },
+        TokenInstruction::InitializeMultisig2 => {
+            ParsedInstruction::InitializeMultisig2 { m: parse_u8(data)? }
+        }
+        TokenInstruction::InitializeMint2 => {
+            let (decimals, mint_authority, freeze_authority) = parse_initialize_mint(data)?;
+            ParsedInstruction::InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            }
+        }
+        TokenInstruction::GetAccountDataSize => ParsedInstruction::GetAccountDataSize,
+        TokenInstruction::InitializeImmutableOwner => ParsedInstruction::InitializeImmutableOwner,
+        TokenInstruction::AmountToUiAmount => ParsedInstruction::AmountToUiAmount {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::UiAmountToAmount => ParsedInstruction::UiAmountToAmount {
+            ui_amount: from_utf8(data).map_err(|_| TokenError::InvalidInstruction)?,
+        },
+        TokenInstruction::WithdrawExcessLamports => ParsedInstruction::WithdrawExcessLamports,
+        TokenInstruction::TransferMany => {
+            let (decimals, amounts) = parse_decimals_and_amounts(data)?;
+            ParsedInstruction::TransferMany { decimals, amounts }
+        }
+        TokenInstruction::MintToMany => {
+            let (decimals, amounts) = parse_decimals_and_amounts(data)?;
+            ParsedInstruction::MintToMany { decimals, amounts }
+        }
+        TokenInstruction::GetBalance => ParsedInstruction::GetBalance,
+        TokenInstruction::GetMintInfo => ParsedInstruction::GetMintInfo,
+        TokenInstruction::InitializeMultisig3 => match data {
+            [m, allow_nested @ (0 | 1), ..] => ParsedInstruction::InitializeMultisig3 {
+                m: *m,
+                allow_nested: *allow_nested == 1,
+            },
+            _ => return Err(TokenError::InvalidInstruction),
+        },
+        TokenInstruction::SetMultisigSigners => match data {
+            [m, n, ..] => ParsedInstruction::SetMultisigSigners { m: *m, n: *n },
+            _ => return Err(TokenError::InvalidInstruction),
+        },
+        TokenInstruction::ApproveWithExpiry => {
+            let (amount, decimals, expiry_kind, expiry) = parse_expiring_approval(data)?;
+            ParsedInstruction::ApproveWithExpiry {
+                amount,
+                decimals,
+                expiry_kind,
+                expiry,
+            }
+        }
+        TokenInstruction::IncreaseAllowance => ParsedInstruction::IncreaseAllowance {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::DecreaseAllowance => ParsedInstruction::DecreaseAllowance {
+            amount: parse_amount(data)?,
+        },
+        TokenInstruction::IncreaseAllowanceChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::IncreaseAllowanceChecked { amount, decimals }
+        }
+        TokenInstruction::DecreaseAllowanceChecked => {
+            let (amount, decimals) = parse_amount_and_decimals(data)?;
+            ParsedInstruction::DecreaseAllowanceChecked { amount, decimals }
+        }
+        TokenInstruction::CloseDelegationRecord => ParsedInstruction::CloseDelegationRecord,
+        TokenInstruction::IndexedBatch => ParsedInstruction::IndexedBatch { data },
+        TokenInstruction::BestEffortBatch => ParsedInstruction::BestEffortBatch { data },
+        TokenInstruction::Batch => ParsedInstruction::Batch { data },
+    })
+}
+
+/// Size of a `u64` in the instruction data.
+const U64_BYTES: usize = core::mem::size_of::<u64>();
+
+#[inline(always)]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut amount = [0; U64_BYTES];
+    amount.copy_from_slice(&bytes[..U64_BYTES]);
+    u64::from_le_bytes(amount)
+}
+
+#[inline(always)]
+fn parse_u8(data: &[u8]) -> Result<u8, TokenError> {
+    data.first().copied().ok_or(TokenError::InvalidInstruction)
+}
+
+#[inline(always)]
+fn parse_amount(data: &[u8]) -> Result<u64, TokenError> {
+    if data.len() >= U64_BYTES {
+        Ok(read_u64(data))
+    } else {
+        Err(TokenError::InvalidInstruction)
+    }
+}
+
+#[inline(always)]
+fn parse_amount_and_decimals(data: &[u8]) -> Result<(u64, u8), TokenError> {
+    Ok((parse_amount(data)?, parse_u8(&data[U64_BYTES..])?))
+}
+
+/// Parses the amount, decimals and expiry of an `ApproveWithExpiry`.
+#[inline(always)]
+fn parse_expiring_approval(data: &[u8]) -> Result<(u64, u8, ExpiryKind, u64), TokenError> {
+    let (amount, decimals) = parse_amount_and_decimals(data)?;
+    let expiry_kind = ExpiryKind::try_from(parse_u8(&data[U64_BYTES + 1..])?)
+        .map_err(|_| TokenError::InvalidInstruction)?;
+    let expiry = parse_amount(&data[U64_BYTES + 2..])?;
+    Ok((amount, decimals, expiry_kind, expiry))
+}
+
+#[inline(always)]
+fn parse_pubkey(data: &[u8]) -> Result<&Pubkey, TokenError> {
+    data.get(..PUBKEY_BYTES)
+        .and_then(|pubkey| pubkey.try_into().ok())
+        .ok_or(TokenError::InvalidInstruction)
+}
+
+/// Parses a `COption<Pubkey>`, which is a `u8` tag followed by the pubkey
+/// when the tag is `1`.
+#[inline(always)]
+fn parse_option_pubkey(data: &[u8]) -> Result<Option<&Pubkey>, TokenError> {
+    match data.split_first() {
+        Some((0, _)) => Ok(None),
+        Some((1, pubkey)) => parse_pubkey(pubkey).map(Some),
+        _ => Err(TokenError::InvalidInstruction),
+    }
+}
+
+#[inline(always)]
+fn parse_initialize_mint(data: &[u8]) -> Result<(u8, &Pubkey, Option<&Pubkey>), TokenError> {
+    let [decimals, data @ ..] = data else {
+        return Err(TokenError::InvalidInstruction);
+    };
+    let mint_authority = parse_pubkey(data)?;
+    let freeze_authority = parse_option_pubkey(&data[PUBKEY_BYTES..])?;
+
+    Ok((*decimals, mint_authority, freeze_authority))
+}
+
+#[inline(always)]
+fn parse_decimals_and_amounts(data: &[u8]) -> Result<(u8, Amounts<'_>), TokenError> {
+    match data {
+        [decimals, amounts @ ..] if !amounts.is_empty() && amounts.len() % U64_BYTES == 0 => {
+            Ok((*decimals, Amounts(amounts)))
+        }
+        _ => Err(TokenError::InvalidInstruction),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
-        super::{AuthorityType, TokenInstruction},
+        super::{decode_batch, parse, AuthorityType, ParsedInstruction, TokenInstruction},
+        crate::{
+            error::{BatchError, TokenError},
+            state::delegation::ExpiryKind,
+        },
         strum::IntoEnumIterator,
     };
 
@@ -594,4 +1465,228 @@ mod tests {
             assert_eq!(AuthorityType::try_from(variant_u8).unwrap(), variant);
         }
     }
+
+    #[test]
+    fn test_decode_batch() {
+        let accounts = [0u8, 1, 2, 3];
+        // Transfer (3 accounts) + SyncNative (1 account).
+        let data = [3, 9, 3, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 17];
+
+        let mut batch = decode_batch(&data, &accounts);
+        assert_eq!(
+            batch.next(),
+            Some(Ok((
+                TokenInstruction::Transfer,
+                &accounts[..3],
+                &[1, 0, 0, 0, 0, 0, 0, 0][..]
+            )))
+        );
+        assert_eq!(
+            batch.next(),
+            Some(Ok((TokenInstruction::SyncNative, &accounts[3..], &[][..])))
+        );
+        assert_eq!(batch.next(), None);
+    }
+
+    #[test]
+    fn test_decode_batch_errors() {
+        let accounts = [0u8, 1, 2];
+
+        let cases: [(&[u8], BatchError); 9] = [
+            (&[], BatchError::TruncatedHeader),
+            (&[1], BatchError::TruncatedHeader),
+            (&[1, 0], BatchError::EmptyInstruction),
+            (&[1, 2, 9], BatchError::TruncatedData),
+            (&[4, 1, 9], BatchError::NotEnoughAccountKeys),
+            (&[1, 1, 100], BatchError::InvalidDiscriminator),
+            (&[1, 1, 255], BatchError::NestedBatch),
+            (&[1, 1, 254], BatchError::NestedBatch),
+            (&[1, 1, 253], BatchError::NestedBatch),
+        ];
+
+        for (data, error) in cases {
+            let mut batch = decode_batch(data, &accounts);
+            assert_eq!(batch.next(), Some(Err(error)));
+            assert_eq!(batch.next(), None);
+        }
+
+        // The error is reported after the valid instructions.
+        let data = [3, 1, 9, 1, 1];
+        let mut batch = decode_batch(&data, &accounts);
+        assert_eq!(
+            batch.next(),
+            Some(Ok((TokenInstruction::CloseAccount, &accounts[..], &[][..])))
+        );
+        assert_eq!(batch.next(), Some(Err(BatchError::TruncatedData)));
+        assert_eq!(batch.next(), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let authority = [7; 32];
+        let mut initialize_mint = [0; 67];
+        initialize_mint[1] = 6;
+        initialize_mint[2..34].copy_from_slice(&authority);
+        initialize_mint[34] = 1;
+        initialize_mint[35..].copy_from_slice(&[8; 32]);
+
+        assert_eq!(
+            parse(&initialize_mint),
+            Ok(ParsedInstruction::InitializeMint {
+                decimals: 6,
+                mint_authority: &authority,
+                freeze_authority: Some(&[8; 32]),
+            })
+        );
+        // Without freeze authority, ignoring the remaining bytes.
+        initialize_mint[0] = 20;
+        initialize_mint[34] = 0;
+        assert_eq!(
+            parse(&initialize_mint[..35]),
+            Ok(ParsedInstruction::InitializeMint2 {
+                decimals: 6,
+                mint_authority: &authority,
+                freeze_authority: None,
+            })
+        );
+
+        let mut set_authority = [7; 35];
+        set_authority[..3].copy_from_slice(&[6, 2, 1]);
+        assert_eq!(
+            parse(&set_authority),
+            Ok(ParsedInstruction::SetAuthority {
+                authority_type: AuthorityType::AccountOwner,
+                new_authority: Some(&authority),
+            })
+        );
+        assert_eq!(
+            parse(&[6, 3, 0]),
+            Ok(ParsedInstruction::SetAuthority {
+                authority_type: AuthorityType::CloseAccount,
+                new_authority: None,
+            })
+        );
+
+        assert_eq!(
+            parse(&[3, 1, 2, 0, 0, 0, 0, 0, 0]),
+            Ok(ParsedInstruction::Transfer { amount: 513 })
+        );
+        assert_eq!(
+            parse(&[15, 1, 0, 0, 0, 0, 0, 0, 0, 9]),
+            Ok(ParsedInstruction::BurnChecked {
+                amount: 1,
+                decimals: 9
+            })
+        );
+        assert_eq!(
+            parse(&[2, 2]),
+            Ok(ParsedInstruction::InitializeMultisig { m: 2 })
+        );
+        assert_eq!(
+            parse(&[248, 20, 1]),
+            Ok(ParsedInstruction::InitializeMultisig3 {
+                m: 20,
+                allow_nested: true
+            })
+        );
+        assert_eq!(
+            parse(&[247, 2, 3]),
+            Ok(ParsedInstruction::SetMultisigSigners { m: 2, n: 3 })
+        );
+        assert_eq!(
+            parse(&[246, 5, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0xff, 0, 0, 0, 0, 0, 0, 0]),
+            Ok(ParsedInstruction::ApproveWithExpiry {
+                amount: 5,
+                decimals: 6,
+                expiry_kind: ExpiryKind::UnixTimestamp,
+                expiry: 255,
+            })
+        );
+        assert_eq!(
+            parse(&[245, 7, 0, 0, 0, 0, 0, 0, 0]),
+            Ok(ParsedInstruction::IncreaseAllowance { amount: 7 })
+        );
+        assert_eq!(
+            parse(&[242, 7, 0, 0, 0, 0, 0, 0, 0, 2]),
+            Ok(ParsedInstruction::DecreaseAllowanceChecked {
+                amount: 7,
+                decimals: 2
+            })
+        );
+        assert_eq!(parse(&[9]), Ok(ParsedInstruction::CloseAccount));
+        assert_eq!(parse(&[241]), Ok(ParsedInstruction::CloseDelegationRecord));
+        assert_eq!(parse(&[250]), Ok(ParsedInstruction::GetBalance));
+        assert_eq!(parse(&[249, 1]), Ok(ParsedInstruction::GetMintInfo));
+        assert_eq!(
+            parse(b"\x181.5"),
+            Ok(ParsedInstruction::UiAmountToAmount { ui_amount: "1.5" })
+        );
+
+        let Ok(ParsedInstruction::TransferMany { decimals, amounts }) =
+            parse(&[252, 6, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0])
+        else {
+            panic!("expected TransferMany");
+        };
+        assert_eq!(decimals, 6);
+        assert_eq!(amounts.len(), 2);
+        assert_eq!(amounts.get(1), Some(2));
+        assert_eq!(amounts.get(2), None);
+        assert!(amounts.iter().eq([1, 2]));
+
+        assert_eq!(
+            parse(&[255, 1, 1, 17]),
+            Ok(ParsedInstruction::Batch { data: &[1, 1, 17] })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases: [&[u8]; 23] = [
+            &[],
+            &[100],
+            &[25],
+            &[0, 6],
+            &[
+                0, 6, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                1, 1, 1, 1, 1, 1, 1,
+            ],
+            &[2],
+            &[3, 1, 0, 0, 0, 0, 0, 0],
+            &[6, 4, 0],
+            &[6, 0, 2],
+            &[6, 0, 1, 1],
+            &[12, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[18, 1],
+            &[24, 0xff],
+            &[252, 6],
+            &[251, 6, 1, 0, 0, 0],
+            &[248],
+            &[248, 20],
+            &[248, 20, 2],
+            &[247, 1],
+            &[246, 5, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[246, 5, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[244, 1, 0, 0],
+            &[243, 1, 0, 0, 0, 0, 0, 0, 0],
+        ];
+
+        for data in cases {
+            assert_eq!(parse(data), Err(TokenError::InvalidInstruction), "{data:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_exhaustive() {
+        // Enough data for any instruction.
+        let mut data = [0; 67];
+
+        for variant in TokenInstruction::iter() {
+            data[0] = variant.clone() as u8;
+            let data = match variant {
+                TokenInstruction::TransferMany | TokenInstruction::MintToMany => &data[..10],
+                _ => &data[..],
+            };
+            assert!(parse(data).is_ok(), "{variant:?}");
+        }
+    }
 }
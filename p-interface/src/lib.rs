@@ -1,9 +1,12 @@
 #![no_std]
 
+pub mod cpi;
 pub mod error;
 pub mod instruction;
 pub mod native_mint;
+pub mod query;
 pub mod state;
+pub mod ui_amount;
 
 pub mod program {
     pinocchio_pubkey::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
@@ -0,0 +1,309 @@
+//! Return data of the query instructions.
+//!
+//! The query instructions, i.e., `GetBalance` and `GetMintInfo`, set the state
+//! of an account as the return data using a fixed layout, decoded by the types
+//! of this module. Optional public keys are encoded as a `u8` tag, `0` when
+//! absent and `1` when present, followed by the public key, which is zeroed
+//! when absent. Integers are little-endian.
+
+use {
+    crate::state::account_state::AccountState,
+    pinocchio::{cpi::get_return_data, program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Return data of the `GetBalance` instruction.
+///
+/// Layout:
+///
+/// | Offset | Length | Field              |
+/// | ------ | ------ | ------------------ |
+/// | 0      | 8      | `amount`           |
+/// | 8      | 33     | `delegate`         |
+/// | 41     | 8      | `delegated_amount` |
+/// | 49     | 1      | `state`            |
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Balance {
+    /// The amount of tokens the account holds.
+    pub amount: u64,
+
+    /// The delegate of the account, if any.
+    pub delegate: Option<Pubkey>,
+
+    /// The amount authorized by the delegate.
+    pub delegated_amount: u64,
+
+    /// The state of the account.
+    pub state: AccountState,
+}
+
+impl Balance {
+    /// Length of the encoded balance.
+    pub const LEN: usize = 50;
+
+    /// Decodes a balance, returning [`ProgramError::InvalidAccountData`] when
+    /// the data does not have the expected layout.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramError> {
+        let bytes: &[u8; Self::LEN] = bytes
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            amount: read_u64(bytes, 0),
+            delegate: read_option_pubkey(bytes, 8)?,
+            delegated_amount: read_u64(bytes, 41),
+            state: AccountState::try_from(bytes[49])?,
+        })
+    }
+
+    /// Decodes the balance set as the return data by the token program.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] when there is no return
+    /// data, it was set by another program or it does not have the expected
+    /// layout.
+    pub fn from_return_data() -> Result<Self, ProgramError> {
+        from_return_data(Self::from_bytes)
+    }
+
+    /// Encodes the balance.
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        bytes[..8].copy_from_slice(&self.amount.to_le_bytes());
+        write_option_pubkey(&mut bytes, 8, self.delegate.as_ref());
+        bytes[41..49].copy_from_slice(&self.delegated_amount.to_le_bytes());
+        bytes[49] = self.state as u8;
+        bytes
+    }
+}
+
+/// Return data of the `GetMintInfo` instruction.
+///
+/// Layout:
+///
+/// | Offset | Length | Field              |
+/// | ------ | ------ | ------------------ |
+/// | 0      | 8      | `supply`           |
+/// | 8      | 1      | `decimals`         |
+/// | 9      | 33     | `mint_authority`   |
+/// | 42     | 33     | `freeze_authority` |
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintInfo {
+    /// Total supply of tokens.
+    pub supply: u64,
+
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+
+    /// The authority that can mint new tokens, if any.
+    pub mint_authority: Option<Pubkey>,
+
+    /// The authority that can freeze token accounts, if any.
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl MintInfo {
+    /// Length of the encoded mint information.
+    pub const LEN: usize = 75;
+
+    /// Decodes the mint information, returning
+    /// [`ProgramError::InvalidAccountData`] when the data does not have the
+    /// expected layout.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramError> {
+        let bytes: &[u8; Self::LEN] = bytes
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            supply: read_u64(bytes, 0),
+            decimals: bytes[8],
+            mint_authority: read_option_pubkey(bytes, 9)?,
+            freeze_authority: read_option_pubkey(bytes, 42)?,
+        })
+    }
+
+    /// Decodes the mint information set as the return data by the token
+    /// program.
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] when there is no return
+    /// data, it was set by another program or it does not have the expected
+    /// layout.
+    pub fn from_return_data() -> Result<Self, ProgramError> {
+        from_return_data(Self::from_bytes)
+    }
+
+    /// Encodes the mint information.
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        bytes[..8].copy_from_slice(&self.supply.to_le_bytes());
+        bytes[8] = self.decimals;
+        write_option_pubkey(&mut bytes, 9, self.mint_authority.as_ref());
+        write_option_pubkey(&mut bytes, 42, self.freeze_authority.as_ref());
+        bytes
+    }
+}
+
+/// Decodes the return data set by the token program.
+fn from_return_data<T>(
+    decode: impl FnOnce(&[u8]) -> Result<T, ProgramError>,
+) -> Result<T, ProgramError> {
+    match get_return_data() {
+        Some(data) if data.program_id() == &crate::program::ID => decode(data.as_slice()),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[inline(always)]
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut value = [0; 8];
+    value.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(value)
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[inline(always)]
+fn read_option_pubkey(bytes: &[u8], offset: usize) -> Result<Option<Pubkey>, ProgramError> {
+    let mut pubkey = Pubkey::default();
+    pubkey.copy_from_slice(&bytes[offset + 1..offset + 33]);
+
+    match bytes[offset] {
+        0 if pubkey == Pubkey::default() => Ok(None),
+        1 => Ok(Some(pubkey)),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[inline(always)]
+fn write_option_pubkey(bytes: &mut [u8], offset: usize, pubkey: Option<&Pubkey>) {
+    if let Some(pubkey) = pubkey {
+        bytes[offset] = 1;
+        bytes[offset + 1..offset + 33].copy_from_slice(pubkey);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_round_trip() {
+        let balances = [
+            Balance {
+                amount: 42,
+                delegate: Some([7; 32]),
+                delegated_amount: 10,
+                state: AccountState::Frozen,
+            },
+            Balance {
+                amount: u64::MAX,
+                delegate: None,
+                delegated_amount: 0,
+                state: AccountState::Initialized,
+            },
+        ];
+
+        for balance in balances {
+            assert_eq!(Balance::from_bytes(&balance.to_bytes()), Ok(balance));
+        }
+    }
+
+    #[test]
+    fn test_balance_layout() {
+        let bytes = Balance {
+            amount: 1,
+            delegate: Some([7; 32]),
+            delegated_amount: 2,
+            state: AccountState::Frozen,
+        }
+        .to_bytes();
+
+        assert_eq!(bytes[..8], [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[8], 1);
+        assert_eq!(bytes[9..41], [7; 32]);
+        assert_eq!(bytes[41..49], [2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[49], 2);
+    }
+
+    #[test]
+    fn test_balance_invalid() {
+        let bytes = Balance {
+            amount: 1,
+            delegate: None,
+            delegated_amount: 0,
+            state: AccountState::Initialized,
+        }
+        .to_bytes();
+
+        assert_eq!(
+            Balance::from_bytes(&bytes[..Balance::LEN - 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        for (offset, value) in [(8, 2), (9, 1), (49, 3)] {
+            let mut invalid = bytes;
+            invalid[offset] = value;
+            assert_eq!(
+                Balance::from_bytes(&invalid),
+                Err(ProgramError::InvalidAccountData)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mint_info_round_trip() {
+        let infos = [
+            MintInfo {
+                supply: 1_000,
+                decimals: 6,
+                mint_authority: Some([1; 32]),
+                freeze_authority: Some([2; 32]),
+            },
+            MintInfo {
+                supply: 0,
+                decimals: 0,
+                mint_authority: None,
+                freeze_authority: None,
+            },
+        ];
+
+        for info in infos {
+            assert_eq!(MintInfo::from_bytes(&info.to_bytes()), Ok(info));
+        }
+    }
+
+    #[test]
+    fn test_mint_info_layout() {
+        let bytes = MintInfo {
+            supply: 3,
+            decimals: 9,
+            mint_authority: None,
+            freeze_authority: Some([2; 32]),
+        }
+        .to_bytes();
+
+        assert_eq!(bytes[..8], [3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[8], 9);
+        assert_eq!(bytes[9..42], [0; 33]);
+        assert_eq!(bytes[42], 1);
+        assert_eq!(bytes[43..], [2; 32]);
+    }
+
+    #[test]
+    fn test_mint_info_invalid() {
+        let bytes = [0; MintInfo::LEN];
+
+        assert_eq!(
+            MintInfo::from_bytes(&[0; MintInfo::LEN + 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        for (offset, value) in [(9, 2), (42, 2), (74, 1)] {
+            let mut invalid = bytes;
+            invalid[offset] = value;
+            assert_eq!(
+                MintInfo::from_bytes(&invalid),
+                Err(ProgramError::InvalidAccountData)
+            );
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use {
-    super::{account_state::AccountState, COption, Initializable, Transmutable},
-    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    super::{
+        account_state::AccountState, load_from_account_info, load_mut_from_account_info,
+        validate_option_tag, COption, Initializable, Transmutable, Validate,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
 };
 
 /// Incinerator address.
@@ -46,6 +53,30 @@ pub struct Account {
 }
 
 impl Account {
+    /// Return a borrow-checked reference to the initialized token account of
+    /// the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// token account. The account data remains borrowed while the reference is
+    /// alive.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<Account>, ProgramError> {
+        load_from_account_info(account_info)
+    }
+
+    /// Return a borrow-checked mutable reference to the initialized token
+    /// account of the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// token account. The account data remains mutably borrowed while the
+    /// reference is alive.
+    #[inline(always)]
+    pub fn from_account_info_mut(
+        account_info: &AccountInfo,
+    ) -> Result<RefMut<Account>, ProgramError> {
+        load_mut_from_account_info(account_info)
+    }
+
     #[inline(always)]
     pub fn set_account_state(&mut self, state: AccountState) {
         self.state = state as u8;
@@ -161,3 +192,13 @@ impl Initializable for Account {
         AccountState::try_from(self.state).map(|state| state != AccountState::Uninitialized)
     }
 }
+
+impl Validate for Account {
+    #[inline(always)]
+    fn validate(&self) -> Result<(), ProgramError> {
+        validate_option_tag(&self.delegate.0)?;
+        AccountState::try_from(self.state)?;
+        validate_option_tag(&self.is_native)?;
+        validate_option_tag(&self.close_authority.0)
+    }
+}
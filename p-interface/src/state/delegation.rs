@@ -0,0 +1,140 @@
+use {
+    super::{
+        load_from_account_info, load_mut_from_account_info, Initializable, Transmutable, Validate,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::clock::Clock,
+    },
+};
+
+/// Kind of expiry of a delegation.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExpiryKind {
+    /// The delegation expires at a slot.
+    Slot,
+
+    /// The delegation expires at a unix timestamp.
+    UnixTimestamp,
+}
+
+impl TryFrom<u8> for ExpiryKind {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ExpiryKind::Slot),
+            1 => Ok(ExpiryKind::UnixTimestamp),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Delegation record of a delegation with an expiry, initialized by
+/// `ApproveWithExpiry`.
+///
+/// The key of the record is set as the delegate of the token account, so the
+/// record must be passed as the authority to use the delegation, followed by
+/// the delegate of the record.
+#[repr(C)]
+pub struct DelegationRecord {
+    /// The token account of the delegation.
+    pub account: Pubkey,
+
+    /// The delegate that can use the delegation.
+    pub delegate: Pubkey,
+
+    /// Slot or unix timestamp at which the delegation expires.
+    expiry: [u8; 8],
+
+    /// Kind of the expiry.
+    expiry_kind: u8,
+
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+}
+
+impl DelegationRecord {
+    /// Return a borrow-checked reference to the initialized delegation record
+    /// of the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// delegation record. The account data remains borrowed while the
+    /// reference is alive.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<Self>, ProgramError> {
+        load_from_account_info(account_info)
+    }
+
+    /// Return a borrow-checked mutable reference to the initialized delegation
+    /// record of the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// delegation record. The account data remains mutably borrowed while the
+    /// reference is alive.
+    #[inline(always)]
+    pub fn from_account_info_mut(account_info: &AccountInfo) -> Result<RefMut<Self>, ProgramError> {
+        load_mut_from_account_info(account_info)
+    }
+
+    /// Return the raw expiry, which is a `u64` slot or an `i64` unix timestamp
+    /// depending on the kind of expiry.
+    #[inline(always)]
+    pub fn expiry(&self) -> u64 {
+        u64::from_le_bytes(self.expiry)
+    }
+
+    #[inline(always)]
+    pub fn expiry_kind(&self) -> Result<ExpiryKind, ProgramError> {
+        ExpiryKind::try_from(self.expiry_kind)
+    }
+
+    #[inline(always)]
+    pub fn set_expiry(&mut self, kind: ExpiryKind, expiry: u64) {
+        self.expiry_kind = kind as u8;
+        self.expiry = expiry.to_le_bytes();
+    }
+
+    /// Return `true` if the delegation has expired at the given clock, i.e.,
+    /// the slot or unix timestamp of the clock is not before the expiry.
+    #[inline(always)]
+    pub fn is_expired(&self, clock: &Clock) -> Result<bool, ProgramError> {
+        Ok(match self.expiry_kind()? {
+            ExpiryKind::Slot => clock.slot >= self.expiry(),
+            ExpiryKind::UnixTimestamp => clock.unix_timestamp >= self.expiry() as i64,
+        })
+    }
+
+    #[inline(always)]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+}
+
+unsafe impl Transmutable for DelegationRecord {
+    /// The length of the delegation record account data.
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl Initializable for DelegationRecord {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+impl Validate for DelegationRecord {
+    #[inline(always)]
+    fn validate(&self) -> Result<(), ProgramError> {
+        self.expiry_kind()?;
+        self.is_initialized().map(|_| ())
+    }
+}
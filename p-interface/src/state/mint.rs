@@ -1,6 +1,13 @@
 use {
-    super::{COption, Initializable, Transmutable},
-    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    super::{
+        load_from_account_info, load_mut_from_account_info, validate_option_tag, COption,
+        Initializable, Transmutable, Validate,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
 };
 
 /// Internal representation of a mint data.
@@ -28,6 +35,27 @@ pub struct Mint {
 }
 
 impl Mint {
+    /// Return a borrow-checked reference to the initialized mint of the
+    /// given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// mint. The account data remains borrowed while the reference is alive.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<Mint>, ProgramError> {
+        load_from_account_info(account_info)
+    }
+
+    /// Return a borrow-checked mutable reference to the initialized mint of
+    /// the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// mint. The account data remains mutably borrowed while the reference
+    /// is alive.
+    #[inline(always)]
+    pub fn from_account_info_mut(account_info: &AccountInfo) -> Result<RefMut<Mint>, ProgramError> {
+        load_mut_from_account_info(account_info)
+    }
+
     #[inline(always)]
     pub fn set_supply(&mut self, supply: u64) {
         self.supply = supply.to_le_bytes();
@@ -99,3 +127,12 @@ impl Initializable for Mint {
         }
     }
 }
+
+impl Validate for Mint {
+    #[inline(always)]
+    fn validate(&self) -> Result<(), ProgramError> {
+        validate_option_tag(&self.mint_authority.0)?;
+        self.is_initialized()?;
+        validate_option_tag(&self.freeze_authority.0)
+    }
+}
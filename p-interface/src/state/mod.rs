@@ -1,11 +1,18 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio::{
+    account_info::{AccountInfo, Ref, RefMut},
+    program_error::ProgramError,
+};
 
 pub mod account;
 pub mod account_state;
+pub mod delegation;
 pub mod mint;
 pub mod multisig;
 
 /// Type alias for fields represented as `COption`.
+///
+/// The accessors only read the first byte of the 4-byte tag; [`Validate`]
+/// checks that the tag is either `[0, 0, 0, 0]` or `[1, 0, 0, 0]`.
 pub type COption<T> = ([u8; 4], T);
 
 /// Marker trait for types that can be cast from a raw pointer.
@@ -28,6 +35,25 @@ pub trait Initializable {
     fn is_initialized(&self) -> Result<bool, ProgramError>;
 }
 
+/// Trait to represent a type whose fields can be validated.
+pub trait Validate {
+    /// Return an error if any field does not have a valid representation.
+    ///
+    /// The checks match the `Pack` implementation of `spl-token`, which
+    /// rejects malformed fields with [`ProgramError::InvalidAccountData`].
+    /// This does not check if the data is initialized.
+    fn validate(&self) -> Result<(), ProgramError>;
+}
+
+/// Validate the tag of a `COption` field.
+#[inline(always)]
+fn validate_option_tag(tag: &[u8; 4]) -> Result<(), ProgramError> {
+    match tag {
+        [0, 0, 0, 0] | [1, 0, 0, 0] => Ok(()),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
 /// Return a reference for an initialized `T` from the given bytes.
 ///
 /// # Safety
@@ -45,6 +71,30 @@ pub unsafe fn load<T: Initializable + Transmutable>(bytes: &[u8]) -> Result<&T,
     })
 }
 
+/// Return a reference for an initialized and valid `T` from the given bytes.
+///
+/// This is stricter than [`load`], since every field must have a valid
+/// representation, and returns the same errors as `Pack::unpack` of
+/// `spl-token`.
+///
+/// # Safety
+///
+/// The caller must ensure that `bytes` contains a valid representation of `T`.
+#[inline(always)]
+pub unsafe fn load_strict<T: Initializable + Transmutable + Validate>(
+    bytes: &[u8],
+) -> Result<&T, ProgramError> {
+    let t: &T = load_unchecked(bytes)?;
+    // The fields are validated first, as `Pack::unpack` does.
+    t.validate()?;
+
+    if t.is_initialized()? {
+        Ok(t)
+    } else {
+        Err(ProgramError::UninitializedAccount)
+    }
+}
+
 /// Return a `T` reference from the given bytes.
 ///
 /// This function does not check if the data is initialized.
@@ -95,3 +145,277 @@ pub unsafe fn load_mut_unchecked<T: Transmutable>(
     }
     Ok(&mut *(bytes.as_mut_ptr() as *mut T))
 }
+
+/// Return a borrow-checked reference for an initialized `T` from the data of
+/// the given account.
+///
+/// The account must be owned by the token program and its data must have the
+/// length of `T`. The account data remains borrowed while the reference is
+/// alive.
+///
+/// This is only sound for types that can be represented by any bytes, so it
+/// is not exposed for arbitrary `Transmutable` types.
+#[inline(always)]
+fn load_from_account_info<T: Initializable + Transmutable>(
+    account_info: &AccountInfo,
+) -> Result<Ref<T>, ProgramError> {
+    if !account_info.is_owned_by(&crate::program::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let data = account_info.try_borrow_data()?;
+    // SAFETY: any bytes are a valid representation of `T`.
+    unsafe { load::<T>(&data)? };
+
+    // SAFETY: the length of the data was validated.
+    Ok(Ref::map(data, |data| unsafe {
+        &*(data.as_ptr() as *const T)
+    }))
+}
+
+/// Return a borrow-checked mutable reference for an initialized `T` from the
+/// data of the given account.
+///
+/// The account must be owned by the token program and its data must have the
+/// length of `T`. The account data remains mutably borrowed while the
+/// reference is alive.
+///
+/// This is only sound for types that can be represented by any bytes, so it
+/// is not exposed for arbitrary `Transmutable` types.
+#[inline(always)]
+fn load_mut_from_account_info<T: Initializable + Transmutable>(
+    account_info: &AccountInfo,
+) -> Result<RefMut<T>, ProgramError> {
+    if !account_info.is_owned_by(&crate::program::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    // SAFETY: any bytes are a valid representation of `T`.
+    unsafe { load_mut::<T>(&mut data)? };
+
+    // SAFETY: the length of the data was validated.
+    Ok(RefMut::map(data, |data| unsafe {
+        &mut *(data.as_mut_ptr() as *mut T)
+    }))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use {
+        super::{
+            account::Account,
+            load, load_strict,
+            mint::Mint,
+            multisig::{LargeMultisig, Multisig},
+            Transmutable,
+        },
+        core::mem::{size_of, transmute},
+        pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    };
+
+    /// Size of the account header in the input buffer of a program.
+    const ACCOUNT_HEADER_SIZE: usize = 88;
+
+    /// Size of the largest state type.
+    const MAX_DATA_LEN: usize = LargeMultisig::LEN;
+
+    /// Backing memory of an `AccountInfo`, laid out as in the input buffer of
+    /// a program: the account header followed by enough data for any of the
+    /// state types.
+    pub(crate) struct TestAccount(
+        [u64; (ACCOUNT_HEADER_SIZE + MAX_DATA_LEN).div_ceil(size_of::<u64>())],
+    );
+
+    impl TestAccount {
+        /// Creates an account without data whose key is filled with `key`.
+        pub(crate) fn new(key: u8) -> Self {
+            let mut account = Self::with_data(&Pubkey::default(), &[]);
+            account.header_mut()[8..40].copy_from_slice(&[key; 32]);
+            account
+        }
+
+        /// Creates an account with the given owner and data.
+        pub(crate) fn with_data(owner: &Pubkey, data: &[u8]) -> Self {
+            assert!(data.len() <= MAX_DATA_LEN);
+
+            let mut account =
+                Self([0; (ACCOUNT_HEADER_SIZE + MAX_DATA_LEN).div_ceil(size_of::<u64>())]);
+            let header = account.header_mut();
+            // Not borrowed.
+            header[0] = u8::MAX;
+            header[40..72].copy_from_slice(owner);
+            header[80..88].copy_from_slice(&(data.len() as u64).to_le_bytes());
+            // SAFETY: the buffer has `MAX_DATA_LEN` bytes following the header
+            // and `data` is not longer.
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    data.as_ptr(),
+                    (account.0.as_mut_ptr() as *mut u8).add(ACCOUNT_HEADER_SIZE),
+                    data.len(),
+                )
+            };
+            account
+        }
+
+        pub(crate) fn info(&mut self) -> AccountInfo {
+            // SAFETY: `AccountInfo` is a pointer to the account header.
+            unsafe { transmute::<*mut u64, AccountInfo>(self.0.as_mut_ptr()) }
+        }
+
+        fn header_mut(&mut self) -> &mut [u8] {
+            // SAFETY: the buffer has at least `ACCOUNT_HEADER_SIZE` bytes.
+            unsafe {
+                core::slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut u8, ACCOUNT_HEADER_SIZE)
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_account_info() {
+        let mut data = [0; Account::LEN];
+        // Initialized state.
+        data[108] = 1;
+        data[64..72].copy_from_slice(&42u64.to_le_bytes());
+
+        let mut account = TestAccount::with_data(&crate::program::ID, &data);
+        let info = account.info();
+
+        let token_account = Account::from_account_info(&info).unwrap();
+        assert_eq!(token_account.amount(), 42);
+
+        // The account data is borrowed while the view is alive.
+        assert_eq!(
+            info.try_borrow_mut_data().err(),
+            Some(ProgramError::AccountBorrowFailed)
+        );
+        assert!(Account::from_account_info_mut(&info).is_err());
+        drop(token_account);
+
+        let mut token_account = Account::from_account_info_mut(&info).unwrap();
+        token_account.set_amount(7);
+        assert!(info.try_borrow_data().is_err());
+        drop(token_account);
+
+        assert_eq!(Account::from_account_info(&info).unwrap().amount(), 7);
+    }
+
+    #[test]
+    fn test_from_account_info_invalid() {
+        let mut data = [0; Account::LEN];
+        data[108] = 1;
+
+        // Not owned by the token program.
+        let mut account = TestAccount::with_data(&[1; 32], &data);
+        assert_eq!(
+            Account::from_account_info(&account.info()).err(),
+            Some(ProgramError::IncorrectProgramId)
+        );
+
+        // Data of another type.
+        let mut account = TestAccount::with_data(&crate::program::ID, &data);
+        assert_eq!(
+            Mint::from_account_info(&account.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Multisig::from_account_info_mut(&account.info()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // Uninitialized.
+        let mut account = TestAccount::with_data(&crate::program::ID, &[0; Account::LEN]);
+        assert_eq!(
+            Account::from_account_info(&account.info()).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+        assert_eq!(
+            Account::from_account_info_mut(&account.info()).err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+    }
+
+    #[test]
+    fn test_load_strict() {
+        let mut data = [0; Account::LEN];
+        // Initialized state, with a delegate and close authority.
+        data[108] = 1;
+        data[72] = 1;
+        data[129] = 1;
+        // SAFETY: any bytes are a valid representation of `Account`.
+        assert!(unsafe { load_strict::<Account>(&data) }.is_ok());
+
+        // Malformed `delegate`, `state`, `is_native` and `close_authority`.
+        for (offset, value) in [(72, 2), (73, 1), (108, 3), (109, 2), (112, 1), (132, 1)] {
+            let mut invalid = data;
+            invalid[offset] = value;
+            // SAFETY: any bytes are a valid representation of `Account`.
+            assert_eq!(
+                unsafe { load_strict::<Account>(&invalid) }.err(),
+                Some(ProgramError::InvalidAccountData),
+                "offset {offset}"
+            );
+        }
+
+        // Only the first byte of the tag is read by `load`.
+        let mut invalid = data;
+        invalid[73] = 1;
+        // SAFETY: any bytes are a valid representation of `Account`.
+        assert!(unsafe { load::<Account>(&invalid) }.is_ok());
+
+        // Malformed fields are reported before the data is uninitialized.
+        let mut uninitialized = [0; Account::LEN];
+        // SAFETY: any bytes are a valid representation of `Account`.
+        assert_eq!(
+            unsafe { load_strict::<Account>(&uninitialized) }.err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+        uninitialized[110] = 1;
+        // SAFETY: any bytes are a valid representation of `Account`.
+        assert_eq!(
+            unsafe { load_strict::<Account>(&uninitialized) }.err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_load_strict_mint_and_multisig() {
+        let mut data = [0; Mint::LEN];
+        // Initialized, with a mint authority.
+        data[45] = 1;
+        data[0] = 1;
+        // SAFETY: any bytes are a valid representation of `Mint`.
+        assert!(unsafe { load_strict::<Mint>(&data) }.is_ok());
+
+        // Malformed `mint_authority`, `is_initialized` and `freeze_authority`.
+        for (offset, value) in [(0, 2), (3, 1), (45, 2), (46, 2), (49, 1)] {
+            let mut invalid = data;
+            invalid[offset] = value;
+            // SAFETY: any bytes are a valid representation of `Mint`.
+            assert_eq!(
+                unsafe { load_strict::<Mint>(&invalid) }.err(),
+                Some(ProgramError::InvalidAccountData),
+                "offset {offset}"
+            );
+        }
+
+        let mut data = [0; Multisig::LEN];
+        // SAFETY: any bytes are a valid representation of `Multisig`.
+        assert_eq!(
+            unsafe { load_strict::<Multisig>(&data) }.err(),
+            Some(ProgramError::UninitializedAccount)
+        );
+        data[2] = 2;
+        // SAFETY: any bytes are a valid representation of `Multisig`.
+        assert_eq!(
+            unsafe { load_strict::<Multisig>(&data) }.err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        // Wrong length.
+        // SAFETY: any bytes are a valid representation of `Multisig`.
+        assert_eq!(
+            unsafe { load_strict::<Multisig>(&data[1..]) }.err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
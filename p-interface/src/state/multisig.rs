@@ -1,6 +1,12 @@
 use {
-    super::{Initializable, Transmutable},
-    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+    super::{
+        load_from_account_info, load_mut_from_account_info, Initializable, Transmutable, Validate,
+    },
+    pinocchio::{
+        account_info::{AccountInfo, Ref, RefMut},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
 };
 
 /// Minimum number of multisignature signers (min N)
@@ -9,9 +15,28 @@ pub const MIN_SIGNERS: u8 = 1;
 /// Maximum number of multisignature signers (max N)
 pub const MAX_SIGNERS: u8 = 11;
 
-/// Multisignature data.
+/// Maximum number of signers of a large multisignature (max N)
+pub const MAX_LARGE_SIGNERS: u8 = 32;
+
+/// Maximum depth of nested multisignature authorities, i.e., multisigs that
+/// are members of a multisig authority, where a direct member has depth 1.
+///
+/// Only large multisignatures that allow nested members can have them.
+pub const MAX_MULTISIG_DEPTH: u8 = 2;
+
+/// Multisignature data with up to [`MAX_SIGNERS`] signers.
+pub type Multisig = GenericMultisig<{ MAX_SIGNERS as usize }>;
+
+/// Multisignature data with up to [`MAX_LARGE_SIGNERS`] signers, initialized
+/// by `InitializeMultisig3`, followed by whether nested members are allowed.
+pub type LargeMultisig = GenericMultisig<{ MAX_LARGE_SIGNERS as usize }, 1>;
+
+/// Multisignature data with up to `N` signers, followed by `F` flag bytes.
+///
+/// Each `N` has a different account data length, which is used to tell the
+/// multisignature types apart.
 #[repr(C)]
-pub struct Multisig {
+pub struct GenericMultisig<const N: usize, const F: usize = 0> {
     /// Number of signers required.
     pub m: u8,
 
@@ -22,28 +47,72 @@ pub struct Multisig {
     is_initialized: u8,
 
     /// Signer public keys.
-    pub signers: [Pubkey; MAX_SIGNERS as usize],
+    pub signers: [Pubkey; N],
+
+    /// Indicates whether nested members are allowed, if present.
+    allow_nested: [u8; F],
 }
 
-impl Multisig {
+impl<const N: usize, const F: usize> GenericMultisig<N, F> {
+    /// Return a borrow-checked reference to the initialized multisig of the
+    /// given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// multisig. The account data remains borrowed while the reference is
+    /// alive.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Ref<Self>, ProgramError> {
+        load_from_account_info(account_info)
+    }
+
+    /// Return a borrow-checked mutable reference to the initialized multisig of
+    /// the given account.
+    ///
+    /// The account must be owned by the token program and hold an initialized
+    /// multisig. The account data remains mutably borrowed while the reference
+    /// is alive.
+    #[inline(always)]
+    pub fn from_account_info_mut(account_info: &AccountInfo) -> Result<RefMut<Self>, ProgramError> {
+        load_mut_from_account_info(account_info)
+    }
+
     /// Utility function that checks index is between [`MIN_SIGNERS`] and
-    /// [`MAX_SIGNERS`].
+    /// `N`.
     pub fn is_valid_signer_index(index: u8) -> bool {
-        (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
+        (MIN_SIGNERS as usize..=N).contains(&(index as usize))
     }
 
     #[inline]
     pub fn set_initialized(&mut self, value: bool) {
         self.is_initialized = value as u8;
     }
+
+    /// Returns `true` if a signer that is itself a multisig can authorize
+    /// without signing, through its own signers.
+    ///
+    /// This is only the case for large multisignatures initialized with
+    /// nested members allowed.
+    #[inline(always)]
+    pub fn allows_nested(&self) -> bool {
+        self.allow_nested.first() == Some(&1)
+    }
+
+    /// Sets whether nested members are allowed, which is ignored when the
+    /// multisignature has no flag for it.
+    #[inline]
+    pub fn set_allow_nested(&mut self, value: bool) {
+        if let Some(allow_nested) = self.allow_nested.first_mut() {
+            *allow_nested = value as u8;
+        }
+    }
 }
 
-unsafe impl Transmutable for Multisig {
-    /// The length of the `Multisig` account data.
-    const LEN: usize = core::mem::size_of::<Multisig>();
+unsafe impl<const N: usize, const F: usize> Transmutable for GenericMultisig<N, F> {
+    /// The length of the multisignature account data.
+    const LEN: usize = core::mem::size_of::<Self>();
 }
 
-impl Initializable for Multisig {
+impl<const N: usize, const F: usize> Initializable for GenericMultisig<N, F> {
     #[inline(always)]
     fn is_initialized(&self) -> Result<bool, ProgramError> {
         match self.is_initialized {
@@ -53,3 +122,15 @@ impl Initializable for Multisig {
         }
     }
 }
+
+impl<const N: usize, const F: usize> Validate for GenericMultisig<N, F> {
+    #[inline(always)]
+    fn validate(&self) -> Result<(), ProgramError> {
+        self.is_initialized()?;
+
+        match self.allow_nested.first() {
+            None | Some(0) | Some(1) => Ok(()),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
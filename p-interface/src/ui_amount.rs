@@ -0,0 +1,417 @@
+//! Exact decimal representation of token amounts.
+
+use {
+    core::{
+        fmt,
+        str::{from_utf8_unchecked, FromStr},
+    },
+    pinocchio::program_error::ProgramError,
+};
+
+/// Maximum length of a formatted UI amount.
+///
+/// A `u8` number of decimals requires up to 255 fractional digits, plus the
+/// integer digit and the decimal point.
+pub const MAX_UI_AMOUNT_LEN: usize = u8::MAX as usize + 2;
+
+/// A token amount with its number of decimals, i.e., the value
+/// `amount / 10^decimals` represented without loss of precision.
+///
+/// Amounts are compared by value, so `1.5` and `1.50` are equal even though
+/// they have a different number of decimals.
+///
+/// The [`Display`](fmt::Display) implementation formats the amount with
+/// exactly `decimals` fractional digits, e.g., `1.500` for an amount of `1500`
+/// with `3` decimals. The alternate flag (`{:#}`) formats the trimmed form,
+/// without trailing zeros or an unneeded decimal point, e.g., `1.5`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UiAmount {
+    /// Raw amount.
+    amount: u64,
+
+    /// Number of decimals of the amount.
+    decimals: u8,
+}
+
+impl UiAmount {
+    /// Creates a new `UiAmount` from a raw amount and its number of decimals.
+    #[inline(always)]
+    pub const fn new(amount: u64, decimals: u8) -> Self {
+        Self { amount, decimals }
+    }
+
+    /// Returns the raw amount.
+    #[inline(always)]
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the number of decimals of the amount.
+    #[inline(always)]
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the same value with the given number of decimals.
+    ///
+    /// Returns `None` if the value cannot be represented exactly, i.e., the
+    /// raw amount overflows or fractional digits would be lost.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn checked_rescale(self, decimals: u8) -> Option<Self> {
+        if self.amount == 0 {
+            return Some(Self::new(0, decimals));
+        }
+
+        let amount = if decimals >= self.decimals {
+            10u64
+                .checked_pow((decimals - self.decimals) as u32)?
+                .checked_mul(self.amount)?
+        } else {
+            // A factor that overflows is larger than any non-zero amount.
+            let factor = 10u64.checked_pow((self.decimals - decimals) as u32)?;
+
+            if self.amount % factor != 0 {
+                return None;
+            }
+            self.amount / factor
+        };
+
+        Some(Self::new(amount, decimals))
+    }
+
+    /// Returns the raw amount of the value for a mint with the given number
+    /// of decimals.
+    ///
+    /// Returns `None` if the value cannot be represented exactly.
+    #[inline(always)]
+    pub fn to_amount(self, decimals: u8) -> Option<u64> {
+        self.checked_rescale(decimals).map(|value| value.amount)
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    ///
+    /// The result has the larger number of decimals of the two amounts.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let decimals = self.decimals.max(other.decimals);
+        let amount = self
+            .to_amount(decimals)?
+            .checked_add(other.to_amount(decimals)?)?;
+
+        Some(Self::new(amount, decimals))
+    }
+
+    /// Subtracts two amounts, returning `None` on overflow or if the result
+    /// would be negative.
+    ///
+    /// The result has the larger number of decimals of the two amounts.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let decimals = self.decimals.max(other.decimals);
+        let amount = self
+            .to_amount(decimals)?
+            .checked_sub(other.to_amount(decimals)?)?;
+
+        Some(Self::new(amount, decimals))
+    }
+
+    /// Multiplies the amount by an integer, returning `None` on overflow.
+    #[inline(always)]
+    pub fn checked_mul(self, multiplier: u64) -> Option<Self> {
+        Some(Self::new(
+            self.amount.checked_mul(multiplier)?,
+            self.decimals,
+        ))
+    }
+
+    /// Formats the amount with exactly `decimals` fractional digits into the
+    /// `buffer`, returning the formatted string.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn format_into<'a>(&self, buffer: &'a mut [u8; MAX_UI_AMOUNT_LEN]) -> &'a str {
+        let decimals = self.decimals as usize;
+        let mut amount = self.amount;
+        let mut position = buffer.len();
+        let mut digits = 0;
+
+        // Digits are written from the end of the buffer; there are at least
+        // `decimals + 1` digits, so the integer part is never empty.
+        loop {
+            if decimals > 0 && digits == decimals {
+                position -= 1;
+                buffer[position] = b'.';
+            }
+
+            position -= 1;
+            buffer[position] = b'0' + (amount % 10) as u8;
+            amount /= 10;
+            digits += 1;
+
+            if amount == 0 && digits > decimals {
+                break;
+            }
+        }
+
+        // SAFETY: the buffer only contains ASCII digits and a decimal point.
+        unsafe { from_utf8_unchecked(&buffer[position..]) }
+    }
+
+    /// Formats the amount into the `buffer` without trailing zeros or an
+    /// unneeded decimal point, returning the formatted string.
+    pub fn format_trimmed_into<'a>(&self, buffer: &'a mut [u8; MAX_UI_AMOUNT_LEN]) -> &'a str {
+        let formatted = self.format_into(buffer);
+
+        if self.decimals > 0 {
+            formatted.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            formatted
+        }
+    }
+
+    /// Returns the amount without trailing zeros in its fractional part.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn normalized(self) -> Self {
+        let mut value = self;
+
+        while value.decimals > 0 && value.amount % 10 == 0 {
+            value.amount /= 10;
+            value.decimals -= 1;
+        }
+
+        value
+    }
+}
+
+impl PartialEq for UiAmount {
+    fn eq(&self, other: &Self) -> bool {
+        let (this, other) = (self.normalized(), other.normalized());
+        this.amount == other.amount && this.decimals == other.decimals
+    }
+}
+
+impl Eq for UiAmount {}
+
+impl fmt::Display for UiAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+
+        if f.alternate() {
+            f.pad(self.format_trimmed_into(&mut buffer))
+        } else {
+            f.pad(self.format_into(&mut buffer))
+        }
+    }
+}
+
+/// Error returned when parsing an invalid UI amount.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseUiAmountError;
+
+impl fmt::Display for ParseUiAmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid UI amount")
+    }
+}
+
+impl From<ParseUiAmountError> for ProgramError {
+    fn from(_: ParseUiAmountError) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+impl FromStr for UiAmount {
+    type Err = ParseUiAmountError;
+
+    /// Parses a decimal number with an optional leading `+` sign and an
+    /// optional fractional part, e.g., `42`, `+42`, `1.5`, `.5` or `1.`.
+    ///
+    /// Trailing zeros of the fractional part are not significant, so `1.50`
+    /// is parsed as an amount of `15` with `1` decimal.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+        let fraction = fraction.trim_end_matches('0');
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(ParseUiAmountError);
+        }
+
+        let decimals = u8::try_from(fraction.len()).map_err(|_| ParseUiAmountError)?;
+        let mut amount = 0u64;
+
+        for digit in integer.bytes().chain(fraction.bytes()) {
+            if !digit.is_ascii_digit() {
+                return Err(ParseUiAmountError);
+            }
+
+            amount = amount
+                .checked_mul(10)
+                .and_then(|amount| amount.checked_add((digit - b'0') as u64))
+                .ok_or(ParseUiAmountError)?;
+        }
+
+        Ok(Self::new(amount, decimals))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UiAmount {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.format_into(&mut [0; MAX_UI_AMOUNT_LEN]))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UiAmount {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = UiAmount;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a decimal token amount")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<UiAmount, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use {
+        super::{ParseUiAmountError, UiAmount, MAX_UI_AMOUNT_LEN},
+        std::{format, string::ToString},
+    };
+
+    #[test]
+    fn test_format() {
+        assert_eq!(UiAmount::new(1500, 3).to_string(), "1.500");
+        assert_eq!(format!("{:#}", UiAmount::new(1500, 3)), "1.5");
+        assert_eq!(UiAmount::new(42, 0).to_string(), "42");
+        assert_eq!(UiAmount::new(7, 4).to_string(), "0.0007");
+        assert_eq!(UiAmount::new(0, 2).to_string(), "0.00");
+        assert_eq!(format!("{:#}", UiAmount::new(0, 2)), "0");
+        assert_eq!(format!("{:#}", UiAmount::new(1_000, 3)), "1");
+        assert_eq!(UiAmount::new(u64::MAX, 0).to_string(), u64::MAX.to_string());
+
+        let formatted = UiAmount::new(1, u8::MAX).to_string();
+        assert_eq!(formatted.len(), MAX_UI_AMOUNT_LEN);
+        assert!(formatted.starts_with("0.000"));
+        assert!(formatted.ends_with("0001"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<UiAmount>();
+
+        assert_eq!(
+            parse("1.50").map(|a| (a.amount(), a.decimals())),
+            Ok((15, 1))
+        );
+        assert_eq!(parse(".5"), Ok(UiAmount::new(5, 1)));
+        assert_eq!(parse("42."), Ok(UiAmount::new(42, 0)));
+        assert_eq!(parse("000042"), Ok(UiAmount::new(42, 0)));
+        assert_eq!(parse("+42"), Ok(UiAmount::new(42, 0)));
+        assert_eq!(parse("+.5"), Ok(UiAmount::new(5, 1)));
+        assert_eq!(
+            parse("18446744073709551615"),
+            Ok(UiAmount::new(u64::MAX, 0))
+        );
+
+        for invalid in [
+            "",
+            ".",
+            "1.2.3",
+            "0.t",
+            "+",
+            "+.",
+            "++1",
+            "-1",
+            " 1",
+            "18446744073709551616",
+        ] {
+            assert_eq!(parse(invalid), Err(ParseUiAmountError), "{invalid}");
+        }
+
+        // Trailing zeros beyond the maximum number of decimals.
+        assert_eq!(
+            parse(&format!("1.5{}", "0".repeat(300))),
+            Ok(UiAmount::new(15, 1))
+        );
+        assert!(parse(&format!("0.{}1", "0".repeat(255))).is_err());
+    }
+
+    #[test]
+    fn test_to_amount() {
+        let parse = |s: &str| s.parse::<UiAmount>().unwrap();
+
+        assert_eq!(parse("1.1").to_amount(1), Some(11));
+        assert_eq!(parse("0.23").to_amount(2), Some(23));
+        assert_eq!(parse("0.20").to_amount(1), Some(2));
+        assert_eq!(parse("42").to_amount(2), Some(4200));
+        assert_eq!(parse("0").to_amount(u8::MAX), Some(0));
+        assert_eq!(parse("0.111").to_amount(2), None);
+        assert_eq!(parse("1").to_amount(20), None);
+        assert_eq!(parse("18446744073709551615").to_amount(1), None);
+
+        let tiny = format!("0.{}1", "0".repeat(253));
+        assert_eq!(parse(&tiny).to_amount(u8::MAX), Some(10));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for decimals in [0, 1, 9, 19, 20, 100, u8::MAX] {
+            for amount in [0, 1, 10, 123_456_789, u64::MAX] {
+                let value = UiAmount::new(amount, decimals);
+
+                assert_eq!(value.to_string().parse(), Ok(value));
+                assert_eq!(format!("{value:#}").parse(), Ok(value));
+                assert_eq!(
+                    value
+                        .to_string()
+                        .parse::<UiAmount>()
+                        .unwrap()
+                        .to_amount(decimals),
+                    Some(amount)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = UiAmount::new(15, 1);
+        let b = UiAmount::new(25, 2);
+
+        assert_eq!(a.checked_add(b), Some(UiAmount::new(175, 2)));
+        assert_eq!(a.checked_sub(b), Some(UiAmount::new(125, 2)));
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(a.checked_mul(3), Some(UiAmount::new(45, 1)));
+        assert_eq!(UiAmount::new(u64::MAX, 0).checked_mul(2), None);
+        assert_eq!(
+            UiAmount::new(u64::MAX, 0).checked_add(UiAmount::new(1, 1)),
+            None
+        );
+
+        assert_eq!(a, UiAmount::new(1_500, 3));
+        assert_ne!(a, b);
+        assert_eq!(UiAmount::new(0, 0), UiAmount::new(0, u8::MAX));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let value = UiAmount::new(1500, 3);
+        let json = serde_json::to_string(&value).unwrap();
+
+        assert_eq!(json, "\"1.500\"");
+        assert_eq!(serde_json::from_str::<UiAmount>(&json).unwrap(), value);
+        assert!(serde_json::from_str::<UiAmount>("\"1.2.3\"").is_err());
+    }
+}
@@ -26,9 +26,9 @@ fn log_error(error: &ProgramError) {
 ///
 /// In the first stage, the entrypoint checks the discriminator of the
 /// instruction data to determine whether the instruction is a "batch"
-/// instruction or a "regular" instruction. This avoids nesting of "batch"
-/// instructions, since it is not sound to have a "batch" instruction inside
-/// another "batch" instruction.
+/// instruction (`Batch`, `IndexedBatch` or `BestEffortBatch`) or a "regular"
+/// instruction. This avoids nesting of "batch" instructions, since it is not
+/// sound to have a "batch" instruction inside another "batch" instruction.
 #[inline(always)]
 pub fn process_instruction(
     _program_id: &Pubkey,
@@ -45,6 +45,18 @@ pub fn process_instruction(
         pinocchio::msg!("Instruction: Batch");
 
         process_batch(accounts, remaining)
+    } else if *discriminator == 254 {
+        // 254 - IndexedBatch
+        #[cfg(feature = "logging")]
+        pinocchio::msg!("Instruction: IndexedBatch");
+
+        process_indexed_batch(accounts, remaining)
+    } else if *discriminator == 253 {
+        // 253 - BestEffortBatch
+        #[cfg(feature = "logging")]
+        pinocchio::msg!("Instruction: BestEffortBatch");
+
+        process_best_effort_batch(accounts, remaining)
     } else {
         inner_process_instruction(accounts, instruction_data)
     };
@@ -280,6 +292,90 @@ fn inner_process_remaining_instruction(
 
             process_withdraw_excess_lamports(accounts)
         }
+        // 252 - TransferMany
+        252 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferMany");
+
+            process_transfer_many(accounts, instruction_data)
+        }
+        // 251 - MintToMany
+        251 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: MintToMany");
+
+            process_mint_to_many(accounts, instruction_data)
+        }
+        // 250 - GetBalance
+        250 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetBalance");
+
+            process_get_balance(accounts)
+        }
+        // 249 - GetMintInfo
+        249 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetMintInfo");
+
+            process_get_mint_info(accounts)
+        }
+        // 248 - InitializeMultisig3
+        248 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeMultisig3");
+
+            process_initialize_multisig3(accounts, instruction_data)
+        }
+        // 247 - SetMultisigSigners
+        247 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetMultisigSigners");
+
+            process_set_multisig_signers(accounts, instruction_data)
+        }
+        // 246 - ApproveWithExpiry
+        246 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveWithExpiry");
+
+            process_approve_with_expiry(accounts, instruction_data)
+        }
+        // 245 - IncreaseAllowance
+        245 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: IncreaseAllowance");
+
+            process_increase_allowance(accounts, instruction_data)
+        }
+        // 244 - DecreaseAllowance
+        244 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DecreaseAllowance");
+
+            process_decrease_allowance(accounts, instruction_data)
+        }
+        // 243 - IncreaseAllowanceChecked
+        243 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: IncreaseAllowanceChecked");
+
+            process_increase_allowance_checked(accounts, instruction_data)
+        }
+        // 242 - DecreaseAllowanceChecked
+        242 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DecreaseAllowanceChecked");
+
+            process_decrease_allowance_checked(accounts, instruction_data)
+        }
+        // 241 - CloseDelegationRecord
+        241 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CloseDelegationRecord");
+
+            process_close_delegation_record(accounts)
+        }
         _ => Err(TokenError::InvalidInstruction.into()),
     }
 }
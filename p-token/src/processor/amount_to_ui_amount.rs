@@ -1,14 +1,13 @@
 use {
-    super::{check_account_owner, unpack_amount, MAX_FORMATTED_DIGITS},
-    core::str::from_utf8_unchecked,
+    super::{check_account_owner, unpack_amount},
     pinocchio::{
         account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
         ProgramResult,
     },
-    pinocchio_log::logger::{Argument, Logger},
     pinocchio_token_interface::{
         error::TokenError,
         state::{load, mint::Mint},
+        ui_amount::{UiAmount, MAX_UI_AMOUNT_LEN},
     },
 };
 
@@ -26,19 +25,10 @@ pub fn process_amount_to_ui_amount(
         load::<Mint>(mint_info.borrow_data_unchecked()).map_err(|_| TokenError::InvalidMint)?
     };
 
-    let mut logger = Logger::<MAX_FORMATTED_DIGITS>::default();
-    logger.append_with_args(amount, &[Argument::Precision(mint.decimals)]);
-    // "Extract" the formatted string from the logger.
-    //
-    // SAFETY: the logger is guaranteed to be a valid UTF-8 string.
-    let mut s = unsafe { from_utf8_unchecked(&logger) };
+    let mut buffer = [0; MAX_UI_AMOUNT_LEN];
+    let ui_amount = UiAmount::new(amount, mint.decimals).format_trimmed_into(&mut buffer);
 
-    if mint.decimals > 0 && s.contains('.') {
-        let zeros_trimmed = s.trim_end_matches('0');
-        s = zeros_trimmed.trim_end_matches('.');
-    }
-
-    set_return_data(s.as_bytes());
+    set_return_data(ui_amount.as_bytes());
 
     Ok(())
 }
@@ -0,0 +1,89 @@
+use {
+    super::{
+        check_account_owner, shared::approve::validate_approval, unpack_amount,
+        unpack_amount_and_decimals, U64_BYTES,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            delegation::{DelegationRecord, ExpiryKind},
+            load_mut_unchecked, Initializable, Validate,
+        },
+    },
+};
+
+pub fn process_approve_with_expiry(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u64 (8) + u8 (1) + u8 (1) + u64 (8)
+    let (amount, decimals) = unpack_amount_and_decimals(instruction_data)?;
+
+    let (expiry_kind, expiry) = match instruction_data.get(U64_BYTES + 1..) {
+        Some([expiry_kind, expiry @ ..]) => (
+            ExpiryKind::try_from(*expiry_kind).map_err(|_| TokenError::InvalidInstruction)?,
+            unpack_amount(expiry)?,
+        ),
+        _ => return Err(TokenError::InvalidInstruction.into()),
+    };
+
+    let [source_account_info, mint_info, delegation_record_info, delegate_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: `source_account_info` is not currently borrowed.
+    let source_account = unsafe {
+        validate_approval(
+            source_account_info,
+            Some((mint_info, decimals)),
+            owner_info,
+            remaining,
+        )?
+    };
+
+    // Validates the delegation record, which can only be reused for the same
+    // source account. A new record must be rent exempt, since it is kept until
+    // it is closed with `CloseDelegationRecord`.
+
+    check_account_owner(delegation_record_info)?;
+
+    // SAFETY: single mutable borrow to `delegation_record_info` account data;
+    // `load_mut_unchecked` validates the length of the data, so the record
+    // cannot be the source account.
+    let record = unsafe {
+        load_mut_unchecked::<DelegationRecord>(delegation_record_info.borrow_mut_data_unchecked())?
+    };
+
+    record.validate()?;
+
+    if record.is_initialized()? {
+        if &record.account != source_account_info.key() {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+    } else if !Rent::get()?.is_exempt(
+        delegation_record_info.lamports(),
+        delegation_record_info.data_len(),
+    ) {
+        return Err(TokenError::NotRentExempt.into());
+    }
+
+    record.account = *source_account_info.key();
+    record.delegate = *delegate_info.key();
+    record.set_expiry(expiry_kind, expiry);
+    record.set_initialized(true);
+
+    // Sets the record as the delegate of the source account.
+
+    source_account.set_delegate(delegation_record_info.key());
+    source_account.set_delegated_amount(amount);
+
+    Ok(())
+}
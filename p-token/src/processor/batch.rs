@@ -1,18 +1,15 @@
 use {
+    super::report_batch_failure,
     crate::entrypoint::inner_process_instruction,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
-    pinocchio_token_interface::error::TokenError,
+    pinocchio_token_interface::{error::TokenError, instruction::IX_HEADER_SIZE},
 };
 
-/// The size of the batch instruction header.
-///
-/// The header of each instruction consists of two `u8` values:
-///  * number of the accounts
-///  * length of the instruction data
-const IX_HEADER_SIZE: usize = 2;
-
 #[allow(clippy::arithmetic_side_effects)]
 pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8]) -> ProgramResult {
+    // Index of the instruction being processed.
+    let mut index: u16 = 0;
+
     loop {
         // Validates the instruction data and accounts offset.
 
@@ -46,7 +43,10 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
             )
         };
 
-        inner_process_instruction(ix_accounts, ix_data)?;
+        inner_process_instruction(ix_accounts, ix_data).inspect_err(|_| {
+            // SAFETY: `ix_data` is guaranteed to have at least one byte (discriminator).
+            report_batch_failure(255, index, unsafe { *ix_data.get_unchecked(0) })
+        })?;
 
         if data_offset == instruction_data.len() {
             // The batch is complete.
@@ -55,6 +55,7 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
 
         accounts = &accounts[expected_accounts..];
         instruction_data = &instruction_data[data_offset..];
+        index += 1;
     }
 
     Ok(())
@@ -0,0 +1,211 @@
+use {
+    super::report_batch_failure,
+    crate::entrypoint::inner_process_instruction,
+    core::mem::MaybeUninit,
+    pinocchio::{
+        account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        instruction::{
+            IX_HEADER_SIZE, MAX_BEST_EFFORT_INSTRUCTIONS, MAX_BEST_EFFORT_WRITABLE_ACCOUNTS,
+        },
+        program::ID as TOKEN_PROGRAM_ID,
+        state::{
+            account::Account,
+            delegation::DelegationRecord,
+            mint::Mint,
+            multisig::{LargeMultisig, Multisig},
+            Transmutable,
+        },
+    },
+};
+
+/// State of a writable account before an instruction is processed.
+struct AccountSnapshot<'a> {
+    /// The account.
+    account: &'a AccountInfo,
+
+    /// Lamports of the account.
+    lamports: u64,
+
+    /// Length of the account data.
+    data_len: usize,
+
+    /// Copy of the account data, only used when `compare_data` is `true`.
+    data: [u8; Multisig::LEN],
+
+    /// Indicates whether the account data is compared or not.
+    compare_data: bool,
+
+    /// Indicates whether the account can be compared at all. The data of a
+    /// large multisig does not fit in the snapshot, so an instruction that
+    /// fails with one as a writable account cannot be skipped.
+    comparable: bool,
+}
+
+impl<'a> AccountSnapshot<'a> {
+    #[inline(always)]
+    fn new(account: &'a AccountInfo) -> Self {
+        let data_len = account.data_len();
+        // Only accounts owned by the token program with the length of one of
+        // the token program's types can have their data modified by the program.
+        let is_owned = account.is_owned_by(&TOKEN_PROGRAM_ID);
+        let compare_data = is_owned
+            && (data_len == Account::LEN
+                || data_len == Mint::LEN
+                || data_len == Multisig::LEN
+                || data_len == DelegationRecord::LEN);
+
+        let mut data = [0u8; Multisig::LEN];
+
+        if compare_data {
+            // SAFETY: There are no active borrows of the account data since no
+            // instruction is being processed.
+            data[..data_len].copy_from_slice(unsafe { account.borrow_data_unchecked() });
+        }
+
+        Self {
+            account,
+            lamports: account.lamports(),
+            data_len,
+            data,
+            compare_data,
+            comparable: !(is_owned && data_len == LargeMultisig::LEN),
+        }
+    }
+
+    /// Checks whether the account is in the same state as when the snapshot
+    /// was taken.
+    #[inline(always)]
+    fn is_unchanged(&self) -> bool {
+        self.comparable
+            && self.account.lamports() == self.lamports
+            && self.account.data_len() == self.data_len
+            // SAFETY: There are no active borrows of the account data since the
+            // instruction has already been processed.
+            && (!self.compare_data
+                || unsafe { self.account.borrow_data_unchecked() } == &self.data[..self.data_len])
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_best_effort_batch(
+    mut accounts: &[AccountInfo],
+    mut instruction_data: &[u8],
+) -> ProgramResult {
+    // Bitmap with the result of each instruction.
+    let mut results = [0u8; MAX_BEST_EFFORT_INSTRUCTIONS / 8];
+
+    // Index of the instruction being processed.
+    let mut index: u16 = 0;
+
+    loop {
+        // Validates the instruction data and accounts offset.
+
+        if instruction_data.len() < IX_HEADER_SIZE {
+            // The instruction data must have at least two bytes.
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        if index as usize >= MAX_BEST_EFFORT_INSTRUCTIONS {
+            // The result of the instruction cannot be represented.
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        // SAFETY: The instruction data is guaranteed to have at least two bytes
+        // (header) + one byte (discriminator) and the values are within the bounds
+        // of an `usize`.
+        let expected_accounts = unsafe { *instruction_data.get_unchecked(0) as usize };
+        let data_offset = IX_HEADER_SIZE + unsafe { *instruction_data.get_unchecked(1) as usize };
+
+        if instruction_data.len() < data_offset || data_offset == IX_HEADER_SIZE {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        if accounts.len() < expected_accounts {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        // SAFETY: The instruction data and accounts lengths are already validated so
+        // all slices are guaranteed to be valid.
+        let (ix_accounts, ix_data) = unsafe {
+            (
+                accounts.get_unchecked(..expected_accounts),
+                instruction_data.get_unchecked(IX_HEADER_SIZE..data_offset),
+            )
+        };
+
+        // SAFETY: `ix_data` is guaranteed to have at least one byte (discriminator).
+        let discriminator = unsafe { *ix_data.get_unchecked(0) };
+
+        // Batch instructions cannot be nested, so the batch fails instead of
+        // skipping them.
+        if discriminator >= 253 {
+            report_batch_failure(253, index, discriminator);
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        // Takes a snapshot of the writable accounts of the instruction, which
+        // determines whether the instruction can be skipped on failure.
+
+        let mut snapshots =
+            [const { MaybeUninit::<AccountSnapshot>::uninit() }; MAX_BEST_EFFORT_WRITABLE_ACCOUNTS];
+        let mut snapshots_len = 0;
+        let mut skippable = true;
+
+        for account in ix_accounts.iter().filter(|account| account.is_writable()) {
+            if snapshots_len == MAX_BEST_EFFORT_WRITABLE_ACCOUNTS {
+                skippable = false;
+                break;
+            }
+
+            snapshots[snapshots_len].write(AccountSnapshot::new(account));
+            snapshots_len += 1;
+        }
+
+        // Process the instruction.
+
+        match inner_process_instruction(ix_accounts, ix_data) {
+            Ok(()) => results[index as usize / 8] |= 1 << (index % 8),
+            Err(error) => {
+                // SAFETY: The first `snapshots_len` elements of `snapshots` were
+                // initialized above.
+                let unchanged = skippable
+                    && snapshots[..snapshots_len]
+                        .iter()
+                        .all(|snapshot| unsafe { snapshot.assume_init_ref() }.is_unchanged());
+
+                if !unchanged {
+                    report_batch_failure(253, index, discriminator);
+                    return Err(error);
+                }
+
+                #[cfg(feature = "logging")]
+                {
+                    let mut logger = pinocchio_log::logger::Logger::<64>::default();
+                    logger
+                        .append("Batch instruction skipped: index ")
+                        .append(index)
+                        .append(", discriminator ")
+                        .append(discriminator);
+                    logger.log();
+                }
+            }
+        }
+
+        if data_offset == instruction_data.len() {
+            // The batch is complete.
+            break;
+        }
+
+        accounts = &accounts[expected_accounts..];
+        instruction_data = &instruction_data[data_offset..];
+        index += 1;
+    }
+
+    set_return_data(&results[..(index as usize / 8) + 1]);
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, delegation::DelegationRecord, load_mut, load_strict},
+    },
+};
+
+#[inline(always)]
+pub fn process_close_delegation_record(accounts: &[AccountInfo]) -> ProgramResult {
+    let [delegation_record_info, source_account_info, destination_account_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if delegation_record_info == destination_account_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    check_account_owner(delegation_record_info)?;
+
+    // SAFETY: scoped immutable borrow to `delegation_record_info` account data
+    // and `load_strict` validates that the record is initialized.
+    let record_account = unsafe {
+        load_strict::<DelegationRecord>(delegation_record_info.borrow_data_unchecked())?.account
+    };
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized; the length of the
+    // data differs from the record, so they cannot be the same account.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if &record_account != source_account_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Revokes the delegation when the record is still the delegate, since its
+    // key would otherwise be left as a plain delegate.
+    if source_account.delegate() == Some(delegation_record_info.key()) {
+        source_account.clear_delegate();
+        source_account.set_delegated_amount(0);
+    }
+
+    let destination_starting_lamports = destination_account_info.lamports();
+    // SAFETY: single mutable borrow to `destination_account_info` lamports and
+    // there are no "active" borrows of `delegation_record_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        *destination_account_info.borrow_mut_lamports_unchecked() = destination_starting_lamports
+            .checked_add(delegation_record_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+        // Closes the delegation record.
+        delegation_record_info.close_unchecked();
+    }
+
+    Ok(())
+}
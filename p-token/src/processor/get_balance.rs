@@ -0,0 +1,35 @@
+use {
+    super::check_account_owner,
+    pinocchio::{
+        account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        query::Balance,
+        state::{account::Account, load},
+    },
+};
+
+#[inline(always)]
+pub fn process_get_balance(accounts: &[AccountInfo]) -> ProgramResult {
+    let [account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(account_info)?;
+
+    // SAFETY: single immutable borrow to `account_info` account data and
+    // `load` validates that the account is initialized.
+    let account = unsafe { load::<Account>(account_info.borrow_data_unchecked())? };
+
+    let balance = Balance {
+        amount: account.amount(),
+        delegate: account.delegate().copied(),
+        delegated_amount: account.delegated_amount(),
+        state: account.account_state()?,
+    };
+
+    set_return_data(&balance.to_bytes());
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use {
+    super::check_account_owner,
+    pinocchio::{
+        account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        query::MintInfo,
+        state::{load, mint::Mint},
+    },
+};
+
+#[inline(always)]
+pub fn process_get_mint_info(accounts: &[AccountInfo]) -> ProgramResult {
+    let [mint_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(mint_info)?;
+
+    // SAFETY: single immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe {
+        load::<Mint>(mint_info.borrow_data_unchecked()).map_err(|_| TokenError::InvalidMint)?
+    };
+
+    let info = MintInfo {
+        supply: mint.supply(),
+        decimals: mint.decimals,
+        mint_authority: mint.mint_authority().copied(),
+        freeze_authority: mint.freeze_authority().copied(),
+    };
+
+    set_return_data(&info.to_bytes());
+
+    Ok(())
+}
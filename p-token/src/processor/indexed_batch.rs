@@ -0,0 +1,87 @@
+use {
+    super::report_batch_failure,
+    crate::entrypoint::inner_process_instruction,
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        instruction::{IX_HEADER_SIZE, MAX_INDEXED_ACCOUNTS},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_indexed_batch(
+    accounts: &[AccountInfo],
+    mut instruction_data: &[u8],
+) -> ProgramResult {
+    // Buffer to hold the accounts of each instruction.
+    let mut accounts_buffer =
+        [const { MaybeUninit::<AccountInfo>::uninit() }; MAX_INDEXED_ACCOUNTS];
+
+    // Index of the instruction being processed.
+    let mut index: u16 = 0;
+
+    loop {
+        // Validates the instruction data and accounts offset.
+
+        if instruction_data.len() < IX_HEADER_SIZE {
+            // The instruction data must have at least two bytes.
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        // SAFETY: The instruction data is guaranteed to have at least two bytes
+        // (header) and the values are within the bounds of an `usize`.
+        let expected_accounts = unsafe { *instruction_data.get_unchecked(0) as usize };
+        let indices_offset = IX_HEADER_SIZE + expected_accounts;
+        let data_offset = indices_offset + unsafe { *instruction_data.get_unchecked(1) as usize };
+
+        if instruction_data.len() < data_offset
+            || data_offset == indices_offset
+            || expected_accounts > MAX_INDEXED_ACCOUNTS
+        {
+            return Err(TokenError::InvalidInstruction.into());
+        }
+
+        // SAFETY: The instruction data length is already validated so the
+        // slice is guaranteed to be valid.
+        let indices = unsafe { instruction_data.get_unchecked(IX_HEADER_SIZE..indices_offset) };
+
+        for (ix_account, index) in accounts_buffer.iter_mut().zip(indices) {
+            let account = accounts
+                .get(*index as usize)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            // Note: Duplicated `AccountInfo`s point to the same account, which is
+            // the same representation used by the runtime for duplicated accounts.
+            ix_account.write(account.clone());
+        }
+
+        // Process the instruction.
+
+        // SAFETY: The first `expected_accounts` elements of `accounts_buffer` were
+        // initialized above and the instruction data length is already validated.
+        let (ix_accounts, ix_data) = unsafe {
+            (
+                from_raw_parts(
+                    accounts_buffer.as_ptr() as *const AccountInfo,
+                    expected_accounts,
+                ),
+                instruction_data.get_unchecked(indices_offset..data_offset),
+            )
+        };
+
+        inner_process_instruction(ix_accounts, ix_data).inspect_err(|_| {
+            // SAFETY: `ix_data` is guaranteed to have at least one byte (discriminator).
+            report_batch_failure(254, index, unsafe { *ix_data.get_unchecked(0) })
+        })?;
+
+        if data_offset == instruction_data.len() {
+            // The batch is complete.
+            break;
+        }
+
+        instruction_data = &instruction_data[data_offset..];
+        index += 1;
+    }
+
+    Ok(())
+}
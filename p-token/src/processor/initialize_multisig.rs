@@ -1,7 +1,7 @@
 use {
     super::shared,
     pinocchio::{account_info::AccountInfo, ProgramResult},
-    pinocchio_token_interface::error::TokenError,
+    pinocchio_token_interface::{error::TokenError, state::multisig::MAX_SIGNERS},
 };
 
 #[inline(always)]
@@ -13,5 +13,7 @@ pub fn process_initialize_multisig(
         .first()
         .ok_or(TokenError::InvalidInstruction)?;
 
-    shared::initialize_multisig::process_initialize_multisig(accounts, *m, true)
+    shared::initialize_multisig::process_initialize_multisig::<{ MAX_SIGNERS as usize }, 0>(
+        accounts, *m, false, true,
+    )
 }
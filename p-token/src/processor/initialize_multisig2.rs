@@ -1,7 +1,7 @@
 use {
     super::shared,
     pinocchio::{account_info::AccountInfo, ProgramResult},
-    pinocchio_token_interface::error::TokenError,
+    pinocchio_token_interface::{error::TokenError, state::multisig::MAX_SIGNERS},
 };
 
 pub fn process_initialize_multisig2(
@@ -12,5 +12,7 @@ pub fn process_initialize_multisig2(
         .first()
         .ok_or(TokenError::InvalidInstruction)?;
 
-    shared::initialize_multisig::process_initialize_multisig(accounts, *m, false)
+    shared::initialize_multisig::process_initialize_multisig::<{ MAX_SIGNERS as usize }, 0>(
+        accounts, *m, false, false,
+    )
 }
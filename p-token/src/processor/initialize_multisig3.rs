@@ -0,0 +1,21 @@
+use {
+    super::shared,
+    pinocchio::{account_info::AccountInfo, ProgramResult},
+    pinocchio_token_interface::{error::TokenError, state::multisig::MAX_LARGE_SIGNERS},
+};
+
+pub fn process_initialize_multisig3(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [m, allow_nested @ (0 | 1), ..] = instruction_data else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    shared::initialize_multisig::process_initialize_multisig::<{ MAX_LARGE_SIGNERS as usize }, 1>(
+        accounts,
+        *m,
+        *allow_nested == 1,
+        false,
+    )
+}
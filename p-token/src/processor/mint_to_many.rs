@@ -0,0 +1,101 @@
+use {
+    super::{check_account_owner, shared::mint_to::validate_mint_authority, U64_BYTES},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load_mut, mint::Mint},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_mint_to_many(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + [u64] (8 * N)
+    let [expected_decimals, amounts @ ..] = instruction_data else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    if amounts.is_empty() || amounts.len() % U64_BYTES != 0 {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let [mint_info, owner_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let destinations_len = amounts.len() / U64_BYTES;
+
+    if remaining.len() < destinations_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let (destination_account_infos, signers) = remaining.split_at(destinations_len);
+
+    // SAFETY: `amounts` length is a multiple of `U64_BYTES`, so each chunk
+    // is guaranteed to have `U64_BYTES` bytes.
+    let amounts = amounts
+        .chunks_exact(U64_BYTES)
+        .map(|amount| unsafe { u64::from_le_bytes(*(amount.as_ptr() as *const [u8; U64_BYTES])) });
+
+    // Validates the mint and its authority once for all destinations.
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    if *expected_decimals != mint.decimals {
+        return Err(TokenError::MintDecimalsMismatch.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_mint_authority(mint, owner_info, signers)? };
+
+    // Validates the mint supply once for the total amount.
+    //
+    // Note: the sum of the amounts cannot overflow a `u128`, since it would
+    // require more than `u64::MAX` amounts.
+    let total_amount = amounts.clone().map(u128::from).sum::<u128>();
+
+    let mint_supply =
+        u64::try_from(mint.supply() as u128 + total_amount).map_err(|_| TokenError::Overflow)?;
+
+    // Credits the destination accounts.
+
+    for (destination_account_info, amount) in destination_account_infos.iter().zip(amounts) {
+        // SAFETY: scoped mutable borrow to `destination_account_info` account data
+        // and `load_mut` validates that the account is initialized; additionally,
+        // token accounts and mints have different lengths, so the account is
+        // guaranteed to be different than `mint_info`.
+        let destination_account =
+            unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+
+        if destination_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        if destination_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+
+        if mint_info.key() != &destination_account.mint {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        if amount == 0 {
+            // Validates the account owner since we are not writing
+            // to the account.
+            check_account_owner(destination_account_info)?;
+        } else {
+            // This should not fail since there is no overflow on the mint supply.
+            destination_account.set_amount(destination_account.amount() + amount);
+        }
+    }
+
+    if total_amount == 0 {
+        // Validates the mint owner since we are not writing to the account.
+        check_account_owner(mint_info)?;
+    } else {
+        mint.set_supply(mint_supply);
+    }
+
+    Ok(())
+}
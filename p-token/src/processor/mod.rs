@@ -1,15 +1,23 @@
 use {
-    core::{slice::from_raw_parts, str::from_utf8_unchecked},
     pinocchio::{
-        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
-        syscalls::sol_memcpy_, ProgramResult,
+        account_info::AccountInfo,
+        program::set_return_data,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+        ProgramResult,
     },
     pinocchio_token_interface::{
         error::TokenError,
+        instruction::BATCH_FAILURE_LEN,
         program::ID as TOKEN_PROGRAM_ID,
         state::{
-            load,
-            multisig::{Multisig, MAX_SIGNERS},
+            delegation::DelegationRecord,
+            load_strict,
+            multisig::{
+                GenericMultisig, LargeMultisig, Multisig, MAX_LARGE_SIGNERS, MAX_MULTISIG_DEPTH,
+                MAX_SIGNERS,
+            },
             Transmutable,
         },
     },
@@ -18,12 +26,22 @@ use {
 pub mod amount_to_ui_amount;
 pub mod approve;
 pub mod approve_checked;
+pub mod approve_with_expiry;
 pub mod batch;
+pub mod best_effort_batch;
 pub mod burn;
 pub mod burn_checked;
 pub mod close_account;
+pub mod close_delegation_record;
+pub mod decrease_allowance;
+pub mod decrease_allowance_checked;
 pub mod freeze_account;
 pub mod get_account_data_size;
+pub mod get_balance;
+pub mod get_mint_info;
+pub mod increase_allowance;
+pub mod increase_allowance_checked;
+pub mod indexed_batch;
 pub mod initialize_account;
 pub mod initialize_account2;
 pub mod initialize_account3;
@@ -32,14 +50,18 @@ pub mod initialize_mint;
 pub mod initialize_mint2;
 pub mod initialize_multisig;
 pub mod initialize_multisig2;
+pub mod initialize_multisig3;
 pub mod mint_to;
 pub mod mint_to_checked;
+pub mod mint_to_many;
 pub mod revoke;
 pub mod set_authority;
+pub mod set_multisig_signers;
 pub mod sync_native;
 pub mod thaw_account;
 pub mod transfer;
 pub mod transfer_checked;
+pub mod transfer_many;
 pub mod ui_amount_to_amount;
 pub mod withdraw_excess_lamports;
 // Shared processors.
@@ -47,32 +69,58 @@ pub mod shared;
 
 pub use {
     amount_to_ui_amount::process_amount_to_ui_amount, approve::process_approve,
-    approve_checked::process_approve_checked, batch::process_batch, burn::process_burn,
+    approve_checked::process_approve_checked, approve_with_expiry::process_approve_with_expiry,
+    batch::process_batch, best_effort_batch::process_best_effort_batch, burn::process_burn,
     burn_checked::process_burn_checked, close_account::process_close_account,
+    close_delegation_record::process_close_delegation_record,
+    decrease_allowance::process_decrease_allowance,
+    decrease_allowance_checked::process_decrease_allowance_checked,
     freeze_account::process_freeze_account, get_account_data_size::process_get_account_data_size,
-    initialize_account::process_initialize_account,
+    get_balance::process_get_balance, get_mint_info::process_get_mint_info,
+    increase_allowance::process_increase_allowance,
+    increase_allowance_checked::process_increase_allowance_checked,
+    indexed_batch::process_indexed_batch, initialize_account::process_initialize_account,
     initialize_account2::process_initialize_account2,
     initialize_account3::process_initialize_account3,
     initialize_immutable_owner::process_initialize_immutable_owner,
     initialize_mint::process_initialize_mint, initialize_mint2::process_initialize_mint2,
     initialize_multisig::process_initialize_multisig,
-    initialize_multisig2::process_initialize_multisig2, mint_to::process_mint_to,
-    mint_to_checked::process_mint_to_checked, revoke::process_revoke,
-    set_authority::process_set_authority, sync_native::process_sync_native,
+    initialize_multisig2::process_initialize_multisig2,
+    initialize_multisig3::process_initialize_multisig3, mint_to::process_mint_to,
+    mint_to_checked::process_mint_to_checked, mint_to_many::process_mint_to_many,
+    revoke::process_revoke, set_authority::process_set_authority,
+    set_multisig_signers::process_set_multisig_signers, sync_native::process_sync_native,
     thaw_account::process_thaw_account, transfer::process_transfer,
-    transfer_checked::process_transfer_checked, ui_amount_to_amount::process_ui_amount_to_amount,
+    transfer_checked::process_transfer_checked, transfer_many::process_transfer_many,
+    ui_amount_to_amount::process_ui_amount_to_amount,
     withdraw_excess_lamports::process_withdraw_excess_lamports,
 };
 
 /// Number of bytes in a `u64`.
 const U64_BYTES: usize = core::mem::size_of::<u64>();
 
-/// Maximum number of digits in a formatted `u64`.
+/// Reports the failure of an instruction of a batch.
 ///
-/// The maximum number of digits is equal to the maximum number
-/// of decimals (`u8::MAX`) plus the length of the decimal point
-/// and the leading zero.
-const MAX_FORMATTED_DIGITS: usize = u8::MAX as usize + 2;
+/// The failure is reported in the return data using the layout described by
+/// [`BATCH_FAILURE_LEN`] and, when the `logging` feature is enabled, also
+/// logged.
+#[cold]
+fn report_batch_failure(batch_discriminator: u8, index: u16, discriminator: u8) {
+    #[cfg(feature = "logging")]
+    {
+        let mut logger = pinocchio_log::logger::Logger::<64>::default();
+        logger
+            .append("Batch instruction failed: index ")
+            .append(index)
+            .append(", discriminator ")
+            .append(discriminator);
+        logger.log();
+    }
+
+    let [index_lo, index_hi] = index.to_le_bytes();
+    let failure: [u8; BATCH_FAILURE_LEN] = [batch_discriminator, index_lo, index_hi, discriminator];
+    set_return_data(&failure);
+}
 
 /// Checks that the account is owned by the expected program.
 #[inline(always)]
@@ -86,12 +134,16 @@ fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
 
 /// Validates owner(s) are present.
 ///
+/// When the owner is a large multisig that allows nested members, a member
+/// that is itself a multisig can authorize without signing: it is then passed
+/// as a non-signer account followed by exactly `m` signer accounts of its own,
+/// laid out the same way, up to [`MAX_MULTISIG_DEPTH`] levels of nesting.
 ///
 /// # Safety
 ///
-/// The `owner_account_info` will be immutable borrowed when it represents
-/// a multisig account, therefore it should not have any mutable borrows when
-/// calling this function.
+/// The `owner_account_info` and nested multisig accounts will be immutable
+/// borrowed when they represent a multisig account, therefore they should not
+/// have any mutable borrows when calling this function.
 #[inline(always)]
 #[allow(clippy::arithmetic_side_effects)]
 unsafe fn validate_owner(
@@ -103,87 +155,185 @@ unsafe fn validate_owner(
         return Err(TokenError::OwnerMismatch.into());
     }
 
-    if owner_account_info.data_len() == Multisig::LEN
-        && owner_account_info.is_owned_by(&TOKEN_PROGRAM_ID)
-    {
+    if owner_account_info.is_owned_by(&TOKEN_PROGRAM_ID) {
         // SAFETY: the caller guarantees that there are no mutable borrows of
-        // `owner_account_info` account data and the `load` validates that the
-        // account is initialized; additionally, `Multisig` accounts are only
-        // ever loaded in this function, which means that previous loads will
-        // have already failed by the time we get here.
-        let multisig = unsafe { load::<Multisig>(owner_account_info.borrow_data_unchecked())? };
-
-        let mut num_signers = 0;
-        let mut matched = [false; MAX_SIGNERS as usize];
-
-        for signer in signers.iter() {
-            for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
-                if key == signer.key() && !matched[position] {
-                    if !signer.is_signer() {
-                        return Err(ProgramError::MissingRequiredSignature);
-                    }
-                    matched[position] = true;
-                    num_signers += 1;
-                }
+        // `owner_account_info` account data.
+        match owner_account_info.data_len() {
+            Multisig::LEN => {
+                return unsafe {
+                    validate_multisig::<{ MAX_SIGNERS as usize }, 0>(owner_account_info, signers, 0)
+                        .map(|_| ())
+                };
             }
+            LargeMultisig::LEN => {
+                return unsafe {
+                    validate_multisig::<{ MAX_LARGE_SIGNERS as usize }, 1>(
+                        owner_account_info,
+                        signers,
+                        0,
+                    )
+                    .map(|_| ())
+                };
+            }
+            _ => (),
         }
-        if num_signers < multisig.m {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-    } else if !owner_account_info.is_signer() {
+    }
+
+    if !owner_account_info.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
     Ok(())
 }
 
-/// Try to convert a UI representation of a token amount to its raw amount using
-/// the given decimals field
-#[allow(clippy::arithmetic_side_effects)]
-fn try_ui_amount_into_amount(ui_amount: &str, decimals: u8) -> Result<u64, ProgramError> {
-    let decimals = decimals as usize;
-    let mut parts = ui_amount.split('.');
-
-    // Splitting a string, even an empty one, will always yield an iterator of at
-    // least length == 1.
-    let amount_str = parts.next().unwrap();
-    let after_decimal = parts.next().unwrap_or("");
-    // Clean up trailing zeros.
-    let after_decimal = after_decimal.trim_end_matches('0');
+/// Validates that the delegate of the token account with key `account_key` is
+/// present.
+///
+/// When the delegate is a [`DelegationRecord`], the record must be for the
+/// token account and not expired; the delegate of the record is then passed as
+/// the first account of `signers`, followed by its own signers. An expired
+/// delegation fails as if the record was not the delegate.
+///
+/// # Safety
+///
+/// The `delegate_info` and multisig accounts will be immutable borrowed when
+/// they represent a delegation record or multisig account, therefore they
+/// should not have any mutable borrows when calling this function.
+#[inline(always)]
+unsafe fn validate_delegate(
+    account_key: &Pubkey,
+    delegate_info: &AccountInfo,
+    signers: &[AccountInfo],
+) -> ProgramResult {
+    if delegate_info.is_owned_by(&TOKEN_PROGRAM_ID)
+        && delegate_info.data_len() == DelegationRecord::LEN
+    {
+        // SAFETY: the caller guarantees that there are no mutable borrows of
+        // `delegate_info` account data and `load_strict` validates that the
+        // record is initialized.
+        let record =
+            unsafe { load_strict::<DelegationRecord>(delegate_info.borrow_data_unchecked())? };
 
-    // Validates the input.
+        if &record.account != account_key || record.is_expired(&Clock::get()?)? {
+            return Err(TokenError::OwnerMismatch.into());
+        }
 
-    let length = amount_str.len();
+        let [record_delegate_info, signers @ ..] = signers else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
 
-    if (amount_str.is_empty() && after_decimal.is_empty())
-        || parts.next().is_some()
-        || after_decimal.len() > decimals
-        || (length + decimals) > MAX_FORMATTED_DIGITS
-    {
-        return Err(ProgramError::InvalidArgument);
+        // SAFETY: the caller guarantees that there are no mutable borrows of
+        // multisig accounts.
+        return unsafe { validate_owner(&record.delegate, record_delegate_info, signers) };
     }
 
-    let mut digits = [b'0'; MAX_FORMATTED_DIGITS];
+    // SAFETY: the caller guarantees that there are no mutable borrows of
+    // `delegate_info` account data.
+    unsafe { validate_owner(delegate_info.key(), delegate_info, signers) }
+}
+
+/// Validates that the required number of signers of a multisignature with up
+/// to `N` signers and `F` flag bytes are present, returning the number of
+/// accounts of `signers` used.
+///
+/// A multisig at `depth` 0 uses all the `signers`, while a nested multisig
+/// uses exactly `m` of them, plus the accounts of its own nested multisigs. A
+/// member that did not sign is only validated as a nested multisig when the
+/// multisig allows nested members.
+///
+/// # Safety
+///
+/// The `multisig_info` and nested multisig accounts will be immutable
+/// borrowed, therefore they should not have any mutable borrows when calling
+/// this function.
+#[allow(clippy::arithmetic_side_effects)]
+unsafe fn validate_multisig<const N: usize, const F: usize>(
+    multisig_info: &AccountInfo,
+    signers: &[AccountInfo],
+    depth: u8,
+) -> Result<usize, ProgramError> {
+    // SAFETY: the caller guarantees that there are no mutable borrows of
+    // `multisig_info` account data and the `load_strict` validates that the
+    // account is initialized; additionally, multisig accounts are only
+    // ever loaded in this function, which means that previous loads will
+    // have already failed by the time we get here.
+    let multisig =
+        unsafe { load_strict::<GenericMultisig<N, F>>(multisig_info.borrow_data_unchecked())? };
+
+    let entries = if depth == 0 {
+        signers.len()
+    } else {
+        multisig.m as usize
+    };
+
+    let mut num_signers = 0;
+    let mut matched = [false; N];
+    let mut index = 0;
 
-    // SAFETY: the total length of `amount_str` and `after_decimal` is less than
-    // `MAX_FORMATTED_DIGITS`.
-    unsafe {
-        sol_memcpy_(digits.as_mut_ptr(), amount_str.as_ptr(), length as u64);
+    for _ in 0..entries {
+        let Some(signer) = signers.get(index) else {
+            break;
+        };
+        index += 1;
 
-        sol_memcpy_(
-            digits.as_mut_ptr().add(length),
-            after_decimal.as_ptr(),
-            after_decimal.len() as u64,
-        );
+        let mut authorized = signer.is_signer();
+
+        for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
+            if key == signer.key() && !matched[position] {
+                if !authorized {
+                    if !multisig.allows_nested() {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    // SAFETY: the caller guarantees that there are no mutable
+                    // borrows of nested multisig accounts.
+                    index += unsafe { validate_nested_multisig(signer, &signers[index..], depth)? };
+                    authorized = true;
+                }
+                matched[position] = true;
+                num_signers += 1;
+            }
+        }
+    }
+    if num_signers < multisig.m {
+        return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let length = amount_str.len() + decimals;
+    Ok(index)
+}
+
+/// Validates a member of a multisig at `depth` that did not sign, which must
+/// be a multisig followed by its own signers, returning the number of
+/// accounts of `signers` used.
+///
+/// # Safety
+///
+/// The `multisig_info` and nested multisig accounts will be immutable
+/// borrowed, therefore they should not have any mutable borrows when calling
+/// this function.
+#[allow(clippy::arithmetic_side_effects)]
+unsafe fn validate_nested_multisig(
+    multisig_info: &AccountInfo,
+    signers: &[AccountInfo],
+    depth: u8,
+) -> Result<usize, ProgramError> {
+    if depth >= MAX_MULTISIG_DEPTH || !multisig_info.is_owned_by(&TOKEN_PROGRAM_ID) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
-    // SAFETY: `digits` only contains valid UTF-8 bytes.
-    unsafe {
-        from_utf8_unchecked(from_raw_parts(digits.as_ptr(), length))
-            .parse::<u64>()
-            .map_err(|_| ProgramError::InvalidArgument)
+    // SAFETY: the caller guarantees that there are no mutable borrows of
+    // `multisig_info` account data.
+    match multisig_info.data_len() {
+        Multisig::LEN => unsafe {
+            validate_multisig::<{ MAX_SIGNERS as usize }, 0>(multisig_info, signers, depth + 1)
+        },
+        LargeMultisig::LEN => unsafe {
+            validate_multisig::<{ MAX_LARGE_SIGNERS as usize }, 1>(
+                multisig_info,
+                signers,
+                depth + 1,
+            )
+        },
+        _ => Err(ProgramError::MissingRequiredSignature),
     }
 }
 
@@ -0,0 +1,89 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            load_mut,
+            multisig::{GenericMultisig, LargeMultisig, Multisig, MAX_LARGE_SIGNERS, MAX_SIGNERS},
+            Transmutable,
+        },
+    },
+};
+
+pub fn process_set_multisig_signers(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [m, n, ..] = instruction_data else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    let [multisig_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (new_signers, signers) = remaining
+        .split_at_checked(*n as usize)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    check_account_owner(multisig_info)?;
+
+    match multisig_info.data_len() {
+        Multisig::LEN => {
+            set_signers::<{ MAX_SIGNERS as usize }, 0>(multisig_info, new_signers, signers, *m)
+        }
+        LargeMultisig::LEN => set_signers::<{ MAX_LARGE_SIGNERS as usize }, 1>(
+            multisig_info,
+            new_signers,
+            signers,
+            *m,
+        ),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Replaces the signers of a multisignature account with up to `N` signers and
+/// `F` flag bytes, authorized by `m` of its current signers.
+///
+/// The flags, i.e., whether nested members are allowed, are kept.
+#[inline(always)]
+fn set_signers<const N: usize, const F: usize>(
+    multisig_info: &AccountInfo,
+    new_signers: &[AccountInfo],
+    signers: &[AccountInfo],
+    m: u8,
+) -> ProgramResult {
+    // SAFETY: `multisig_info` account data is not currently borrowed.
+    unsafe { validate_owner(multisig_info.key(), multisig_info, signers)? };
+
+    // The number of new signers is read from a `u8`.
+    let n = new_signers.len() as u8;
+
+    if !GenericMultisig::<N, F>::is_valid_signer_index(n) {
+        return Err(TokenError::InvalidNumberOfProvidedSigners.into());
+    }
+    // Unlike the initialization, the threshold must be reachable, otherwise the
+    // multisig could never sign again.
+    if !GenericMultisig::<N, F>::is_valid_signer_index(m) || m > n {
+        return Err(TokenError::InvalidNumberOfRequiredSigners.into());
+    }
+
+    // SAFETY: single mutable borrow to `multisig_info` account data; the borrow
+    // of `validate_owner` has ended.
+    let multisig =
+        unsafe { load_mut::<GenericMultisig<N, F>>(multisig_info.borrow_mut_data_unchecked())? };
+
+    multisig.m = m;
+    multisig.n = n;
+
+    let (active, unused) = multisig.signers.split_at_mut(n as usize);
+
+    for (signer, new_signer_info) in active.iter_mut().zip(new_signers) {
+        *signer = *new_signer_info.key();
+    }
+    // Previous signers beyond `n` are cleared.
+    unused.fill([0; 32]);
+
+    Ok(())
+}
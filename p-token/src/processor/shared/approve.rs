@@ -13,41 +13,150 @@ pub fn process_approve(
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
-    // Accounts expected depend on whether we have the mint `decimals` or not; when
-    // we have the mint `decimals`, we expect the mint account to be present.
+    let (source_account_info, expected_mint_info, delegate_info, owner_info, remaining) =
+        approval_accounts(accounts, expected_decimals)?;
+
+    // SAFETY: `source_account_info` is not currently borrowed.
+    let source_account = unsafe {
+        validate_approval(
+            source_account_info,
+            expected_mint_info,
+            owner_info,
+            remaining,
+        )?
+    };
+
+    // Sets the delegate and delegated amount.
 
+    source_account.set_delegate(delegate_info.key());
+    source_account.set_delegated_amount(amount);
+
+    Ok(())
+}
+
+/// Increases or decreases the delegated amount of the source account by
+/// `amount`, failing when the delegate of the source account is not the given
+/// delegate.
+#[inline(always)]
+pub fn process_adjust_allowance(
+    accounts: &[AccountInfo],
+    amount: u64,
+    expected_decimals: Option<u8>,
+    increase: bool,
+) -> ProgramResult {
     let (source_account_info, expected_mint_info, delegate_info, owner_info, remaining) =
-        if let Some(expected_decimals) = expected_decimals {
-            let [source_account_info, expected_mint_info, delegate_info, owner_info, remaining @ ..] =
-                accounts
-            else {
-                return Err(ProgramError::NotEnoughAccountKeys);
-            };
-
-            (
-                source_account_info,
-                Some((expected_mint_info, expected_decimals)),
-                delegate_info,
-                owner_info,
-                remaining,
-            )
-        } else {
-            let [source_account_info, delegate_info, owner_info, remaining @ ..] = accounts else {
-                return Err(ProgramError::NotEnoughAccountKeys);
-            };
-            (
-                source_account_info,
-                None,
-                delegate_info,
-                owner_info,
-                remaining,
-            )
+        approval_accounts(accounts, expected_decimals)?;
+
+    // SAFETY: `source_account_info` is not currently borrowed.
+    let source_account = unsafe {
+        validate_approval(
+            source_account_info,
+            expected_mint_info,
+            owner_info,
+            remaining,
+        )?
+    };
+
+    // The allowance can only be adjusted for the current delegate, so a change
+    // of delegate between the instructions is detected.
+
+    if source_account.delegate() != Some(delegate_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let delegated_amount = if increase {
+        source_account
+            .delegated_amount()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?
+    } else {
+        source_account
+            .delegated_amount()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?
+    };
+
+    source_account.set_delegated_amount(delegated_amount);
+
+    if delegated_amount == 0 {
+        source_account.clear_delegate();
+    }
+
+    Ok(())
+}
+
+/// Accounts of an approval, as
+/// `(source, Option<(mint, decimals)>, delegate, owner, remaining)`.
+type ApprovalAccounts<'a> = (
+    &'a AccountInfo,
+    Option<(&'a AccountInfo, u8)>,
+    &'a AccountInfo,
+    &'a AccountInfo,
+    &'a [AccountInfo],
+);
+
+/// Splits the accounts of an approval.
+///
+/// Accounts expected depend on whether we have the mint `decimals` or not; when
+/// we have the mint `decimals`, we expect the mint account to be present.
+#[inline(always)]
+fn approval_accounts(
+    accounts: &[AccountInfo],
+    expected_decimals: Option<u8>,
+) -> Result<ApprovalAccounts<'_>, ProgramError> {
+    if let Some(expected_decimals) = expected_decimals {
+        let [source_account_info, expected_mint_info, delegate_info, owner_info, remaining @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok((
+            source_account_info,
+            Some((expected_mint_info, expected_decimals)),
+            delegate_info,
+            owner_info,
+            remaining,
+        ))
+    } else {
+        let [source_account_info, delegate_info, owner_info, remaining @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        Ok((
+            source_account_info,
+            None,
+            delegate_info,
+            owner_info,
+            remaining,
+        ))
+    }
+}
+
+/// Validates that the owner of the source account can approve a delegate,
+/// returning the source account.
+///
+/// When `expected_mint_info` is present, the mint and decimals of the source
+/// account are also checked.
+///
+/// # Safety
+///
+/// The `source_account_info` account data is mutably borrowed for the lifetime
+/// of the returned reference, therefore it should not have any borrows when
+/// calling this function.
+#[allow(clippy::mut_from_ref)]
+#[inline(always)]
+pub unsafe fn validate_approval<'a>(
+    source_account_info: &'a AccountInfo,
+    expected_mint_info: Option<(&AccountInfo, u8)>,
+    owner_info: &AccountInfo,
+    remaining: &[AccountInfo],
+) -> Result<&'a mut Account, ProgramError> {
     // Validates source account.
 
-    // SAFETY: single mutable borrow to `source_account_info` account data and
-    // `load_mut` validates that the account is initialized.
+    // SAFETY: the caller guarantees a single mutable borrow to
+    // `source_account_info` account data and `load_mut` validates that the
+    // account is initialized.
     let source_account =
         unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
 
@@ -72,10 +181,5 @@ pub fn process_approve(
     // SAFETY: `owner_info` is not currently borrowed.
     unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
 
-    // Sets the delegate and delegated amount.
-
-    source_account.set_delegate(delegate_info.key());
-    source_account.set_delegated_amount(amount);
-
-    Ok(())
+    Ok(source_account)
 }
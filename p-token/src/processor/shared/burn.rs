@@ -1,5 +1,5 @@
 use {
-    crate::processor::{check_account_owner, validate_owner},
+    crate::processor::{check_account_owner, validate_delegate, validate_owner},
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
     pinocchio_token_interface::{
         error::TokenError,
@@ -55,7 +55,7 @@ pub fn process_burn(
         match source_account.delegate() {
             Some(delegate) if authority_info.key() == delegate => {
                 // SAFETY: `authority_info` is not currently borrowed.
-                unsafe { validate_owner(delegate, authority_info, remaining)? };
+                unsafe { validate_delegate(source_account_info.key(), authority_info, remaining)? };
 
                 let delegated_amount = source_account
                     .delegated_amount()
@@ -7,14 +7,17 @@ use {
     },
     pinocchio_token_interface::{
         error::TokenError,
-        state::{load_mut_unchecked, multisig::Multisig, Initializable},
+        state::{load_mut_unchecked, multisig::GenericMultisig, Initializable},
     },
 };
 
+/// Initializes a multisignature account with up to `N` signers and `F` flag
+/// bytes, where `allow_nested` is ignored when there are no flags.
 #[inline(always)]
-pub fn process_initialize_multisig(
+pub fn process_initialize_multisig<const N: usize, const F: usize>(
     accounts: &[AccountInfo],
     m: u8,
+    allow_nested: bool,
     rent_sysvar_account_provided: bool,
 ) -> ProgramResult {
     // Accounts expected depend on whether we have the `rent_sysvar` account or not.
@@ -43,8 +46,9 @@ pub fn process_initialize_multisig(
     };
 
     // SAFETY: single mutable borrow to `multisig_info` account data.
-    let multisig =
-        unsafe { load_mut_unchecked::<Multisig>(multisig_info.borrow_mut_data_unchecked())? };
+    let multisig = unsafe {
+        load_mut_unchecked::<GenericMultisig<N, F>>(multisig_info.borrow_mut_data_unchecked())?
+    };
 
     if multisig.is_initialized()? {
         return Err(TokenError::AlreadyInUse.into());
@@ -59,10 +63,10 @@ pub fn process_initialize_multisig(
     multisig.m = m;
     multisig.n = remaining.len() as u8;
 
-    if !Multisig::is_valid_signer_index(multisig.n) {
+    if !GenericMultisig::<N, F>::is_valid_signer_index(multisig.n) {
         return Err(TokenError::InvalidNumberOfProvidedSigners.into());
     }
-    if !Multisig::is_valid_signer_index(multisig.m) {
+    if !GenericMultisig::<N, F>::is_valid_signer_index(multisig.m) {
         return Err(TokenError::InvalidNumberOfRequiredSigners.into());
     }
 
@@ -70,6 +74,7 @@ pub fn process_initialize_multisig(
         multisig.signers[i] = *signer_info.key();
     }
 
+    multisig.set_allow_nested(allow_nested);
     multisig.set_initialized(true);
 
     Ok(())
@@ -47,11 +47,8 @@ pub fn process_mint_to(
         }
     }
 
-    match mint.mint_authority() {
-        // SAFETY: `owner_info` is not currently borrowed.
-        Some(mint_authority) => unsafe { validate_owner(mint_authority, owner_info, remaining)? },
-        None => return Err(TokenError::FixedSupply.into()),
-    }
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_mint_authority(mint, owner_info, remaining)? };
 
     if amount == 0 {
         // Validates the accounts' owner since we are not writing
@@ -71,3 +68,24 @@ pub fn process_mint_to(
 
     Ok(())
 }
+
+/// Validates the mint authority of `mint`.
+///
+/// # Safety
+///
+/// The `owner_info` should not have any mutable borrows when calling this
+/// function, since it will be immutable borrowed when it represents a multisig
+/// account.
+#[inline(always)]
+pub unsafe fn validate_mint_authority(
+    mint: &Mint,
+    owner_info: &AccountInfo,
+    signers: &[AccountInfo],
+) -> ProgramResult {
+    match mint.mint_authority() {
+        // SAFETY: the caller guarantees that `owner_info` is not currently
+        // mutably borrowed.
+        Some(mint_authority) => unsafe { validate_owner(mint_authority, owner_info, signers) },
+        None => Err(TokenError::FixedSupply.into()),
+    }
+}
@@ -1,6 +1,8 @@
 use {
-    crate::processor::{check_account_owner, validate_owner},
-    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    crate::processor::{check_account_owner, validate_delegate, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    },
     pinocchio_token_interface::{
         error::TokenError,
         state::{account::Account, load, load_mut, load_mut_unchecked, mint::Mint},
@@ -126,15 +128,18 @@ pub fn process_transfer(
 
     // Validates the authority (delegate or owner).
 
-    if source_account.delegate() == Some(authority_info.key()) {
-        // SAFETY: `authority_info` is not currently borrowed.
-        unsafe { validate_owner(authority_info.key(), authority_info, remaining)? };
-
-        let delegated_amount = source_account
-            .delegated_amount()
-            .checked_sub(amount)
-            .ok_or(TokenError::InsufficientFunds)?;
+    // SAFETY: `authority_info` is not currently borrowed.
+    let delegated_amount = unsafe {
+        validate_authority(
+            source_account_info.key(),
+            source_account,
+            authority_info,
+            remaining,
+            amount,
+        )?
+    };
 
+    if let Some(delegated_amount) = delegated_amount {
         if !self_transfer {
             source_account.set_delegated_amount(delegated_amount);
 
@@ -142,9 +147,6 @@ pub fn process_transfer(
                 source_account.clear_delegate();
             }
         }
-    } else {
-        // SAFETY: `authority_info` is not currently borrowed.
-        unsafe { validate_owner(&source_account.owner, authority_info, remaining)? };
     }
 
     if self_transfer || amount == 0 {
@@ -188,3 +190,43 @@ pub fn process_transfer(
 
     Ok(())
 }
+
+/// Validates the authority (delegate or owner) of a transfer of `amount`
+/// tokens from `source_account`, with key `source_key`.
+///
+/// When the authority is the delegate of the source account, returns the
+/// delegated amount remaining after the transfer; it is up to the caller to
+/// update the source account.
+///
+/// # Safety
+///
+/// The `authority_info` should not have any mutable borrows when calling this
+/// function, since it will be immutable borrowed when it represents a multisig
+/// or delegation record account.
+#[inline(always)]
+pub unsafe fn validate_authority(
+    source_key: &Pubkey,
+    source_account: &Account,
+    authority_info: &AccountInfo,
+    signers: &[AccountInfo],
+    amount: u64,
+) -> Result<Option<u64>, ProgramError> {
+    if source_account.delegate() == Some(authority_info.key()) {
+        // SAFETY: the caller guarantees that `authority_info` is not currently
+        // mutably borrowed.
+        unsafe { validate_delegate(source_key, authority_info, signers)? };
+
+        let delegated_amount = source_account
+            .delegated_amount()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?;
+
+        Ok(Some(delegated_amount))
+    } else {
+        // SAFETY: the caller guarantees that `authority_info` is not currently
+        // mutably borrowed.
+        unsafe { validate_owner(&source_account.owner, authority_info, signers)? };
+
+        Ok(None)
+    }
+}
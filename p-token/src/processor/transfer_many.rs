@@ -0,0 +1,183 @@
+use {
+    super::{check_account_owner, shared::transfer::validate_authority, U64_BYTES},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load, load_mut, mint::Mint},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_transfer_many(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + [u64] (8 * N)
+    let [expected_decimals, amounts @ ..] = instruction_data else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    if amounts.is_empty() || amounts.len() % U64_BYTES != 0 {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let [source_account_info, mint_info, authority_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let destinations_len = amounts.len() / U64_BYTES;
+
+    if remaining.len() < destinations_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let (destination_account_infos, signers) = remaining.split_at(destinations_len);
+
+    // SAFETY: `amounts` length is a multiple of `U64_BYTES`, so each chunk
+    // is guaranteed to have `U64_BYTES` bytes.
+    let amounts = amounts
+        .chunks_exact(U64_BYTES)
+        .map(|amount| unsafe { u64::from_le_bytes(*(amount.as_ptr() as *const [u8; U64_BYTES])) });
+
+    // Validates source account.
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // Calculates the total amount of the transfers and the amount that is moved
+    // out of the source account, i.e., excluding self transfers.
+
+    let mut total_amount = 0u64;
+    let mut moved_amount = 0u64;
+
+    for (destination_account_info, amount) in destination_account_infos.iter().zip(amounts.clone())
+    {
+        total_amount = total_amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        // Comparing whether the AccountInfo's "point" to the same account or
+        // not - this is a faster comparison since it just checks the internal
+        // raw pointer.
+        if source_account_info != destination_account_info {
+            // Note: `moved_amount` is always less than or equal to `total_amount`.
+            moved_amount += amount;
+        }
+    }
+
+    // Implicitly validates that the account has enough tokens by calculating the
+    // remaining amount.
+    source_account
+        .amount()
+        .checked_sub(total_amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    // Validates the mint information.
+
+    if mint_info.key() != &source_account.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    // SAFETY: single immutable borrow of `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if *expected_decimals != mint.decimals {
+        return Err(TokenError::MintDecimalsMismatch.into());
+    }
+
+    // Validates the authority (delegate or owner) once for all transfers.
+
+    // SAFETY: `authority_info` is not currently borrowed.
+    let delegated_amount = unsafe {
+        validate_authority(
+            source_account_info.key(),
+            source_account,
+            authority_info,
+            signers,
+            total_amount,
+        )?
+    };
+
+    if moved_amount == 0 {
+        // Validates the token account owner since we are not writing
+        // to the source account.
+        check_account_owner(source_account_info)?;
+    } else {
+        if let Some(delegated_amount) = delegated_amount {
+            // Note: self transfers do not consume the delegated amount.
+            let delegated_amount = delegated_amount + (total_amount - moved_amount);
+            source_account.set_delegated_amount(delegated_amount);
+
+            if delegated_amount == 0 {
+                source_account.clear_delegate();
+            }
+        }
+
+        // Debits the source account once.
+
+        // Note: `moved_amount` is less than or equal to `total_amount`, which was
+        // validated against the source account amount.
+        source_account.set_amount(source_account.amount() - moved_amount);
+
+        if source_account.is_native() {
+            // SAFETY: single mutable borrow to `source_account_info` lamports.
+            let source_lamports = unsafe { source_account_info.borrow_mut_lamports_unchecked() };
+            *source_lamports = source_lamports
+                .checked_sub(moved_amount)
+                .ok_or(TokenError::Overflow)?;
+        }
+    }
+
+    // Credits the destination accounts.
+
+    for (destination_account_info, amount) in destination_account_infos.iter().zip(amounts) {
+        if source_account_info == destination_account_info {
+            // Self transfers were already validated.
+            continue;
+        }
+
+        // SAFETY: scoped mutable borrow to `destination_account_info` account data
+        // and `load_mut` validates that the account is initialized; additionally,
+        // the account is guaranteed to be different than `source_account_info`.
+        let destination_account =
+            unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+
+        if destination_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        // Note: `mint_info` key was already validated to match the source
+        // account mint.
+        if mint_info.key() != &destination_account.mint {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        if amount == 0 {
+            // Validates the token account owner since we are not writing
+            // to the account.
+            check_account_owner(destination_account_info)?;
+            continue;
+        }
+
+        // Note: The amount of a token account is always within the range of the
+        // mint supply (`u64`).
+        destination_account.set_amount(destination_account.amount() + amount);
+
+        if source_account.is_native() {
+            // SAFETY: single mutable borrow to `destination_account_info` lamports; the
+            // account is already validated to be different from
+            // `source_account_info`.
+            let destination_lamports =
+                unsafe { destination_account_info.borrow_mut_lamports_unchecked() };
+            *destination_lamports = destination_lamports
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use {
-    super::{check_account_owner, try_ui_amount_into_amount},
+    super::check_account_owner,
     core::str::from_utf8,
     pinocchio::{
         account_info::AccountInfo, program::set_return_data, program_error::ProgramError,
@@ -8,6 +8,7 @@ use {
     pinocchio_token_interface::{
         error::TokenError,
         state::{load, mint::Mint},
+        ui_amount::UiAmount,
     },
 };
 
@@ -25,7 +26,10 @@ pub fn process_ui_amount_to_amount(
         load::<Mint>(mint_info.borrow_data_unchecked()).map_err(|_| TokenError::InvalidMint)?
     };
 
-    let amount = try_ui_amount_into_amount(ui_amount, mint.decimals)?;
+    let amount = ui_amount
+        .parse::<UiAmount>()?
+        .to_amount(mint.decimals)
+        .ok_or(ProgramError::InvalidArgument)?;
     set_return_data(&amount.to_le_bytes());
 
     Ok(())
@@ -8,7 +8,13 @@ use {
     },
     pinocchio_token_interface::{
         error::TokenError,
-        state::{account::Account, load, mint::Mint, multisig::Multisig, Transmutable},
+        state::{
+            account::Account,
+            load,
+            mint::Mint,
+            multisig::{LargeMultisig, Multisig},
+            Transmutable,
+        },
     },
 };
 
@@ -59,7 +65,7 @@ pub fn process_withdraw_excess_lamports(accounts: &[AccountInfo]) -> ProgramResu
                 }
             }
         }
-        Multisig::LEN => {
+        Multisig::LEN | LargeMultisig::LEN => {
             // SAFETY: `authority_info` is not currently mutably borrowed.
             unsafe { validate_owner(source_account_info.key(), authority_info, remaining)? };
         }
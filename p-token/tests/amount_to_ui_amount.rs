@@ -1,11 +1,15 @@
 mod setup;
 
 use {
+    pinocchio_token_interface::state::mint::Mint,
     setup::{mint, TOKEN_PROGRAM_ID},
+    solana_keypair::Keypair,
     solana_program_test::{tokio, ProgramTest},
     solana_pubkey::Pubkey,
     solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
     solana_transaction::Transaction,
+    std::mem::size_of,
 };
 
 #[tokio::test]
@@ -45,3 +49,73 @@ async fn amount_to_ui_amount() {
 
     assert!(account.is_some());
 }
+
+#[tokio::test]
+async fn amount_to_ui_amount_with_more_decimals_than_digits() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with more decimals than the digits of a `u64`.
+
+    let mint = Keypair::new();
+
+    let account_size = size_of::<Mint>();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_mint(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &Pubkey::new_unique(),
+        None,
+        25,
+    )
+    .unwrap();
+    initialize_ix.program_id = TOKEN_PROGRAM_ID;
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we convert an amount with trailing zeros.
+
+    let mut amount_to_ui_amount_ix =
+        spl_token::instruction::amount_to_ui_amount(&spl_token::ID, &mint.pubkey(), 1200).unwrap();
+    amount_to_ui_amount_ix.program_id = TOKEN_PROGRAM_ID;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[amount_to_ui_amount_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the fractional part is padded with leading zeros and the trailing
+    // zeros are trimmed.
+
+    assert!(matches!(simulation.result, Some(Ok(()))));
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, TOKEN_PROGRAM_ID);
+    assert_eq!(return_data.data, b"0.00000000000000000000012");
+}
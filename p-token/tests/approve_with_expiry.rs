@@ -0,0 +1,724 @@
+mod setup;
+
+use {
+    assert_matches::assert_matches,
+    pinocchio_token_interface::instruction::TokenInstruction,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_clock::Clock,
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_pack_v3::Pack as _,
+    solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    spl_token_interface::state::{DelegationRecord, Expiry},
+};
+
+/// Creates an `ApproveWithExpiry` instruction.
+#[allow(clippy::too_many_arguments)]
+fn approve_with_expiry(
+    source: &Pubkey,
+    mint: &Pubkey,
+    delegation_record: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    expiry: Expiry,
+) -> Instruction {
+    let (expiry_kind, expiry) = expiry.pack();
+
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*delegation_record, false),
+            AccountMeta::new_readonly(*delegate, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data: [
+            &[TokenInstruction::ApproveWithExpiry as u8][..],
+            &amount.to_le_bytes(),
+            &[decimals, expiry_kind],
+            &expiry,
+        ]
+        .concat(),
+    }
+}
+
+/// Makes `instruction` use the delegation of a delegation record, signed by its
+/// delegate.
+fn with_record(
+    mut instruction: Instruction,
+    delegation_record: &Pubkey,
+    delegate: &Pubkey,
+) -> Instruction {
+    instruction
+        .accounts
+        .iter_mut()
+        .filter(|meta| meta.pubkey == *delegation_record)
+        .for_each(|meta| meta.is_signer = false);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*delegate, true));
+    instruction
+}
+
+/// Creates a `Transfer` instruction using the delegation of a delegation
+/// record, signed by its delegate.
+fn transfer_with_record(
+    source: &Pubkey,
+    destination: &Pubkey,
+    delegation_record: &Pubkey,
+    delegate: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let instruction = spl_token::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        source,
+        destination,
+        delegation_record,
+        &[],
+        amount,
+    )
+    .unwrap();
+    with_record(instruction, delegation_record, delegate)
+}
+
+/// Creates a mint and a token account with 100 tokens, returning the keys of
+/// the mint and the account.
+async fn setup_account(context: &mut ProgramTestContext, owner: &Pubkey) -> (Pubkey, Pubkey) {
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(context, &mint, owner, &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    (mint, account)
+}
+
+#[tokio::test]
+async fn approve_with_expiry_until_slot() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    // When we approve a delegate until slot 1000, with a new delegation record.
+
+    let delegation_record = Keypair::new();
+    let delegate = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &delegation_record.pubkey(),
+            rent.minimum_balance(DelegationRecord::LEN),
+            DelegationRecord::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        approve_with_expiry(
+            &account,
+            &mint,
+            &delegation_record.pubkey(),
+            &delegate.pubkey(),
+            &owner.pubkey(),
+            50,
+            4,
+            Expiry::Slot(1_000),
+        ),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegation_record, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegation record is the delegate of the account.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.delegate.is_some());
+    assert!(token_account.delegate.unwrap() == delegation_record.pubkey());
+    assert!(token_account.delegated_amount == 50);
+
+    let record = context
+        .banks_client
+        .get_account(delegation_record.pubkey())
+        .await
+        .unwrap();
+
+    assert!(record.is_some());
+
+    let record = DelegationRecord::unpack(&record.unwrap().data).unwrap();
+
+    assert_eq!(record.account.to_bytes(), account.to_bytes());
+    assert_eq!(record.delegate.to_bytes(), delegate.pubkey().to_bytes());
+    assert_eq!(record.expiry, Expiry::Slot(1_000));
+
+    // And the delegate can transfer tokens before the expiry.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_with_record(
+            &account,
+            &destination,
+            &delegation_record.pubkey(),
+            &delegate.pubkey(),
+            10,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.amount == 90);
+    assert!(token_account.delegated_amount == 40);
+
+    // But not after the expiry.
+
+    context.warp_to_slot(1_000).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_with_record(
+            &account,
+            &destination,
+            &delegation_record.pubkey(),
+            &delegate.pubkey(),
+            10,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        last_blockhash,
+    );
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
+
+#[tokio::test]
+async fn approve_with_expiry_until_unix_timestamp() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    // When we approve a delegate for the next hour.
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let expiry = clock.unix_timestamp + 3_600;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::UnixTimestamp(expiry),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // Then the delegate can transfer tokens before the expiry.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_with_record(
+            &account,
+            &destination,
+            &delegation_record,
+            &delegate.pubkey(),
+            10,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.amount == 90);
+    assert!(token_account.delegated_amount == 40);
+
+    // But not once the clock reaches the expiry.
+
+    context.set_sysvar(&Clock {
+        unix_timestamp: expiry,
+        ..clock
+    });
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_with_record(
+            &account,
+            &destination,
+            &delegation_record,
+            &delegate.pubkey(),
+            10,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(4) // TokenError::OwnerMismatch
+        ))
+    );
+}
+
+#[tokio::test]
+async fn burn_with_delegation_record() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens, delegated through a delegation
+    // record.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the delegate burns tokens.
+
+    let burn_ix = spl_token::instruction::burn(
+        &TOKEN_PROGRAM_ID,
+        &account,
+        &mint,
+        &delegation_record,
+        &[],
+        20,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[with_record(burn_ix, &delegation_record, &delegate.pubkey())],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are burned from the delegated amount.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.amount == 80);
+    assert!(token_account.delegated_amount == 30);
+
+    let mint = context.banks_client.get_account(mint).await.unwrap();
+    let mint = spl_token::state::Mint::unpack(&mint.unwrap().data).unwrap();
+
+    assert!(mint.supply == 80);
+}
+
+#[tokio::test]
+async fn transfer_many_with_delegation_record() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens, delegated through a delegation
+    // record.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // And 2 destination token accounts.
+
+    let mut destinations = Vec::with_capacity(2);
+
+    for amount in [10u64, 20] {
+        let destination = account::initialize(
+            &mut context,
+            &mint,
+            &Pubkey::new_unique(),
+            &TOKEN_PROGRAM_ID,
+        )
+        .await;
+        destinations.push((destination, amount));
+    }
+
+    // When the delegate transfers the tokens to all destinations.
+
+    let mut transfer_many_ix = Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(account, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(delegation_record, false),
+        ],
+        data: vec![TokenInstruction::TransferMany as u8, 4],
+    };
+
+    for (destination, amount) in &destinations {
+        transfer_many_ix
+            .accounts
+            .push(AccountMeta::new(*destination, false));
+        transfer_many_ix
+            .data
+            .extend_from_slice(&amount.to_le_bytes());
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[with_record(
+            transfer_many_ix,
+            &delegation_record,
+            &delegate.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source account is debited from the delegated amount.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.amount == 70);
+    assert!(token_account.delegated_amount == 20);
+
+    // And each destination account is credited with its amount.
+
+    for (destination, amount) in destinations {
+        let destination = context.banks_client.get_account(destination).await.unwrap();
+        let destination = spl_token::state::Account::unpack(&destination.unwrap().data).unwrap();
+
+        assert!(destination.amount == amount);
+    }
+}
+
+#[tokio::test]
+async fn fail_transfer_with_delegation_record_of_another_account() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens, delegated through a delegation
+    // record.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // And another token account of the same owner that approves the record
+    // key as a plain delegate.
+
+    let other_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    account::approve(
+        &mut context,
+        &other_account,
+        &delegation_record,
+        &owner,
+        50,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the delegate of the record tries to transfer from the other
+    // account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_with_record(
+            &other_account,
+            &account,
+            &delegation_record,
+            &delegate.pubkey(),
+            0,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the record is rejected, since it is for a different account.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(4) // TokenError::OwnerMismatch
+        ))
+    );
+}
+
+#[tokio::test]
+async fn fail_approve_with_expiry_reusing_record_of_another_account() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens, delegated through a delegation
+    // record.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // And another token account.
+
+    let other_owner = Keypair::new();
+
+    let other_account = account::initialize(
+        &mut context,
+        &mint,
+        &other_owner.pubkey(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the owner of the other account approves with the same record.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_with_expiry(
+            &other_account,
+            &mint,
+            &delegation_record,
+            &delegate.pubkey(),
+            &other_owner.pubkey(),
+            50,
+            4,
+            Expiry::Slot(1_000),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other_owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the record is rejected, since it is in use by the first account.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(6) // TokenError::AlreadyInUse
+        ))
+    );
+}
+
+#[tokio::test]
+async fn fail_approve_with_expiry_with_record_not_rent_exempt() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let owner = Keypair::new();
+    let (mint, account) = setup_account(&mut context, &owner.pubkey()).await;
+
+    // When we approve a delegate with a new delegation record that is not
+    // rent exempt.
+
+    let delegation_record = Keypair::new();
+    let delegate = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &delegation_record.pubkey(),
+            rent.minimum_balance(DelegationRecord::LEN)
+                .saturating_sub(1),
+            DelegationRecord::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        approve_with_expiry(
+            &account,
+            &mint,
+            &delegation_record.pubkey(),
+            &delegate.pubkey(),
+            &owner.pubkey(),
+            50,
+            4,
+            Expiry::Slot(1_000),
+        ),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegation_record, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the record is rejected.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(0) // TokenError::NotRentExempt
+        ))
+    );
+}
@@ -1,7 +1,7 @@
 mod setup;
 
 use {
-    crate::setup::TOKEN_PROGRAM_ID,
+    crate::setup::{account, mint, TOKEN_PROGRAM_ID},
     solana_instruction::{AccountMeta, Instruction},
     solana_keypair::Keypair,
     solana_program_error::ProgramError,
@@ -219,3 +219,86 @@ async fn batch() {
         spl_token::state::Account::unpack(&owner_b_ta_a_account.unwrap().data).unwrap();
     assert_eq!(owner_b_ta_a_account.amount, 1000000);
 }
+
+#[tokio::test]
+async fn batch_failure() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let destination = account::initialize(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the second transfer of a batch exceeds the remaining amount.
+
+    let transfers = [60, 60]
+        .into_iter()
+        .map(|amount| {
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &account,
+                &destination,
+                &owner.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_instruction(transfers).unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the batch fails and the return data identifies the failed instruction.
+
+    assert!(matches!(simulation.result, Some(Err(_))));
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, TOKEN_PROGRAM_ID);
+    // batch discriminator, index (`u16`) and transfer discriminator.
+    assert_eq!(return_data.data, vec![255, 1, 0, 3]);
+}
@@ -0,0 +1,247 @@
+mod setup;
+
+use {
+    assert_matches::assert_matches,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, BanksClientError, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+};
+
+fn best_effort_batch_instruction(
+    instructions: Vec<Instruction>,
+) -> Result<Instruction, ProgramError> {
+    // Create a `Vec` of ordered `AccountMeta`s
+    let mut accounts: Vec<AccountMeta> = vec![];
+    // Start with the best-effort batch discriminator
+    let mut data: Vec<u8> = vec![0xfd];
+
+    for instruction in instructions {
+        // Error out on non-token IX.
+        if instruction.program_id.ne(&spl_token::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        data.push(instruction.accounts.len() as u8);
+        data.push(instruction.data.len() as u8);
+
+        data.extend_from_slice(&instruction.data);
+        accounts.extend_from_slice(&instruction.accounts);
+    }
+
+    Ok(Instruction {
+        program_id: spl_token::ID,
+        data,
+        accounts,
+    })
+}
+
+#[tokio::test]
+async fn best_effort_batch() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let destination = account::initialize(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the second transfer of a best-effort batch exceeds the remaining
+    // amount.
+
+    let transfers: Vec<Instruction> = [60, 60, 30]
+        .into_iter()
+        .map(|amount| {
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &account,
+                &destination,
+                &owner.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[best_effort_batch_instruction(transfers.clone()).unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the failed transfer is skipped and the return data reports the
+    // result of each instruction.
+
+    assert!(matches!(simulation.result, Some(Ok(()))));
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, TOKEN_PROGRAM_ID);
+    assert_eq!(return_data.data, vec![0b101]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[best_effort_batch_instruction(transfers).unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 10);
+
+    let destination = context.banks_client.get_account(destination).await.unwrap();
+
+    assert!(destination.is_some());
+
+    let destination = destination.unwrap();
+    let destination = spl_token::state::Account::unpack(&destination.data).unwrap();
+
+    assert!(destination.amount == 90);
+}
+
+#[tokio::test]
+async fn fail_best_effort_batch_with_nested_batch() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let destination = account::initialize(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When a best-effort batch has a transfer followed by a nested `Batch` or
+    // `IndexedBatch` instruction.
+
+    for nested_discriminator in [255, 254] {
+        let transfer = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &account,
+            &destination,
+            &owner.pubkey(),
+            &[],
+            60,
+        )
+        .unwrap();
+        let nested = Instruction {
+            program_id: spl_token::ID,
+            accounts: vec![],
+            data: vec![nested_discriminator],
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[best_effort_batch_instruction(vec![transfer, nested]).unwrap()],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &owner],
+            context.last_blockhash,
+        );
+        let error = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        // Then the whole batch fails instead of skipping the nested batch.
+
+        assert_matches!(
+            error,
+            BanksClientError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(12) // TokenError::InvalidInstruction
+            ))
+        );
+    }
+
+    // And the transfer is not applied.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert!(account.amount == 100);
+}
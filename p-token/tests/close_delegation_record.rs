@@ -0,0 +1,295 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::instruction::TokenInstruction,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_pack_v3::Pack as _,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    spl_token_interface::state::{DelegationRecord, Expiry},
+};
+
+/// Creates a `CloseDelegationRecord` instruction.
+fn close_delegation_record(
+    delegation_record: &Pubkey,
+    account: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*delegation_record, false),
+            AccountMeta::new(*account, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data: vec![TokenInstruction::CloseDelegationRecord as u8],
+    }
+}
+
+#[tokio::test]
+async fn close_delegation_record_revokes_delegation() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with a delegation record as its delegate.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    let record_lamports = context
+        .banks_client
+        .get_account(delegation_record)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When the owner closes the delegation record.
+
+    let destination = Pubkey::new_unique();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_delegation_record(
+            &delegation_record,
+            &account,
+            &destination,
+            &owner.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegation record is closed.
+
+    let record = context
+        .banks_client
+        .get_account(delegation_record)
+        .await
+        .unwrap();
+
+    assert!(record.is_none());
+
+    // And its lamports are transferred to the destination.
+
+    let destination = context.banks_client.get_account(destination).await.unwrap();
+
+    assert!(destination.is_some());
+    assert_eq!(destination.unwrap().lamports, record_lamports);
+
+    // And the delegation is revoked.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.delegate.is_none());
+    assert!(token_account.delegated_amount == 0);
+}
+
+#[tokio::test]
+async fn close_stale_delegation_record() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account delegated through a delegation record.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // And the delegation replaced by another delegate, which leaves the
+    // record initialized.
+
+    let new_delegate = Pubkey::new_unique();
+
+    account::approve(
+        &mut context,
+        &account,
+        &new_delegate,
+        &owner,
+        20,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    let record = context
+        .banks_client
+        .get_account(delegation_record)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(DelegationRecord::unpack(&record.data).is_ok());
+
+    // When the owner closes the stale delegation record.
+
+    let destination = Pubkey::new_unique();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_delegation_record(
+            &delegation_record,
+            &account,
+            &destination,
+            &owner.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegation record is closed and its lamports are transferred
+    // to the destination.
+
+    let closed = context
+        .banks_client
+        .get_account(delegation_record)
+        .await
+        .unwrap();
+
+    assert!(closed.is_none());
+
+    let destination = context.banks_client.get_account(destination).await.unwrap();
+
+    assert_eq!(destination.unwrap().lamports, record.lamports);
+
+    // And the new delegation is kept.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert!(token_account.delegate.unwrap() == new_delegate);
+    assert!(token_account.delegated_amount == 20);
+}
+
+#[tokio::test]
+async fn fail_close_delegation_record_with_delegate() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with a delegation record as its delegate.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    let delegate = Keypair::new();
+
+    let delegation_record = account::approve_with_expiry(
+        &mut context,
+        &account,
+        &mint,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        4,
+        Expiry::Slot(1_000),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the delegate tries to close the delegation record.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_delegation_record(
+            &delegation_record,
+            &account,
+            &delegate.pubkey(),
+            &delegate.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    // Then the transaction fails, since only the owner can close the record.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
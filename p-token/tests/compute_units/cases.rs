@@ -0,0 +1,1571 @@
+//! Instructions measured by the compute unit suite.
+
+use {
+    mollusk_svm::Mollusk,
+    pinocchio_token_interface::{
+        instruction::TokenInstruction,
+        state::{
+            multisig::{LargeMultisig, MAX_LARGE_SIGNERS},
+            Transmutable,
+        },
+    },
+    solana_account::Account as SolanaAccount,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_program_pack_v3::Pack as _,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token::{
+        instruction::{self, AuthorityType},
+        state::{Account, AccountState, Mint, Multisig},
+    },
+    spl_token_interface::state::{DelegationRecord, Expiry},
+};
+
+const DECIMALS: u8 = 6;
+
+/// Balance of the token accounts and supply of the mints.
+const BALANCE: u64 = 1_000_000;
+
+const AMOUNT: u64 = 1_000;
+
+/// Lamports above the rent-exempt reserve of an account.
+const EXCESS_LAMPORTS: u64 = 1_000_000;
+
+/// Number of signers required by the multisignature authorities.
+const M: u8 = 2;
+
+/// Number of signers of the multisignature authorities.
+const N: usize = 3;
+
+/// An instruction measured by the suite.
+pub struct Case {
+    /// Name of the case, used as its key in the baseline.
+    pub name: String,
+    /// Instruction executed on `p-token`.
+    pub instruction: Instruction,
+    /// Equivalent instructions executed on `spl-token`, if it supports them.
+    pub spl_token: Option<Vec<Instruction>>,
+    /// Accounts of the instructions.
+    pub accounts: Vec<(Pubkey, SolanaAccount)>,
+}
+
+impl Case {
+    /// Creates a case executing the same instruction on both programs.
+    fn new(name: &str, instruction: Instruction, accounts: Vec<(Pubkey, SolanaAccount)>) -> Self {
+        Self {
+            name: name.to_string(),
+            spl_token: Some(vec![instruction.clone()]),
+            instruction,
+            accounts,
+        }
+    }
+
+    /// Creates a case comparing `instruction` with the chain of `equivalent`
+    /// instructions on `spl-token`.
+    fn equivalent(
+        name: &str,
+        instruction: Instruction,
+        equivalent: Vec<Instruction>,
+        accounts: Vec<(Pubkey, SolanaAccount)>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            instruction,
+            spl_token: Some(equivalent),
+            accounts,
+        }
+    }
+
+    /// Creates a case for an instruction not supported by `spl-token`.
+    fn p_token_only(
+        name: &str,
+        instruction: Instruction,
+        accounts: Vec<(Pubkey, SolanaAccount)>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            instruction,
+            spl_token: None,
+            accounts,
+        }
+    }
+}
+
+/// Authority of an instruction, either a single signer or a multisignature
+/// account with `M` of its `N` signers.
+struct Authority {
+    key: Pubkey,
+    signers: Vec<Pubkey>,
+    accounts: Vec<(Pubkey, SolanaAccount)>,
+}
+
+impl Authority {
+    fn new(multisig: bool) -> Self {
+        let key = Pubkey::new_unique();
+
+        if !multisig {
+            return Self {
+                key,
+                signers: vec![],
+                accounts: vec![(key, SolanaAccount::default())],
+            };
+        }
+
+        let signers = (0..N).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let mut multisig = Multisig {
+            m: M,
+            n: N as u8,
+            is_initialized: true,
+            ..Default::default()
+        };
+        multisig.signers[..N].copy_from_slice(&signers);
+
+        let signers = signers[..M as usize].to_vec();
+        let accounts = [(key, packed(multisig))]
+            .into_iter()
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| (*signer, SolanaAccount::default())),
+            )
+            .collect();
+
+        Self {
+            key,
+            signers,
+            accounts,
+        }
+    }
+
+    fn signers(&self) -> Vec<&Pubkey> {
+        self.signers.iter().collect()
+    }
+
+    /// Returns the name of a case, with a `_multisig` suffix for
+    /// multisignature authorities.
+    fn case_name(&self, name: &str) -> String {
+        if self.signers.is_empty() {
+            name.to_string()
+        } else {
+            format!("{name}_multisig")
+        }
+    }
+}
+
+/// Returns an account of the token program with the packed `state`.
+fn packed<T: Pack>(state: T) -> SolanaAccount {
+    let mut data = vec![0; T::LEN];
+    T::pack(state, &mut data).unwrap();
+
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(T::LEN),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Like [`packed`], for the delegation record of the token interface, which
+/// implements `Pack` of a newer version of the crate.
+fn packed_record(record: DelegationRecord) -> SolanaAccount {
+    let mut data = vec![0; DelegationRecord::LEN];
+    DelegationRecord::pack(record, &mut data).unwrap();
+
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(DelegationRecord::LEN),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Returns an uninitialized account of the token program with `space` bytes.
+fn uninitialized(space: usize) -> SolanaAccount {
+    SolanaAccount::new(
+        Rent::default().minimum_balance(space),
+        space,
+        &spl_token::ID,
+    )
+}
+
+fn mint(mint_authority: &Pubkey, freeze_authority: Option<&Pubkey>) -> SolanaAccount {
+    packed(Mint {
+        mint_authority: COption::Some(*mint_authority),
+        supply: BALANCE,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: freeze_authority.copied().into(),
+    })
+}
+
+fn token_account(mint: &Pubkey, owner: &Pubkey) -> Account {
+    Account {
+        mint: *mint,
+        owner: *owner,
+        amount: BALANCE,
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+}
+
+/// Returns all the cases of the suite.
+pub fn cases(mollusk: &Mollusk) -> Vec<Case> {
+    let mut cases = vec![
+        initialize_mint(mollusk),
+        initialize_mint2(),
+        initialize_account(mollusk),
+        initialize_account2(mollusk),
+        initialize_account3(),
+        initialize_multisig(mollusk),
+        initialize_multisig2(),
+        initialize_multisig3(),
+        set_multisig_signers(),
+        initialize_immutable_owner(),
+        sync_native(),
+        get_account_data_size(),
+        amount_to_ui_amount(),
+        ui_amount_to_amount(),
+        get_balance(),
+        get_mint_info(),
+    ];
+
+    let authority_cases: [fn(bool) -> Case; 27] = [
+        transfer,
+        transfer_checked,
+        approve,
+        approve_checked,
+        approve_with_expiry,
+        transfer_with_expiry,
+        close_delegation_record,
+        increase_allowance,
+        decrease_allowance,
+        increase_allowance_checked,
+        decrease_allowance_checked,
+        revoke,
+        set_authority,
+        mint_to,
+        mint_to_checked,
+        burn,
+        burn_checked,
+        close_account,
+        freeze_account,
+        thaw_account,
+        withdraw_excess_lamports,
+        transfer_many,
+        mint_to_many,
+        batch_transfer_checked,
+        best_effort_batch_transfer_checked,
+        best_effort_batch_transfer_checked_skipped,
+        indexed_batch_transfer_checked,
+    ];
+
+    for case in authority_cases {
+        cases.push(case(false));
+        cases.push(case(true));
+    }
+
+    cases.push(batch_initialize_account3_mint_to_checked());
+    cases.push(batch_transfer_checked_close_account());
+
+    cases
+}
+
+fn initialize_mint(mollusk: &Mollusk) -> Case {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_mint",
+        instruction::initialize_mint(&spl_token::ID, &mint, &mint_authority, None, DECIMALS)
+            .unwrap(),
+        vec![
+            (mint, uninitialized(Mint::LEN)),
+            mollusk.sysvars.keyed_account_for_rent_sysvar(),
+        ],
+    )
+}
+
+fn initialize_mint2() -> Case {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_mint2",
+        instruction::initialize_mint2(&spl_token::ID, &mint, &mint_authority, None, DECIMALS)
+            .unwrap(),
+        vec![(mint, uninitialized(Mint::LEN))],
+    )
+}
+
+fn initialize_account(mollusk: &Mollusk) -> Case {
+    let account = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_account",
+        instruction::initialize_account(&spl_token::ID, &account, &mint, &owner).unwrap(),
+        vec![
+            (account, uninitialized(Account::LEN)),
+            (mint, self::mint(&owner, None)),
+            (owner, SolanaAccount::default()),
+            mollusk.sysvars.keyed_account_for_rent_sysvar(),
+        ],
+    )
+}
+
+fn initialize_account2(mollusk: &Mollusk) -> Case {
+    let account = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_account2",
+        instruction::initialize_account2(&spl_token::ID, &account, &mint, &owner).unwrap(),
+        vec![
+            (account, uninitialized(Account::LEN)),
+            (mint, self::mint(&owner, None)),
+            mollusk.sysvars.keyed_account_for_rent_sysvar(),
+        ],
+    )
+}
+
+fn initialize_account3() -> Case {
+    let account = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_account3",
+        instruction::initialize_account3(&spl_token::ID, &account, &mint, &owner).unwrap(),
+        vec![
+            (account, uninitialized(Account::LEN)),
+            (mint, self::mint(&owner, None)),
+        ],
+    )
+}
+
+fn initialize_multisig(mollusk: &Mollusk) -> Case {
+    let multisig = Pubkey::new_unique();
+    let signers = (0..N).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    Case::new(
+        "initialize_multisig",
+        instruction::initialize_multisig(
+            &spl_token::ID,
+            &multisig,
+            &signers.iter().collect::<Vec<_>>(),
+            M,
+        )
+        .unwrap(),
+        [
+            (multisig, uninitialized(Multisig::LEN)),
+            mollusk.sysvars.keyed_account_for_rent_sysvar(),
+        ]
+        .into_iter()
+        .chain(
+            signers
+                .iter()
+                .map(|signer| (*signer, SolanaAccount::default())),
+        )
+        .collect(),
+    )
+}
+
+fn initialize_multisig2() -> Case {
+    let multisig = Pubkey::new_unique();
+    let signers = (0..N).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    Case::new(
+        "initialize_multisig2",
+        instruction::initialize_multisig2(
+            &spl_token::ID,
+            &multisig,
+            &signers.iter().collect::<Vec<_>>(),
+            M,
+        )
+        .unwrap(),
+        [(multisig, uninitialized(Multisig::LEN))]
+            .into_iter()
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| (*signer, SolanaAccount::default())),
+            )
+            .collect(),
+    )
+}
+
+fn initialize_multisig3() -> Case {
+    let multisig = Pubkey::new_unique();
+    let signers = (0..MAX_LARGE_SIGNERS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+
+    Case::p_token_only(
+        "initialize_multisig3",
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: [AccountMeta::new(multisig, false)]
+                .into_iter()
+                .chain(
+                    signers
+                        .iter()
+                        .map(|signer| AccountMeta::new_readonly(*signer, false)),
+                )
+                .collect(),
+            data: vec![TokenInstruction::InitializeMultisig3 as u8, M, 0],
+        },
+        [(multisig, uninitialized(LargeMultisig::LEN))]
+            .into_iter()
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| (*signer, SolanaAccount::default())),
+            )
+            .collect(),
+    )
+}
+
+fn set_multisig_signers() -> Case {
+    let authority = Authority::new(true);
+    let new_signers = (0..N).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    Case::p_token_only(
+        "set_multisig_signers",
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: [AccountMeta::new(authority.key, false)]
+                .into_iter()
+                .chain(
+                    new_signers
+                        .iter()
+                        .map(|signer| AccountMeta::new_readonly(*signer, false)),
+                )
+                .chain(
+                    authority
+                        .signers
+                        .iter()
+                        .map(|signer| AccountMeta::new_readonly(*signer, true)),
+                )
+                .collect(),
+            data: vec![TokenInstruction::SetMultisigSigners as u8, M, N as u8],
+        },
+        authority
+            .accounts
+            .into_iter()
+            .chain(
+                new_signers
+                    .iter()
+                    .map(|signer| (*signer, SolanaAccount::default())),
+            )
+            .collect(),
+    )
+}
+
+fn initialize_immutable_owner() -> Case {
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        "initialize_immutable_owner",
+        instruction::initialize_immutable_owner(&spl_token::ID, &account).unwrap(),
+        vec![(account, uninitialized(Account::LEN))],
+    )
+}
+
+fn sync_native() -> Case {
+    let account = Pubkey::new_unique();
+    let rent_exempt_reserve = Rent::default().minimum_balance(Account::LEN);
+
+    let mut native_account = packed(Account {
+        is_native: COption::Some(rent_exempt_reserve),
+        amount: 0,
+        ..token_account(&spl_token::native_mint::ID, &Pubkey::new_unique())
+    });
+    native_account.lamports += EXCESS_LAMPORTS;
+
+    Case::new(
+        "sync_native",
+        instruction::sync_native(&spl_token::ID, &account).unwrap(),
+        vec![(account, native_account)],
+    )
+}
+
+fn get_account_data_size() -> Case {
+    let mint = Pubkey::new_unique();
+
+    Case::new(
+        "get_account_data_size",
+        instruction::get_account_data_size(&spl_token::ID, &mint).unwrap(),
+        vec![(mint, self::mint(&Pubkey::new_unique(), None))],
+    )
+}
+
+fn amount_to_ui_amount() -> Case {
+    let mint = Pubkey::new_unique();
+
+    Case::new(
+        "amount_to_ui_amount",
+        instruction::amount_to_ui_amount(&spl_token::ID, &mint, AMOUNT).unwrap(),
+        vec![(mint, self::mint(&Pubkey::new_unique(), None))],
+    )
+}
+
+fn ui_amount_to_amount() -> Case {
+    let mint = Pubkey::new_unique();
+
+    Case::new(
+        "ui_amount_to_amount",
+        instruction::ui_amount_to_amount(&spl_token::ID, &mint, "0.001").unwrap(),
+        vec![(mint, self::mint(&Pubkey::new_unique(), None))],
+    )
+}
+
+fn get_balance() -> Case {
+    let account = Pubkey::new_unique();
+    let token_account = Account {
+        delegate: COption::Some(Pubkey::new_unique()),
+        delegated_amount: AMOUNT,
+        ..token_account(&Pubkey::new_unique(), &Pubkey::new_unique())
+    };
+
+    Case::p_token_only(
+        "get_balance",
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: vec![AccountMeta::new_readonly(account, false)],
+            data: vec![250],
+        },
+        vec![(account, packed(token_account))],
+    )
+}
+
+fn get_mint_info() -> Case {
+    let mint = Pubkey::new_unique();
+
+    Case::p_token_only(
+        "get_mint_info",
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: vec![AccountMeta::new_readonly(mint, false)],
+            data: vec![249],
+        },
+        vec![(
+            mint,
+            self::mint(&Pubkey::new_unique(), Some(&Pubkey::new_unique())),
+        )],
+    )
+}
+
+fn transfer(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("transfer"),
+        instruction::transfer(
+            &spl_token::ID,
+            &source,
+            &destination,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+        )
+        .unwrap(),
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (destination, packed(token_account(&mint, &authority.key))),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn transfer_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("transfer_checked"),
+        instruction::transfer_checked(
+            &spl_token::ID,
+            &source,
+            &mint,
+            &destination,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+            DECIMALS,
+        )
+        .unwrap(),
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+            (destination, packed(token_account(&mint, &authority.key))),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn approve(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("approve"),
+        instruction::approve(
+            &spl_token::ID,
+            &source,
+            &delegate,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+        )
+        .unwrap(),
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (delegate, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn approve_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("approve_checked"),
+        instruction::approve_checked(
+            &spl_token::ID,
+            &source,
+            &mint,
+            &delegate,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+            DECIMALS,
+        )
+        .unwrap(),
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+            (delegate, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn approve_with_expiry(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let record = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    let (expiry_kind, expiry) = Expiry::Slot(u64::MAX).pack();
+    let data = [
+        &[TokenInstruction::ApproveWithExpiry as u8][..],
+        &AMOUNT.to_le_bytes(),
+        &[DECIMALS, expiry_kind],
+        &expiry,
+    ]
+    .concat();
+
+    Case::p_token_only(
+        &authority.case_name("approve_with_expiry"),
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: [
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(record, false),
+                AccountMeta::new_readonly(delegate, false),
+                AccountMeta::new_readonly(authority.key, !multisig),
+            ]
+            .into_iter()
+            .chain(
+                authority
+                    .signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(*signer, true)),
+            )
+            .collect(),
+            data,
+        },
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+            (record, uninitialized(DelegationRecord::LEN)),
+            (delegate, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+/// Closes a `DelegationRecord` that is still the delegate of its account.
+fn close_delegation_record(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let record = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    Case::p_token_only(
+        &authority.case_name("close_delegation_record"),
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: [
+                AccountMeta::new(record, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(authority.key, !multisig),
+            ]
+            .into_iter()
+            .chain(
+                authority
+                    .signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(*signer, true)),
+            )
+            .collect(),
+            data: vec![TokenInstruction::CloseDelegationRecord as u8],
+        },
+        [
+            (
+                source,
+                packed(Account {
+                    delegate: COption::Some(record),
+                    delegated_amount: AMOUNT,
+                    ..token_account(&mint, &authority.key)
+                }),
+            ),
+            (
+                record,
+                packed_record(DelegationRecord {
+                    account: source.to_bytes().into(),
+                    delegate: Pubkey::new_unique().to_bytes().into(),
+                    expiry: Expiry::Slot(u64::MAX),
+                    is_initialized: true,
+                }),
+            ),
+            (destination, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn increase_allowance(multisig: bool) -> Case {
+    adjust_allowance(
+        "increase_allowance",
+        TokenInstruction::IncreaseAllowance,
+        multisig,
+    )
+}
+
+fn decrease_allowance(multisig: bool) -> Case {
+    adjust_allowance(
+        "decrease_allowance",
+        TokenInstruction::DecreaseAllowance,
+        multisig,
+    )
+}
+
+fn increase_allowance_checked(multisig: bool) -> Case {
+    adjust_allowance(
+        "increase_allowance_checked",
+        TokenInstruction::IncreaseAllowanceChecked,
+        multisig,
+    )
+}
+
+fn decrease_allowance_checked(multisig: bool) -> Case {
+    adjust_allowance(
+        "decrease_allowance_checked",
+        TokenInstruction::DecreaseAllowanceChecked,
+        multisig,
+    )
+}
+
+/// Adjusts the allowance of the current delegate of an account, which is
+/// approved for twice the amount.
+fn adjust_allowance(name: &str, instruction: TokenInstruction, multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    let checked = matches!(
+        instruction,
+        TokenInstruction::IncreaseAllowanceChecked | TokenInstruction::DecreaseAllowanceChecked
+    );
+    let mut data = [&[instruction as u8][..], &AMOUNT.to_le_bytes()].concat();
+    let mut accounts = vec![AccountMeta::new(source, false)];
+    if checked {
+        data.push(DECIMALS);
+        accounts.push(AccountMeta::new_readonly(mint, false));
+    }
+    accounts.push(AccountMeta::new_readonly(delegate, false));
+    accounts.push(AccountMeta::new_readonly(authority.key, !multisig));
+    accounts.extend(
+        authority
+            .signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    let source_account = Account {
+        delegate: COption::Some(delegate),
+        delegated_amount: 2 * AMOUNT,
+        ..token_account(&mint, &authority.key)
+    };
+
+    Case::p_token_only(
+        &authority.case_name(name),
+        Instruction {
+            program_id: spl_token::ID,
+            accounts,
+            data,
+        },
+        [
+            (source, packed(source_account)),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+            (delegate, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+/// Transfers with the delegation of a `DelegationRecord`, signed by its
+/// delegate.
+fn transfer_with_expiry(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let record = Pubkey::new_unique();
+
+    let mut instruction = instruction::transfer(
+        &spl_token::ID,
+        &source,
+        &destination,
+        &record,
+        &authority.signers(),
+        AMOUNT,
+    )
+    .unwrap();
+    // The record is followed by its delegate and the signers of the delegate.
+    instruction.accounts[2].is_signer = false;
+    instruction.accounts.insert(
+        3,
+        AccountMeta::new_readonly(authority.key, authority.signers.is_empty()),
+    );
+
+    Case::p_token_only(
+        &authority.case_name("transfer_with_expiry"),
+        instruction,
+        [
+            (
+                source,
+                packed(Account {
+                    delegate: COption::Some(record),
+                    delegated_amount: AMOUNT,
+                    ..token_account(&mint, &Pubkey::new_unique())
+                }),
+            ),
+            (
+                destination,
+                packed(token_account(&mint, &Pubkey::new_unique())),
+            ),
+            (
+                record,
+                packed_record(DelegationRecord {
+                    account: source.to_bytes().into(),
+                    delegate: authority.key.to_bytes().into(),
+                    expiry: Expiry::Slot(u64::MAX),
+                    is_initialized: true,
+                }),
+            ),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn revoke(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("revoke"),
+        instruction::revoke(
+            &spl_token::ID,
+            &source,
+            &authority.key,
+            &authority.signers(),
+        )
+        .unwrap(),
+        [(
+            source,
+            packed(Account {
+                delegate: COption::Some(Pubkey::new_unique()),
+                delegated_amount: AMOUNT,
+                ..token_account(&mint, &authority.key)
+            }),
+        )]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn set_authority(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("set_authority"),
+        instruction::set_authority(
+            &spl_token::ID,
+            &account,
+            Some(&Pubkey::new_unique()),
+            AuthorityType::AccountOwner,
+            &authority.key,
+            &authority.signers(),
+        )
+        .unwrap(),
+        [(account, packed(token_account(&mint, &authority.key)))]
+            .into_iter()
+            .chain(authority.accounts)
+            .collect(),
+    )
+}
+
+fn mint_to(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("mint_to"),
+        instruction::mint_to(
+            &spl_token::ID,
+            &mint,
+            &account,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+        )
+        .unwrap(),
+        [
+            (mint, self::mint(&authority.key, None)),
+            (account, packed(token_account(&mint, &Pubkey::new_unique()))),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn mint_to_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("mint_to_checked"),
+        instruction::mint_to_checked(
+            &spl_token::ID,
+            &mint,
+            &account,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+            DECIMALS,
+        )
+        .unwrap(),
+        [
+            (mint, self::mint(&authority.key, None)),
+            (account, packed(token_account(&mint, &Pubkey::new_unique()))),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn burn(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("burn"),
+        instruction::burn(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+        )
+        .unwrap(),
+        [
+            (account, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn burn_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("burn_checked"),
+        instruction::burn_checked(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &authority.key,
+            &authority.signers(),
+            AMOUNT,
+            DECIMALS,
+        )
+        .unwrap(),
+        [
+            (account, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn close_account(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("close_account"),
+        instruction::close_account(
+            &spl_token::ID,
+            &account,
+            &destination,
+            &authority.key,
+            &authority.signers(),
+        )
+        .unwrap(),
+        [
+            (
+                account,
+                packed(Account {
+                    amount: 0,
+                    ..token_account(&mint, &authority.key)
+                }),
+            ),
+            (destination, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn freeze_account(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("freeze_account"),
+        instruction::freeze_account(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &authority.key,
+            &authority.signers(),
+        )
+        .unwrap(),
+        [
+            (account, packed(token_account(&mint, &Pubkey::new_unique()))),
+            (
+                mint,
+                self::mint(&Pubkey::new_unique(), Some(&authority.key)),
+            ),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn thaw_account(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    Case::new(
+        &authority.case_name("thaw_account"),
+        instruction::thaw_account(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &authority.key,
+            &authority.signers(),
+        )
+        .unwrap(),
+        [
+            (
+                account,
+                packed(Account {
+                    state: AccountState::Frozen,
+                    ..token_account(&mint, &Pubkey::new_unique())
+                }),
+            ),
+            (
+                mint,
+                self::mint(&Pubkey::new_unique(), Some(&authority.key)),
+            ),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn withdraw_excess_lamports(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let mut source_account = packed(token_account(&mint, &authority.key));
+    source_account.lamports += EXCESS_LAMPORTS;
+
+    let mut accounts = vec![
+        AccountMeta::new(source, false),
+        AccountMeta::new(destination, false),
+        AccountMeta::new_readonly(authority.key, !multisig),
+    ];
+    accounts.extend(
+        authority
+            .signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    Case::p_token_only(
+        &authority.case_name("withdraw_excess_lamports"),
+        Instruction {
+            program_id: spl_token::ID,
+            accounts,
+            data: vec![38],
+        },
+        [
+            (source, source_account),
+            (destination, SolanaAccount::default()),
+        ]
+        .into_iter()
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+/// Creates a `TransferMany` or `MintToMany` instruction.
+///
+/// The `accounts` are followed by the destination accounts and the signers of
+/// the authority.
+fn many_instruction(
+    discriminator: u8,
+    mut accounts: Vec<AccountMeta>,
+    destinations: &[Pubkey],
+    authority: &Authority,
+) -> Instruction {
+    accounts.extend(
+        destinations
+            .iter()
+            .map(|destination| AccountMeta::new(*destination, false)),
+    );
+    accounts.extend(
+        authority
+            .signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    let mut data = vec![discriminator, DECIMALS];
+    for _ in destinations {
+        data.extend_from_slice(&AMOUNT.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: spl_token::ID,
+        accounts,
+        data,
+    }
+}
+
+fn transfer_many(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destinations = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction = many_instruction(
+        252,
+        vec![
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(authority.key, !multisig),
+        ],
+        &destinations,
+        &authority,
+    );
+    let equivalent = destinations
+        .iter()
+        .map(|destination| {
+            instruction::transfer_checked(
+                &spl_token::ID,
+                &source,
+                &mint,
+                destination,
+                &authority.key,
+                &authority.signers(),
+                AMOUNT,
+                DECIMALS,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    Case::equivalent(
+        &authority.case_name("transfer_many"),
+        instruction,
+        equivalent,
+        [
+            (source, packed(token_account(&mint, &authority.key))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+        ]
+        .into_iter()
+        .chain(destinations.map(|destination| {
+            (
+                destination,
+                packed(token_account(&mint, &Pubkey::new_unique())),
+            )
+        }))
+        .chain(authority.accounts)
+        .collect(),
+    )
+}
+
+fn mint_to_many(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let mint = Pubkey::new_unique();
+    let destinations = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction = many_instruction(
+        251,
+        vec![
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(authority.key, !multisig),
+        ],
+        &destinations,
+        &authority,
+    );
+    let equivalent = destinations
+        .iter()
+        .map(|destination| {
+            instruction::mint_to_checked(
+                &spl_token::ID,
+                &mint,
+                destination,
+                &authority.key,
+                &authority.signers(),
+                AMOUNT,
+                DECIMALS,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    Case::equivalent(
+        &authority.case_name("mint_to_many"),
+        instruction,
+        equivalent,
+        [(mint, self::mint(&authority.key, None))]
+            .into_iter()
+            .chain(destinations.map(|destination| {
+                (
+                    destination,
+                    packed(token_account(&mint, &Pubkey::new_unique())),
+                )
+            }))
+            .chain(authority.accounts)
+            .collect(),
+    )
+}
+
+/// Creates a `Batch` or `BestEffortBatch` instruction.
+fn batch(discriminator: u8, instructions: &[Instruction]) -> Instruction {
+    let mut accounts = Vec::new();
+    let mut data = vec![discriminator];
+
+    for instruction in instructions {
+        data.push(instruction.accounts.len() as u8);
+        data.push(instruction.data.len() as u8);
+        data.extend_from_slice(&instruction.data);
+        accounts.extend_from_slice(&instruction.accounts);
+    }
+
+    Instruction {
+        program_id: spl_token::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `IndexedBatch` instruction, where each account is only
+/// provided once.
+fn indexed_batch(instructions: &[Instruction]) -> Instruction {
+    let mut accounts: Vec<AccountMeta> = Vec::new();
+    let mut data = vec![254];
+
+    for instruction in instructions {
+        data.push(instruction.accounts.len() as u8);
+        data.push(instruction.data.len() as u8);
+
+        for meta in &instruction.accounts {
+            let index = match accounts.iter().position(|a| a.pubkey == meta.pubkey) {
+                Some(index) => {
+                    accounts[index].is_signer |= meta.is_signer;
+                    accounts[index].is_writable |= meta.is_writable;
+                    index
+                }
+                None => {
+                    accounts.push(meta.clone());
+                    accounts.len() - 1
+                }
+            };
+            data.push(index as u8);
+        }
+
+        data.extend_from_slice(&instruction.data);
+    }
+
+    Instruction {
+        program_id: spl_token::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Two `TransferChecked` instructions from the same source account, with the
+/// accounts of the case.
+fn two_transfers_checked(
+    authority: &Authority,
+) -> (Vec<Instruction>, Vec<(Pubkey, SolanaAccount)>) {
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destinations = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instructions = destinations
+        .iter()
+        .map(|destination| {
+            instruction::transfer_checked(
+                &spl_token::ID,
+                &source,
+                &mint,
+                destination,
+                &authority.key,
+                &authority.signers(),
+                AMOUNT,
+                DECIMALS,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let accounts = [
+        (source, packed(token_account(&mint, &authority.key))),
+        (mint, self::mint(&Pubkey::new_unique(), None)),
+    ]
+    .into_iter()
+    .chain(destinations.map(|destination| {
+        (
+            destination,
+            packed(token_account(&mint, &Pubkey::new_unique())),
+        )
+    }))
+    .chain(authority.accounts.iter().cloned())
+    .collect();
+
+    (instructions, accounts)
+}
+
+fn batch_transfer_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let (instructions, accounts) = two_transfers_checked(&authority);
+
+    Case::equivalent(
+        &authority.case_name("batch_transfer_checked_x2"),
+        batch(255, &instructions),
+        instructions,
+        accounts,
+    )
+}
+
+fn best_effort_batch_transfer_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let (instructions, accounts) = two_transfers_checked(&authority);
+
+    Case::equivalent(
+        &authority.case_name("best_effort_batch_transfer_checked_x2"),
+        batch(253, &instructions),
+        instructions,
+        accounts,
+    )
+}
+
+/// A best-effort batch where a transfer exceeding the remaining amount is
+/// skipped between two transfers, which compares the snapshots of its
+/// accounts on SBF.
+fn best_effort_batch_transfer_checked_skipped(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let (instructions, accounts) = two_transfers_checked(&authority);
+
+    let mut skipped = instructions[0].clone();
+    skipped.data = instruction::TokenInstruction::TransferChecked {
+        amount: BALANCE,
+        decimals: DECIMALS,
+    }
+    .pack();
+
+    Case::equivalent(
+        &authority.case_name("best_effort_batch_transfer_checked_skipped_x3"),
+        batch(
+            253,
+            &[instructions[0].clone(), skipped, instructions[1].clone()],
+        ),
+        instructions,
+        accounts,
+    )
+}
+
+fn indexed_batch_transfer_checked(multisig: bool) -> Case {
+    let authority = Authority::new(multisig);
+    let (instructions, accounts) = two_transfers_checked(&authority);
+
+    Case::equivalent(
+        &authority.case_name("indexed_batch_transfer_checked_x2"),
+        indexed_batch(&instructions),
+        instructions,
+        accounts,
+    )
+}
+
+/// Initializes a token account and mints tokens to it.
+fn batch_initialize_account3_mint_to_checked() -> Case {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let instructions = vec![
+        instruction::initialize_account3(&spl_token::ID, &account, &mint, &owner).unwrap(),
+        instruction::mint_to_checked(
+            &spl_token::ID,
+            &mint,
+            &account,
+            &mint_authority,
+            &[],
+            AMOUNT,
+            DECIMALS,
+        )
+        .unwrap(),
+    ];
+
+    Case::equivalent(
+        "batch_initialize_account3_mint_to_checked",
+        batch(255, &instructions),
+        instructions,
+        vec![
+            (account, uninitialized(Account::LEN)),
+            (mint, self::mint(&mint_authority, None)),
+            (mint_authority, SolanaAccount::default()),
+        ],
+    )
+}
+
+/// Empties a token account and closes it.
+fn batch_transfer_checked_close_account() -> Case {
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let instructions = vec![
+        instruction::transfer_checked(
+            &spl_token::ID,
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            BALANCE,
+            DECIMALS,
+        )
+        .unwrap(),
+        instruction::close_account(&spl_token::ID, &source, &owner, &owner, &[]).unwrap(),
+    ];
+
+    Case::equivalent(
+        "batch_transfer_checked_close_account",
+        batch(255, &instructions),
+        instructions,
+        vec![
+            (source, packed(token_account(&mint, &owner))),
+            (mint, self::mint(&Pubkey::new_unique(), None)),
+            (
+                destination,
+                packed(token_account(&mint, &Pubkey::new_unique())),
+            ),
+            (owner, SolanaAccount::default()),
+        ],
+    )
+}
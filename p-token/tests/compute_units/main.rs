@@ -0,0 +1,168 @@
+//! Compute unit regression tests.
+//!
+//! Each case executes an instruction (or a batch of instructions) on `p-token`
+//! and the equivalent instructions on `spl-token`, recording the compute units
+//! consumed by each program. Instructions with an authority are measured with
+//! a single signer and a multisignature authority. Instructions that
+//! `spl-token` does not support, e.g., `TransferMany`, are compared with the
+//! chain of instructions that `spl-token` needs for the same effect.
+//!
+//! The comparison is written as a CSV table with the columns
+//! `case,p_token,spl_token,baseline` to `compute_units.csv` in the target
+//! temporary directory. The test fails if a case consumes more compute units
+//! on `p-token` than recorded in `baseline.csv`, if a case is missing from the
+//! baseline, or if the baseline has a case that no longer exists.
+//!
+//! Both programs need to be built first, since the tests load them from
+//! `SBF_OUT_DIR`:
+//!
+//! ```text
+//! pnpm programs:build
+//! pnpm p-token:build
+//! pnpm p-token:test --test compute_units
+//! ```
+//!
+//! The baseline is updated with the `UPDATE_BASELINE` environment variable:
+//!
+//! ```text
+//! UPDATE_BASELINE=1 pnpm p-token:test --test compute_units
+//! ```
+
+#![allow(clippy::arithmetic_side_effects)]
+
+mod cases;
+
+use {
+    cases::Case,
+    mollusk_svm::{result::InstructionResult, Mollusk},
+    pinocchio_token_program as _,
+    std::{collections::BTreeMap, fmt::Write, fs},
+};
+
+const BASELINE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/compute_units/baseline.csv"
+);
+
+const TABLE: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/compute_units.csv");
+
+const BASELINE_HEADER: &str = "case,compute_units";
+
+const TABLE_HEADER: &str = "case,p_token,spl_token,baseline";
+
+/// Compute units consumed by a case.
+struct Measurement {
+    name: String,
+    p_token: u64,
+    spl_token: Option<u64>,
+}
+
+fn check_success(name: &str, program: &str, result: &InstructionResult) {
+    assert!(
+        !result.program_result.is_err(),
+        "{name} failed on {program}: {:?}",
+        result.program_result
+    );
+}
+
+fn measure(p_token: &Mollusk, spl_token: &Mollusk, case: Case) -> Measurement {
+    let result = p_token.process_instruction(&case.instruction, &case.accounts);
+    check_success(&case.name, "p-token", &result);
+
+    let spl_token = case.spl_token.map(|instructions| {
+        let result = spl_token.process_instruction_chain(&instructions, &case.accounts);
+        check_success(&case.name, "spl-token", &result);
+        result.compute_units_consumed
+    });
+
+    Measurement {
+        name: case.name,
+        p_token: result.compute_units_consumed,
+        spl_token,
+    }
+}
+
+/// Parses the baseline as a map from case name to compute units.
+fn parse_baseline(content: &str) -> BTreeMap<String, u64> {
+    let mut lines = content.lines();
+    assert_eq!(
+        lines.next(),
+        Some(BASELINE_HEADER),
+        "invalid baseline header"
+    );
+
+    lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (name, units) = line
+                .split_once(',')
+                .unwrap_or_else(|| panic!("invalid baseline line: {line}"));
+            let units = units
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid compute units: {line}"));
+            (name.to_string(), units)
+        })
+        .collect()
+}
+
+#[test]
+fn compute_units() {
+    let p_token = Mollusk::new(&spl_token::ID, "pinocchio_token_program");
+    let spl_token = Mollusk::new(&spl_token::ID, "spl_token");
+
+    let measurements = cases::cases(&p_token)
+        .into_iter()
+        .map(|case| measure(&p_token, &spl_token, case))
+        .collect::<Vec<_>>();
+
+    let mut baseline = parse_baseline(&fs::read_to_string(BASELINE).unwrap());
+
+    if std::env::var_os("UPDATE_BASELINE").is_some() {
+        let mut content = format!("{BASELINE_HEADER}\n");
+        for measurement in &measurements {
+            writeln!(content, "{},{}", measurement.name, measurement.p_token).unwrap();
+        }
+        fs::write(BASELINE, content).unwrap();
+        baseline = parse_baseline(&fs::read_to_string(BASELINE).unwrap());
+    }
+
+    let mut table = format!("{TABLE_HEADER}\n");
+    let mut errors = Vec::new();
+
+    for measurement in &measurements {
+        let expected = baseline.remove(&measurement.name);
+
+        writeln!(
+            table,
+            "{},{},{},{}",
+            measurement.name,
+            measurement.p_token,
+            measurement
+                .spl_token
+                .map(|units| units.to_string())
+                .unwrap_or_default(),
+            expected.map(|units| units.to_string()).unwrap_or_default(),
+        )
+        .unwrap();
+
+        match expected {
+            Some(expected) if measurement.p_token > expected => errors.push(format!(
+                "{} regressed: {} > {expected} compute units",
+                measurement.name, measurement.p_token
+            )),
+            None => errors.push(format!("{} is missing from the baseline", measurement.name)),
+            _ => (),
+        }
+    }
+
+    errors.extend(
+        baseline
+            .keys()
+            .map(|name| format!("{name} is in the baseline but not measured")),
+    );
+
+    fs::write(TABLE, &table).unwrap();
+    println!("{table}");
+
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
@@ -0,0 +1,147 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::instruction::TokenInstruction,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+/// Creates a `DecreaseAllowanceChecked` instruction.
+fn decrease_allowance_checked(
+    source: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*delegate, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data: [
+            &[TokenInstruction::DecreaseAllowanceChecked as u8][..],
+            &amount.to_le_bytes(),
+            &[decimals],
+        ]
+        .concat(),
+    }
+}
+
+#[tokio::test]
+async fn decrease_allowance_checked_to_zero() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens and a delegate approved for 50.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let delegate = Pubkey::new_unique();
+
+    let approve_ix = spl_token::instruction::approve(
+        &TOKEN_PROGRAM_ID,
+        &account,
+        &delegate,
+        &owner.pubkey(),
+        &[],
+        50,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we decrease the allowance by more than the delegated amount.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[decrease_allowance_checked(
+            &account,
+            &mint,
+            &delegate,
+            &owner.pubkey(),
+            51,
+            4,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    // Then the transaction fails.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+    // When we decrease the allowance by the delegated amount.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[decrease_allowance_checked(
+            &account,
+            &mint,
+            &delegate,
+            &owner.pubkey(),
+            50,
+            4,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegate is cleared.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.delegate.is_none());
+    assert!(token_account.delegated_amount == 0);
+}
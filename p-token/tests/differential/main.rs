@@ -0,0 +1,51 @@
+//! Differential fuzzing between `spl-token` and `p-token`.
+//!
+//! Each test case generates a small universe of random account states and an
+//! instruction (including batch instructions), executes it on both programs
+//! natively and asserts that they produce identical results, error codes,
+//! account data and lamports. When a diverging case is found, proptest shrinks
+//! it to a minimal failing case and records it in the `proptest-regressions`
+//! directory, so it is executed again on subsequent runs.
+//!
+//! The number of test cases can be increased with the `PROPTEST_CASES`
+//! environment variable:
+//!
+//! ```text
+//! PROPTEST_CASES=100000 cargo test -p pinocchio-token-program --test differential
+//! ```
+//!
+//! The strict loading of account states in `pinocchio-token-interface` is
+//! also compared with `Pack::unpack` of `spl-token`.
+//!
+//! Note that return data is not available outside of the SVM, so the return
+//! data of `AmountToUiAmount`, `GetAccountDataSize`, `GetBalance`,
+//! `GetMintInfo` and batch instructions is not compared. The return data of
+//! the UI amount conversions is compared in the SVM by the `return_data` tests.
+//!
+//! Similarly, the `Clock` sysvar is not available, so both programs fail
+//! when a delegation of `ApproveWithExpiry` is used, after validating its
+//! delegation record.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+mod reference;
+mod runtime;
+mod strategy;
+
+use {
+    pinocchio_token_program as _,
+    proptest::prelude::*,
+    strategy::{state_data_strategy, test_case_strategy},
+};
+
+proptest! {
+    #[test]
+    fn differential(test_case in test_case_strategy()) {
+        reference::check(&test_case)?;
+    }
+
+    #[test]
+    fn load_strict(data in state_data_strategy()) {
+        reference::check_load_strict(&data)?;
+    }
+}
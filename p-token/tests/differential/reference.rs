@@ -0,0 +1,510 @@
+//! Reference semantics and comparison of the results of both programs.
+//!
+//! Instructions implemented by `spl-token` are executed directly; for the
+//! instructions that only `p-token` implements, the reference semantics is
+//! defined in terms of `spl-token` instructions:
+//!
+//! - `WithdrawExcessLamports` (38) follows `spl-token-2022`, validating the
+//!   authority as `spl-token` does; additionally, a mint without mint authority
+//!   can sign for itself. Uninitialized token accounts and mints fail with
+//!   `UninitializedAccount`, as in the other `spl-token` instructions, rather
+//!   than with the `InvalidState` of `spl-token-2022`. Since the rent sysvar is
+//!   not available outside of the SVM, the instruction always fails after
+//!   validating the authority.
+//! - `Batch` (255) and `IndexedBatch` (254) execute their instructions in
+//!   sequence, failing on the first error.
+//! - `BestEffortBatch` (253) executes its instructions in sequence, reverting
+//!   and skipping failed instructions with at most
+//!   `MAX_BEST_EFFORT_WRITABLE_ACCOUNTS` writable accounts.
+//! - `TransferMany` (252) and `MintToMany` (251) execute a `TransferChecked` or
+//!   `MintToChecked` instruction for each destination account.
+//! - `GetBalance` (250) and `GetMintInfo` (249) unpack the token account or
+//!   mint owned by the token program, without modifying any account. The return
+//!   data is not compared.
+//!
+//! The instructions from `InitializeMultisig3` (248) to `CloseDelegationRecord`
+//! (241) and the large multisig and delegation record accounts they use have no
+//! `spl-token` counterpart, so they are not part of the test cases.
+
+use {
+    crate::{
+        runtime::{
+            check_runtime_rules, process_native, process_p_token, process_spl_token, AccountState,
+        },
+        strategy::{Instruction, TestCase},
+    },
+    pinocchio_token_interface::{
+        instruction::{decode_batch, MAX_BEST_EFFORT_WRITABLE_ACCOUNTS, MAX_INDEXED_ACCOUNTS},
+        state::{self, Initializable, Transmutable, Validate},
+    },
+    proptest::{prop_assert_eq, prop_assume, test_runner::TestCaseError},
+    solana_account_info::AccountInfo,
+    solana_program_error::ProgramError,
+    solana_program_option::COption,
+    solana_program_pack::{IsInitialized, Pack},
+    solana_pubkey::Pubkey,
+    spl_token::{
+        error::TokenError,
+        processor::Processor,
+        state::{Account, Mint, Multisig},
+    },
+    spl_token_interface::state::{DelegationRecord, LargeMultisig},
+};
+
+/// Discriminator of the `CloseAccount` instruction.
+const CLOSE_ACCOUNT: u8 = 9;
+
+/// Discriminators of the instructions that only `p-token` implements and that
+/// have no reference semantics.
+const P_TOKEN_ONLY_DISCRIMINATORS: [u8; 8] = [248, 247, 246, 245, 244, 243, 242, 241];
+
+/// How the results of both programs are compared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    /// Results, including error codes, and account states must match.
+    Strict,
+
+    /// Both programs must either succeed with the same account states or
+    /// fail. This is used when the reference executes several instructions
+    /// in place of a single one, which validate accounts in a different order.
+    Outcome,
+}
+
+/// Checks that `load_strict` accepts and rejects the same data as
+/// `Pack::unpack`, with the same error codes, for each type of account state.
+pub fn check_load_strict(data: &[u8]) -> Result<(), TestCaseError> {
+    fn load_strict<T: Initializable + Transmutable + Validate>(data: &[u8]) -> Result<(), u64> {
+        // SAFETY: any bytes are a valid representation of the account states.
+        unsafe { state::load_strict::<T>(data) }
+            .map(|_| ())
+            .map_err(u64::from)
+    }
+
+    fn unpack<T: Pack + IsInitialized>(data: &[u8]) -> Result<(), u64> {
+        T::unpack(data).map(|_| ()).map_err(u64::from)
+    }
+
+    // The large multisig and delegation record implement `Pack` of a newer
+    // version of the crate.
+    fn unpack_v3<T: solana_program_pack_v3::Pack + solana_program_pack_v3::IsInitialized>(
+        data: &[u8],
+    ) -> Result<(), u64> {
+        T::unpack(data).map(|_| ()).map_err(u64::from)
+    }
+
+    prop_assert_eq!(
+        load_strict::<state::account::Account>(data),
+        unpack::<Account>(data)
+    );
+    prop_assert_eq!(load_strict::<state::mint::Mint>(data), unpack::<Mint>(data));
+    prop_assert_eq!(
+        load_strict::<state::multisig::Multisig>(data),
+        unpack::<Multisig>(data)
+    );
+    prop_assert_eq!(
+        load_strict::<state::multisig::LargeMultisig>(data),
+        unpack_v3::<LargeMultisig>(data)
+    );
+    prop_assert_eq!(
+        load_strict::<state::delegation::DelegationRecord>(data),
+        unpack_v3::<DelegationRecord>(data)
+    );
+
+    Ok(())
+}
+
+/// Executes the test case on both programs and checks that the results match.
+pub fn check(test_case: &TestCase) -> Result<(), TestCaseError> {
+    let TestCase {
+        universe,
+        instruction,
+    } = test_case;
+
+    assume_supported(instruction)?;
+
+    let ix = instruction.encode();
+
+    let mut expected = universe.clone();
+    let mut skipped = false;
+    let expected_result = process(&mut expected, &ix.accounts, &ix.data, &mut skipped)
+        .and_then(|()| check_runtime_rules(universe, &expected));
+
+    let mut actual = universe.clone();
+    let actual_result = process_p_token(&mut actual, &ix.accounts, &ix.data).and_then(|()| {
+        // Closing accounts is a no-op outside of the SVM, so it is emulated
+        // once the instruction succeeded.
+        closed_accounts(instruction)
+            .into_iter()
+            .for_each(|index| actual[index].close());
+        check_runtime_rules(universe, &actual)
+    });
+
+    let mode = if contains(instruction, &[252, 251]) {
+        Mode::Outcome
+    } else {
+        Mode::Strict
+    };
+
+    match (expected_result, actual_result) {
+        (Ok(()), Ok(())) => {
+            for (index, (expected, actual)) in expected.iter().zip(&actual).enumerate() {
+                prop_assert_eq!(
+                    expected.normalized(),
+                    actual.normalized(),
+                    "account {} diverged",
+                    index
+                );
+            }
+        }
+        // A best-effort batch fails in `p-token` when a failed instruction
+        // modified its accounts, while the reference reverts and skips it.
+        (_, Err(_)) if skipped => (),
+        (Err(expected), Err(actual)) => {
+            if mode == Mode::Strict {
+                prop_assert_eq!(expected, actual, "error codes diverged");
+            }
+        }
+        (expected, actual) => {
+            prop_assert_eq!(expected, actual, "results diverged");
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejects test cases that cannot be compared outside of the SVM.
+///
+/// Closed accounts keep their state in `p-token` until the end of the
+/// instruction, so batches cannot reference an account after closing it;
+/// and best-effort batches cannot close accounts, since it is not known
+/// whether the instruction was executed or skipped. Instructions without
+/// reference semantics are not compared either.
+fn assume_supported(instruction: &Instruction) -> Result<(), TestCaseError> {
+    prop_assume!(!contains(instruction, &P_TOKEN_ONLY_DISCRIMINATORS));
+
+    if let Instruction::Batch {
+        discriminator,
+        items,
+    } = instruction
+    {
+        for (position, item) in items.iter().enumerate() {
+            if item.data.first() == Some(&CLOSE_ACCOUNT) {
+                prop_assume!(*discriminator != 253);
+
+                if let Some(closed) = item.accounts.first() {
+                    prop_assume!(!items[position + 1..]
+                        .iter()
+                        .any(|item| item.accounts.contains(closed)));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the accounts closed by a successful instruction.
+fn closed_accounts(instruction: &Instruction) -> Vec<usize> {
+    instruction
+        .items()
+        .iter()
+        .filter(|item| item.data.first() == Some(&CLOSE_ACCOUNT))
+        .filter_map(|item| item.accounts.first().copied())
+        .collect()
+}
+
+/// Checks whether the instruction is or contains one of the `discriminators`.
+fn contains(instruction: &Instruction, discriminators: &[u8]) -> bool {
+    instruction.items().iter().any(|item| {
+        item.data
+            .first()
+            .is_some_and(|discriminator| discriminators.contains(discriminator))
+    })
+}
+
+fn error_code(error: impl Into<ProgramError>) -> u64 {
+    u64::from(error.into())
+}
+
+fn invalid_instruction() -> u64 {
+    error_code(TokenError::InvalidInstruction)
+}
+
+/// Executes an instruction with the reference semantics.
+///
+/// `skipped` is set when an instruction of a best-effort batch is skipped.
+fn process(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+    skipped: &mut bool,
+) -> Result<(), u64> {
+    match data.split_first() {
+        Some((255, data)) => process_batch(universe, accounts, data),
+        Some((254, data)) => process_indexed_batch(universe, accounts, data),
+        Some((253, data)) => process_best_effort_batch(universe, accounts, data, skipped),
+        _ => process_instruction(universe, accounts, data),
+    }
+}
+
+/// Executes a non-batch instruction with the reference semantics.
+fn process_instruction(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+) -> Result<(), u64> {
+    match data.first() {
+        Some(38) => process_native(universe, accounts, process_withdraw_excess_lamports),
+        Some(252) => process_many(universe, accounts, data, |amount, decimals, fixed, dest| {
+            // TransferChecked: source, mint, destination, authority and signers.
+            let [source, mint, authority, signers @ ..] = fixed else {
+                unreachable!()
+            };
+            let accounts = [&[*source, *mint, dest, *authority][..], signers].concat();
+            (
+                [&[12][..], &amount.to_le_bytes(), &[decimals]].concat(),
+                accounts,
+            )
+        }),
+        Some(251) => process_many(universe, accounts, data, |amount, decimals, fixed, dest| {
+            // MintToChecked: mint, destination, authority and signers.
+            let [mint, authority, signers @ ..] = fixed else {
+                unreachable!()
+            };
+            let accounts = [&[*mint, dest, *authority][..], signers].concat();
+            (
+                [&[14][..], &amount.to_le_bytes(), &[decimals]].concat(),
+                accounts,
+            )
+        }),
+        Some(250) => process_native(universe, accounts, process_get_balance),
+        Some(249) => process_native(universe, accounts, process_get_mint_info),
+        _ => process_spl_token(universe, accounts, data),
+    }
+}
+
+fn process_get_balance(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let [account_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account_info.owner != &spl_token::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Account::unpack(&account_info.data.borrow())?;
+
+    Ok(())
+}
+
+fn process_get_mint_info(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let [mint_info, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if mint_info.owner != &spl_token::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Mint::unpack(&mint_info.data.borrow()).map_err(|_| TokenError::InvalidMint)?;
+
+    Ok(())
+}
+
+fn process_withdraw_excess_lamports(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let [source_info, _destination_info, authority_info, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let validate_owner = |expected_owner: &Pubkey| {
+        Processor::validate_owner(&spl_token::ID, expected_owner, authority_info, signers)
+    };
+
+    let source_data = source_info.data.borrow();
+
+    match source_data.len() {
+        Account::LEN => {
+            let account = Account::unpack(&source_data)?;
+
+            if account.is_native() {
+                return Err(TokenError::NativeNotSupported.into());
+            }
+
+            validate_owner(&account.owner)?;
+        }
+        Mint::LEN => {
+            let mint = Mint::unpack(&source_data)?;
+
+            match mint.mint_authority {
+                COption::Some(mint_authority) => validate_owner(&mint_authority)?,
+                COption::None if source_info.key == authority_info.key => {
+                    if !authority_info.is_signer {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                }
+                COption::None => return Err(TokenError::AuthorityTypeNotSupported.into()),
+            }
+        }
+        Multisig::LEN => validate_owner(source_info.key)?,
+        _ => return Err(TokenError::InvalidState.into()),
+    }
+
+    Err(ProgramError::UnsupportedSysvar)
+}
+
+/// Executes `TransferMany` or `MintToMany` as a sequence of instructions, one
+/// for each destination account.
+///
+/// The `build` closure receives the amount, decimals, the fixed accounts
+/// (followed by the signers) and the destination of each instruction.
+fn process_many(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+    build: impl Fn(u64, u8, &[usize], usize) -> (Vec<u8>, Vec<usize>),
+) -> Result<(), u64> {
+    let fixed_len = if data[0] == 252 { 3 } else { 2 };
+
+    let [_, decimals, amounts @ ..] = data else {
+        return Err(invalid_instruction());
+    };
+
+    if amounts.is_empty() || amounts.len() % 8 != 0 {
+        return Err(invalid_instruction());
+    }
+
+    let destinations_len = amounts.len() / 8;
+
+    if accounts.len() < fixed_len + destinations_len {
+        return Err(error_code(ProgramError::NotEnoughAccountKeys));
+    }
+
+    let (fixed, remaining) = accounts.split_at(fixed_len);
+    let (destinations, signers) = remaining.split_at(destinations_len);
+    let fixed = [fixed, signers].concat();
+
+    for (amount, destination) in amounts.chunks_exact(8).zip(destinations) {
+        let amount = u64::from_le_bytes(amount.try_into().unwrap());
+        let (data, accounts) = build(amount, *decimals, &fixed, *destination);
+        process_spl_token(universe, &accounts, &data)?;
+    }
+
+    Ok(())
+}
+
+fn process_batch(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+) -> Result<(), u64> {
+    for item in decode_batch(data, accounts) {
+        let (instruction, ix_accounts, ix_data) =
+            item.map_err(|error| u64::from(pinocchio::program_error::ProgramError::from(error)))?;
+        let data = [&[instruction as u8][..], ix_data].concat();
+        process_instruction(universe, ix_accounts, &data)?;
+    }
+
+    Ok(())
+}
+
+fn process_indexed_batch(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    mut data: &[u8],
+) -> Result<(), u64> {
+    loop {
+        let [expected_accounts, data_len, remaining @ ..] = data else {
+            return Err(invalid_instruction());
+        };
+        let expected_accounts = *expected_accounts as usize;
+        let data_len = *data_len as usize;
+
+        if remaining.len() < expected_accounts + data_len
+            || data_len == 0
+            || expected_accounts > MAX_INDEXED_ACCOUNTS
+        {
+            return Err(invalid_instruction());
+        }
+
+        let (indices, remaining) = remaining.split_at(expected_accounts);
+        let (ix_data, remaining) = remaining.split_at(data_len);
+
+        let ix_accounts = indices
+            .iter()
+            .map(|index| accounts.get(*index as usize).copied())
+            .collect::<Option<Vec<_>>>()
+            .ok_or(error_code(ProgramError::NotEnoughAccountKeys))?;
+
+        process_nested(universe, &ix_accounts, ix_data)?;
+
+        if remaining.is_empty() {
+            return Ok(());
+        }
+
+        data = remaining;
+    }
+}
+
+fn process_best_effort_batch(
+    universe: &mut [AccountState],
+    mut accounts: &[usize],
+    mut data: &[u8],
+    skipped: &mut bool,
+) -> Result<(), u64> {
+    loop {
+        let [expected_accounts, data_len, remaining @ ..] = data else {
+            return Err(invalid_instruction());
+        };
+        let expected_accounts = *expected_accounts as usize;
+        let data_len = *data_len as usize;
+
+        if remaining.len() < data_len || data_len == 0 {
+            return Err(invalid_instruction());
+        }
+
+        if accounts.len() < expected_accounts {
+            return Err(error_code(ProgramError::NotEnoughAccountKeys));
+        }
+
+        let (ix_data, remaining) = remaining.split_at(data_len);
+        let (ix_accounts, remaining_accounts) = accounts.split_at(expected_accounts);
+
+        // Nested batch instructions are never skipped.
+        if matches!(ix_data.first(), Some(253..=255)) {
+            return Err(invalid_instruction());
+        }
+
+        let writable = ix_accounts
+            .iter()
+            .filter(|index| universe[**index].is_writable)
+            .count();
+        let snapshot = universe.to_vec();
+
+        if let Err(error) = process_nested(universe, ix_accounts, ix_data) {
+            if writable > MAX_BEST_EFFORT_WRITABLE_ACCOUNTS {
+                return Err(error);
+            }
+            universe.clone_from_slice(&snapshot);
+            *skipped = true;
+        }
+
+        if remaining.is_empty() {
+            return Ok(());
+        }
+
+        accounts = remaining_accounts;
+        data = remaining;
+    }
+}
+
+/// Executes an instruction of a batch, where batch instructions are invalid.
+fn process_nested(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+) -> Result<(), u64> {
+    match data.first() {
+        Some(253..=255) => Err(invalid_instruction()),
+        _ => process_instruction(universe, accounts, data),
+    }
+}
@@ -0,0 +1,272 @@
+//! Native execution of both token program implementations.
+//!
+//! Both processors are executed in-process against the same account states:
+//! `p-token` through its program entrypoint, using an input buffer with the
+//! same layout as the one created by the SVM loader; and `spl-token` through
+//! its `Processor::process` function.
+
+use {
+    solana_account_info::AccountInfo,
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+    std::{fmt, mem::size_of, ptr::copy_nonoverlapping},
+};
+
+/// Maximum number of bytes an account can grow during an instruction.
+const MAX_PERMITTED_DATA_INCREASE: usize = 10 * 1024;
+
+/// Marker of a non-duplicated account in the input buffer.
+const NON_DUP_MARKER: u8 = u8::MAX;
+
+/// Size of the account header in the input buffer.
+const ACCOUNT_HEADER_SIZE: usize = 88;
+
+/// Error code used when an instruction breaks a rule enforced by the runtime,
+/// e.g., modifying an account that is not writable.
+pub const RUNTIME_VIOLATION: u64 = u64::MAX;
+
+extern "C" {
+    /// Program entrypoint of `p-token`.
+    fn entrypoint(input: *mut u8) -> u64;
+}
+
+/// Native implementation of the `sol_memcpy_` syscall used by `p-token`.
+///
+/// # Safety
+///
+/// `dst` and `src` must be valid for `n` bytes and must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn sol_memcpy_(dst: *mut u8, src: *const u8, n: u64) {
+    copy_nonoverlapping(src, dst, n as usize);
+}
+
+/// State of an account in the universe of a test case.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AccountState {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl fmt::Debug for AccountState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Data is formatted as an hex string to keep failing cases readable.
+        let data = self
+            .data
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        f.debug_struct("AccountState")
+            .field("key", &self.key)
+            .field("owner", &self.owner)
+            .field("lamports", &self.lamports)
+            .field("data", &data)
+            .field("is_signer", &self.is_signer)
+            .field("is_writable", &self.is_writable)
+            .finish()
+    }
+}
+
+impl AccountState {
+    /// Returns the state of the account as observed after the transaction,
+    /// where accounts without lamports are deleted by the runtime.
+    pub fn normalized(&self) -> Option<&Self> {
+        (self.lamports > 0).then_some(self)
+    }
+
+    /// Closes the account, mimicking `AccountInfo::close_unchecked`, which
+    /// is a no-op outside of the SVM.
+    pub fn close(&mut self) {
+        self.owner = Pubkey::default();
+        self.lamports = 0;
+        self.data.clear();
+    }
+}
+
+/// Executes an instruction on `p-token`, returning the error code on failure.
+pub fn process_p_token(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+) -> Result<(), u64> {
+    let mut input = Input::default();
+    let mut offsets = vec![None; universe.len()];
+
+    input.write(&(accounts.len() as u64).to_le_bytes());
+
+    for (position, index) in accounts.iter().enumerate() {
+        if offsets[*index].is_some() {
+            // Duplicated accounts reference the position of the first
+            // occurrence of the account.
+            let first = accounts.iter().position(|i| i == index).unwrap();
+            input.write(&[first as u8, 0, 0, 0, 0, 0, 0, 0]);
+            continue;
+        }
+
+        debug_assert!(position < u8::MAX as usize);
+        let account = &universe[*index];
+        offsets[*index] = Some(input.len());
+
+        input.write(&[
+            NON_DUP_MARKER,
+            account.is_signer as u8,
+            account.is_writable as u8,
+            0, // executable
+        ]);
+        input.write(&0i32.to_le_bytes()); // resize delta
+        input.write(account.key.as_ref());
+        input.write(account.owner.as_ref());
+        input.write(&account.lamports.to_le_bytes());
+        input.write(&(account.data.len() as u64).to_le_bytes());
+        input.write(&account.data);
+        input.write(&[0; MAX_PERMITTED_DATA_INCREASE]);
+        input.align(size_of::<u64>());
+        input.write(&0u64.to_le_bytes()); // rent epoch
+    }
+
+    input.write(&(data.len() as u64).to_le_bytes());
+    input.write(data);
+    input.write(spl_token::ID.as_ref());
+
+    // SAFETY: the input buffer follows the layout expected by the entrypoint.
+    let result = unsafe { entrypoint(input.as_mut_ptr()) };
+
+    // Reads the accounts back, even if the instruction failed.
+    for (index, offset) in offsets.iter().enumerate() {
+        if let Some(offset) = offset {
+            let bytes = input.bytes();
+            let account = &mut universe[index];
+            account.owner =
+                Pubkey::new_from_array(bytes[offset + 40..offset + 72].try_into().unwrap());
+            account.lamports =
+                u64::from_le_bytes(bytes[offset + 72..offset + 80].try_into().unwrap());
+            let data_len = u64::from_le_bytes(bytes[offset + 80..offset + 88].try_into().unwrap());
+            let data = offset + ACCOUNT_HEADER_SIZE;
+            account.data = bytes[data..data + data_len as usize].to_vec();
+        }
+    }
+
+    match result {
+        0 => Ok(()),
+        error => Err(error),
+    }
+}
+
+/// Executes an instruction on `spl-token`, returning the error code on failure.
+pub fn process_spl_token(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    data: &[u8],
+) -> Result<(), u64> {
+    process_native(universe, accounts, |accounts| {
+        spl_token::processor::Processor::process(&spl_token::ID, accounts, data)
+    })
+}
+
+/// Executes a native processor on the accounts of the universe, returning the
+/// error code on failure.
+pub fn process_native(
+    universe: &mut [AccountState],
+    accounts: &[usize],
+    process: impl FnOnce(&[AccountInfo]) -> Result<(), ProgramError>,
+) -> Result<(), u64> {
+    let infos = universe
+        .iter_mut()
+        .map(|account| {
+            AccountInfo::new(
+                &account.key,
+                account.is_signer,
+                account.is_writable,
+                &mut account.lamports,
+                &mut account.data,
+                &account.owner,
+                false,
+                0,
+            )
+        })
+        .collect::<Vec<_>>();
+    // Duplicated accounts share the same lamports and data.
+    let accounts = accounts
+        .iter()
+        .map(|index| infos[*index].clone())
+        .collect::<Vec<_>>();
+
+    process(&accounts).map_err(u64::from)
+}
+
+/// Checks the rules that the runtime enforces at the end of an instruction,
+/// given the states of the accounts before (`pre`) and after (`post`) it.
+pub fn check_runtime_rules(pre: &[AccountState], post: &[AccountState]) -> Result<(), u64> {
+    let mut pre_lamports = 0u128;
+    let mut post_lamports = 0u128;
+
+    for (pre, post) in pre.iter().zip(post) {
+        pre_lamports += pre.lamports as u128;
+        post_lamports += post.lamports as u128;
+
+        let modified = pre.owner != post.owner || pre.data != post.data;
+
+        if (modified || pre.lamports != post.lamports) && !pre.is_writable {
+            return Err(RUNTIME_VIOLATION);
+        }
+
+        if (modified || post.lamports < pre.lamports) && pre.owner != spl_token::ID {
+            return Err(RUNTIME_VIOLATION);
+        }
+    }
+
+    if pre_lamports != post_lamports {
+        return Err(RUNTIME_VIOLATION);
+    }
+
+    Ok(())
+}
+
+/// Input buffer aligned to `u128`, as created by the SVM loader.
+#[derive(Default)]
+struct Input {
+    buffer: Vec<u128>,
+    len: usize,
+}
+
+impl Input {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        self.buffer.resize(end.div_ceil(size_of::<u128>()), 0);
+        let start = self.len;
+        self.bytes_mut()[start..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    fn align(&mut self, align: usize) {
+        let padding = self.len.next_multiple_of(align) - self.len;
+        self.write(&vec![0; padding]);
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buffer.as_mut_ptr() as *mut u8
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: the buffer has at least `len` bytes.
+        unsafe { std::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, self.len) }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: the buffer has at least `len` bytes after resizing.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buffer.as_mut_ptr() as *mut u8,
+                self.buffer.len() * size_of::<u128>(),
+            )
+        }
+    }
+}
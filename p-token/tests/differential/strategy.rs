@@ -0,0 +1,791 @@
+//! Strategies to generate account states and instructions.
+//!
+//! A test case is composed of a small "universe" of accounts and an
+//! instruction that references accounts of the universe by index. Each
+//! position of the universe has a preferred role (e.g., a mint or a token
+//! account) and account states usually reference the keys of other accounts
+//! of the universe, so that instructions have a reasonable chance of
+//! succeeding. Any position can still hold any kind of account, and
+//! instructions can reference any account.
+
+use {
+    crate::runtime::AccountState,
+    proptest::{collection::vec, prelude::*, sample::select},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_program_pack_v3::Pack as _,
+    solana_pubkey::Pubkey,
+    spl_token::{
+        native_mint,
+        state::{Account, AccountState as TokenAccountState, Mint, Multisig},
+    },
+    spl_token_interface::state::{DelegationRecord, Expiry, LargeMultisig},
+};
+
+/// Number of accounts in the universe.
+pub const UNIVERSE_LEN: usize = 8;
+
+/// Index of the mint in the universe.
+const MINT_INDEX: usize = 0;
+
+/// Indices of the token accounts in the universe.
+const ACCOUNT_INDICES: [usize; 2] = [1, 2];
+
+/// Index of the wallet used as authority in the universe.
+const WALLET_INDEX: usize = 3;
+
+/// Index of the multisig (or wallet) used as authority in the universe.
+const MULTISIG_INDEX: usize = 4;
+
+/// Index of the multisig (or other account) that can be a nested member of the
+/// multisig authority.
+const NESTED_MULTISIG_INDEX: usize = 5;
+
+/// Index of the native mint in the universe.
+const NATIVE_MINT_INDEX: usize = 6;
+
+/// Index of the rent sysvar in the universe.
+const RENT_INDEX: usize = 7;
+
+/// Maximum token amount of an account, so the sum of the amounts of all
+/// accounts in the universe cannot overflow.
+const MAX_AMOUNT: u64 = u64::MAX / UNIVERSE_LEN as u64;
+
+/// Maximum lamports of an account, so the sum of the lamports of all accounts
+/// in the universe cannot overflow.
+const MAX_LAMPORTS: u64 = u64::MAX / (2 * UNIVERSE_LEN as u64);
+
+/// Lengths of the account states of `spl-token`.
+const STATE_LENS: [usize; 3] = [Mint::LEN, Account::LEN, Multisig::LEN];
+
+/// Lengths of the account states that only `p-token` supports, which are not
+/// part of the universe.
+const P_TOKEN_STATE_LENS: [usize; 2] = [LargeMultisig::LEN, DelegationRecord::LEN];
+
+/// Discriminators of the non-batch instructions.
+const DISCRIMINATORS: &[u8] = &[
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 38,
+    252, 251, 250, 249,
+];
+
+/// Returns the key of the account at `index` in the universe.
+pub fn key(index: usize) -> Pubkey {
+    match index {
+        NATIVE_MINT_INDEX => native_mint::id(),
+        RENT_INDEX => solana_rent::sysvar::id(),
+        _ => Pubkey::new_from_array([index as u8 + 1; 32]),
+    }
+}
+
+/// Returns the minimum balance for an account to be rent exempt.
+fn minimum_balance(data_len: usize) -> u64 {
+    solana_rent::Rent::default().minimum_balance(data_len)
+}
+
+/// Instruction to be executed, referencing accounts of the universe by index.
+#[derive(Clone, Debug)]
+pub struct Ix {
+    pub data: Vec<u8>,
+    pub accounts: Vec<usize>,
+}
+
+/// Instruction generated for a test case.
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    /// A single (non-batch) instruction.
+    Single(Ix),
+
+    /// A batch instruction with its sequence of instructions.
+    ///
+    /// The accounts of each instruction are indices into the universe, even
+    /// for indexed batches.
+    Batch { discriminator: u8, items: Vec<Ix> },
+}
+
+impl Instruction {
+    /// Returns the instructions executed, i.e., the instruction itself or the
+    /// instructions of a batch.
+    pub fn items(&self) -> &[Ix] {
+        match self {
+            Instruction::Single(ix) => core::slice::from_ref(ix),
+            Instruction::Batch { items, .. } => items,
+        }
+    }
+
+    /// Returns the instruction data and accounts as expected by the programs.
+    pub fn encode(&self) -> Ix {
+        match self {
+            Instruction::Single(ix) => ix.clone(),
+            Instruction::Batch {
+                discriminator: 254,
+                items,
+            } => {
+                // Accounts of the batch are the unique accounts of all items.
+                let mut accounts = Vec::new();
+                let mut data = vec![254];
+
+                for item in items {
+                    data.push(item.accounts.len() as u8);
+                    data.push(item.data.len() as u8);
+
+                    for account in &item.accounts {
+                        let position =
+                            accounts
+                                .iter()
+                                .position(|a| a == account)
+                                .unwrap_or_else(|| {
+                                    accounts.push(*account);
+                                    accounts.len() - 1
+                                });
+                        data.push(position as u8);
+                    }
+
+                    data.extend_from_slice(&item.data);
+                }
+
+                Ix { data, accounts }
+            }
+            Instruction::Batch {
+                discriminator,
+                items,
+            } => {
+                let mut accounts = Vec::new();
+                let mut data = vec![*discriminator];
+
+                for item in items {
+                    data.push(item.accounts.len() as u8);
+                    data.push(item.data.len() as u8);
+                    data.extend_from_slice(&item.data);
+                    accounts.extend_from_slice(&item.accounts);
+                }
+
+                Ix { data, accounts }
+            }
+        }
+    }
+}
+
+/// A test case: the initial state of the universe and an instruction.
+#[derive(Clone, Debug)]
+pub struct TestCase {
+    pub universe: Vec<AccountState>,
+    pub instruction: Instruction,
+}
+
+/// Kind of account in the universe.
+#[derive(Clone, Debug)]
+enum Kind {
+    Mint(Mint),
+    Account(Account),
+    Multisig(Multisig),
+    LargeMultisig(Box<LargeMultisig>),
+    DelegationRecord(DelegationRecord),
+    Uninitialized(usize),
+    Wallet,
+    Foreign(Vec<u8>),
+}
+
+/// Generates a key, usually of an account in the universe.
+fn key_strategy() -> impl Strategy<Value = Pubkey> {
+    prop_oneof![
+        9 => (0..UNIVERSE_LEN).prop_map(key),
+        1 => any::<[u8; 32]>().prop_map(Pubkey::new_from_array),
+    ]
+}
+
+/// Generates the key of an authority, usually the wallet or the multisig.
+fn authority_key_strategy() -> impl Strategy<Value = Pubkey> {
+    prop_oneof![
+        6 => Just(key(WALLET_INDEX)),
+        3 => Just(key(MULTISIG_INDEX)),
+        1 => key_strategy(),
+    ]
+}
+
+/// Generates the key of a mint, usually the mint or the native mint.
+fn mint_key_strategy() -> impl Strategy<Value = Pubkey> {
+    prop_oneof![
+        8 => Just(key(MINT_INDEX)),
+        1 => key_strategy(),
+    ]
+}
+
+fn option_authority_key_strategy() -> impl Strategy<Value = COption<Pubkey>> {
+    prop_oneof![
+        1 => Just(COption::None),
+        3 => authority_key_strategy().prop_map(COption::Some),
+    ]
+}
+
+fn amount_strategy() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        2 => Just(0),
+        2 => Just(1),
+        6 => 0..100u64,
+        2 => 0..1_000u64,
+        1 => Just(u64::MAX),
+        1 => any::<u64>(),
+    ]
+}
+
+fn decimals_strategy() -> impl Strategy<Value = u8> {
+    prop_oneof![
+        8 => select(vec![0u8, 6, 9]),
+        1 => any::<u8>(),
+    ]
+}
+
+fn mint_strategy() -> impl Strategy<Value = Kind> {
+    (
+        option_authority_key_strategy(),
+        prop_oneof![0..1_000_000u64, 0..=MAX_AMOUNT],
+        decimals_strategy(),
+        option_authority_key_strategy(),
+    )
+        .prop_map(|(mint_authority, supply, decimals, freeze_authority)| {
+            Kind::Mint(Mint {
+                mint_authority,
+                supply,
+                decimals,
+                is_initialized: true,
+                freeze_authority,
+            })
+        })
+}
+
+fn account_strategy() -> impl Strategy<Value = Kind> {
+    (
+        mint_key_strategy(),
+        authority_key_strategy(),
+        prop_oneof![4 => 0..1_000u64, 1 => 0..=MAX_AMOUNT],
+        prop_oneof![
+            4 => option_authority_key_strategy(),
+            1 => Just(COption::Some(key(NESTED_MULTISIG_INDEX))),
+        ],
+        prop_oneof![
+            5 => Just(TokenAccountState::Initialized),
+            1 => Just(TokenAccountState::Frozen),
+        ],
+        prop_oneof![4 => Just(false), 1 => Just(true)],
+        prop_oneof![4 => 0..1_000u64, 1 => 0..=MAX_AMOUNT],
+        option_authority_key_strategy(),
+    )
+        .prop_map(
+            |(mint, owner, amount, delegate, state, is_native, delegated_amount, close)| {
+                Kind::Account(Account {
+                    mint: if is_native { native_mint::id() } else { mint },
+                    owner,
+                    amount,
+                    delegated_amount: if delegate.is_some() {
+                        delegated_amount
+                    } else {
+                        0
+                    },
+                    delegate,
+                    state,
+                    // The rent-exempt reserve is set when the lamports are
+                    // generated.
+                    is_native: if is_native {
+                        COption::Some(0)
+                    } else {
+                        COption::None
+                    },
+                    close_authority: close,
+                })
+            },
+        )
+}
+
+/// Generates the threshold, number of signers and the first three signers of
+/// a multisig.
+fn multisig_signers_strategy() -> impl Strategy<Value = (u8, u8, Vec<Pubkey>)> {
+    (
+        prop_oneof![3 => Just(1u8), 1 => 1..=3u8],
+        1..=3u8,
+        vec(
+            prop_oneof![
+                3 => Just(key(WALLET_INDEX)),
+                2 => Just(key(NESTED_MULTISIG_INDEX)),
+                1 => select(vec![key(1), key(2)]),
+                1 => key_strategy(),
+            ],
+            3,
+        ),
+    )
+}
+
+fn multisig_strategy() -> impl Strategy<Value = Kind> {
+    multisig_signers_strategy().prop_map(|(m, n, keys)| {
+        let mut signers = [Pubkey::default(); 11];
+        signers[..3].copy_from_slice(&keys);
+        Kind::Multisig(Multisig {
+            m,
+            n,
+            is_initialized: true,
+            signers,
+        })
+    })
+}
+
+fn large_multisig_strategy() -> impl Strategy<Value = Kind> {
+    (multisig_signers_strategy(), proptest::bool::weighted(0.8)).prop_map(
+        |((m, n, keys), allow_nested)| {
+            let mut large_multisig = LargeMultisig {
+                m,
+                n,
+                is_initialized: true,
+                allow_nested,
+                ..LargeMultisig::default()
+            };
+            for (signer, key) in large_multisig.signers.iter_mut().zip(keys) {
+                *signer = key.to_bytes().into();
+            }
+            Kind::LargeMultisig(Box::new(large_multisig))
+        },
+    )
+}
+
+fn expiry_strategy() -> impl Strategy<Value = Expiry> {
+    prop_oneof![
+        any::<u64>().prop_map(Expiry::Slot),
+        any::<i64>().prop_map(Expiry::UnixTimestamp),
+    ]
+}
+
+fn delegation_record_strategy() -> impl Strategy<Value = Kind> {
+    (
+        prop_oneof![
+            4 => select(ACCOUNT_INDICES.to_vec()).prop_map(key),
+            1 => key_strategy(),
+        ],
+        authority_key_strategy(),
+        expiry_strategy(),
+    )
+        .prop_map(|(account, delegate, expiry)| {
+            Kind::DelegationRecord(DelegationRecord {
+                account: account.to_bytes().into(),
+                delegate: delegate.to_bytes().into(),
+                expiry,
+                is_initialized: true,
+            })
+        })
+}
+
+/// Generates the kind of account at `index` in the universe, favouring the
+/// role of the position.
+fn kind_strategy(index: usize) -> impl Strategy<Value = Kind> {
+    let (mint, account, multisig, uninitialized, wallet) = match index {
+        MINT_INDEX | NATIVE_MINT_INDEX => (12, 1, 1, 1, 1),
+        1 | 2 => (1, 24, 1, 1, 1),
+        WALLET_INDEX => (1, 1, 1, 1, 12),
+        MULTISIG_INDEX => (1, 1, 6, 1, 6),
+        NESTED_MULTISIG_INDEX => (2, 2, 6, 2, 2),
+        _ => (2, 2, 2, 6, 2),
+    };
+
+    prop_oneof![
+        mint => mint_strategy(),
+        account => account_strategy(),
+        multisig => multisig_strategy(),
+        uninitialized => select(STATE_LENS.to_vec()).prop_map(Kind::Uninitialized),
+        wallet => Just(Kind::Wallet),
+        1 => vec(any::<u8>(), 0..200)
+            .prop_filter("token account length", |data| {
+                !STATE_LENS.contains(&data.len()) && !P_TOKEN_STATE_LENS.contains(&data.len())
+            })
+            .prop_map(Kind::Foreign),
+    ]
+}
+
+/// Generates the accounts of the universe.
+fn universe_strategy() -> impl Strategy<Value = Vec<AccountState>> {
+    let kinds = (0..RENT_INDEX).map(kind_strategy).collect::<Vec<_>>();
+
+    let flags = (0..UNIVERSE_LEN)
+        .map(|index| {
+            let signer = if index == WALLET_INDEX { 0.8 } else { 0.3 };
+            (
+                proptest::bool::weighted(signer),
+                proptest::bool::weighted(0.9),
+                prop_oneof![4 => Just(0), 1 => 0..=1_000_000u64],
+                prop_oneof![
+                    24 => Just(spl_token::ID),
+                    1 => any::<[u8; 32]>().prop_map(Pubkey::new_from_array),
+                ],
+                proptest::bool::weighted(0.9),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    (kinds, flags).prop_map(|(mut kinds, flags)| {
+        fix_amounts(&mut kinds);
+
+        let mut universe = kinds
+            .into_iter()
+            .zip(flags.iter())
+            .enumerate()
+            .map(
+                |(index, (kind, (is_signer, is_writable, extra, owner, rent_exempt)))| {
+                    let (owner, mut data) = match &kind {
+                        Kind::Mint(mint) => (*owner, pack(mint)),
+                        Kind::Account(account) => (*owner, pack(account)),
+                        Kind::Multisig(multisig) => (*owner, pack(multisig)),
+                        Kind::LargeMultisig(multisig) => (*owner, pack_v3(multisig.as_ref())),
+                        Kind::DelegationRecord(record) => (*owner, pack_v3(record)),
+                        Kind::Uninitialized(len) => (*owner, vec![0; *len]),
+                        Kind::Wallet => (Pubkey::default(), Vec::new()),
+                        Kind::Foreign(data) => (*owner, data.clone()),
+                    };
+
+                    let mut lamports = if *rent_exempt {
+                        minimum_balance(data.len()) + extra
+                    } else {
+                        *extra
+                    };
+
+                    if let Kind::Account(mut account) = kind {
+                        if account.is_native.is_some() {
+                            let reserve = minimum_balance(Account::LEN);
+                            account.is_native = COption::Some(reserve);
+                            lamports = reserve + account.amount + extra;
+                            data = pack(&account);
+                        }
+                    }
+
+                    AccountState {
+                        key: key(index),
+                        owner,
+                        lamports: lamports.min(MAX_LAMPORTS),
+                        data,
+                        is_signer: *is_signer,
+                        is_writable: *is_writable,
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+
+        universe.push(AccountState {
+            key: key(RENT_INDEX),
+            owner: Pubkey::from_str_const("Sysvar1111111111111111111111111111111111111"),
+            lamports: 1,
+            data: rent_data(),
+            is_signer: flags[RENT_INDEX].0,
+            is_writable: false,
+        });
+
+        universe
+    })
+}
+
+/// Adjusts the token amounts so that the programs cannot overflow when
+/// updating amounts.
+///
+/// The supply of a mint is always greater than or equal to the sum of the
+/// amounts of its token accounts; accounts of native tokens have a bounded
+/// amount, and accounts of other tokens without a mint in the universe have
+/// no tokens.
+fn fix_amounts(kinds: &mut [Kind]) {
+    let mints = kinds
+        .iter()
+        .enumerate()
+        .filter(|(_, kind)| matches!(kind, Kind::Mint(_)))
+        .map(|(index, _)| key(index))
+        .collect::<Vec<_>>();
+
+    for kind in kinds.iter_mut() {
+        if let Kind::Account(account) = kind {
+            if account.is_native.is_some() {
+                account.amount %= 1_000_000_000;
+            } else if !mints.contains(&account.mint) {
+                account.amount = 0;
+            }
+        }
+    }
+
+    for mint_key in mints {
+        let total = kinds
+            .iter()
+            .filter_map(|kind| match kind {
+                Kind::Account(account) if account.mint == mint_key => Some(account.amount),
+                _ => None,
+            })
+            .sum::<u64>();
+
+        let index = (0..UNIVERSE_LEN)
+            .find(|index| key(*index) == mint_key)
+            .unwrap();
+
+        if let Kind::Mint(mint) = &mut kinds[index] {
+            mint.supply = mint.supply.max(total);
+        }
+    }
+}
+
+fn pack<T: Pack>(value: &T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    value.pack_into_slice(&mut data);
+    data
+}
+
+/// Like [`pack`], for the large multisig and delegation record of the token
+/// interface, which implement `Pack` of a newer version of the crate.
+fn pack_v3<T: solana_program_pack_v3::Pack>(value: &T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    value.pack_into_slice(&mut data);
+    data
+}
+
+/// Generates the data of a mint, token account, multisig or delegation record,
+/// possibly uninitialized, with up to two bytes overwritten to produce
+/// malformed fields.
+pub fn state_data_strategy() -> impl Strategy<Value = Vec<u8>> {
+    (
+        prop_oneof![
+            mint_strategy(),
+            account_strategy(),
+            multisig_strategy(),
+            large_multisig_strategy(),
+            delegation_record_strategy(),
+            select([&STATE_LENS[..], &P_TOKEN_STATE_LENS].concat()).prop_map(Kind::Uninitialized),
+        ],
+        vec(
+            (
+                any::<prop::sample::Index>(),
+                prop_oneof![select(vec![0u8, 1, 2]), any::<u8>()],
+            ),
+            0..=2,
+        ),
+    )
+        .prop_map(|(kind, writes)| {
+            let mut data = match kind {
+                Kind::Mint(mint) => pack(&mint),
+                Kind::Account(account) => pack(&account),
+                Kind::Multisig(multisig) => pack(&multisig),
+                Kind::LargeMultisig(multisig) => pack_v3(multisig.as_ref()),
+                Kind::DelegationRecord(record) => pack_v3(&record),
+                Kind::Uninitialized(len) => vec![0; len],
+                _ => unreachable!(),
+            };
+            for (index, value) in writes {
+                let index = index.index(data.len());
+                data[index] = value;
+            }
+            data
+        })
+}
+
+/// Serialized `Rent` sysvar with the default values.
+fn rent_data() -> Vec<u8> {
+    let rent = solana_rent::Rent::default();
+    let mut data = Vec::with_capacity(17);
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+fn option_key_data_strategy() -> impl Strategy<Value = Vec<u8>> {
+    option_authority_key_strategy().prop_map(|key| match key {
+        COption::Some(key) => [&[1][..], key.as_ref()].concat(),
+        COption::None => vec![0],
+    })
+}
+
+fn ui_amount_strategy() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        4 => "[0-9]{0,12}(\\.[0-9]{0,12})?".prop_map(String::into_bytes),
+        1 => vec(any::<u8>(), 0..24),
+    ]
+}
+
+/// Generates the instruction data for the given discriminator.
+fn data_strategy(discriminator: u8) -> BoxedStrategy<Vec<u8>> {
+    let payload = match discriminator {
+        // InitializeMint, InitializeMint2
+        0 | 20 => (
+            decimals_strategy(),
+            authority_key_strategy(),
+            option_key_data_strategy(),
+        )
+            .prop_map(|(decimals, authority, freeze)| {
+                [&[decimals][..], authority.as_ref(), &freeze].concat()
+            })
+            .boxed(),
+        // InitializeMultisig, InitializeMultisig2
+        2 | 19 => (0..=4u8).prop_map(|m| vec![m]).boxed(),
+        // Transfer, Approve, MintTo, Burn, AmountToUiAmount
+        3 | 4 | 7 | 8 | 23 => amount_strategy()
+            .prop_map(|amount| amount.to_le_bytes().to_vec())
+            .boxed(),
+        // SetAuthority
+        6 => (0..=4u8, option_key_data_strategy())
+            .prop_map(|(authority_type, key)| [&[authority_type][..], &key].concat())
+            .boxed(),
+        // TransferChecked, ApproveChecked, MintToChecked, BurnChecked
+        12..=15 => (amount_strategy(), decimals_strategy())
+            .prop_map(|(amount, decimals)| [&amount.to_le_bytes()[..], &[decimals]].concat())
+            .boxed(),
+        // InitializeAccount2, InitializeAccount3
+        16 | 18 => authority_key_strategy()
+            .prop_map(|key| key.as_ref().to_vec())
+            .boxed(),
+        // UiAmountToAmount
+        24 => ui_amount_strategy().boxed(),
+        // TransferMany, MintToMany
+        252 | 251 => (decimals_strategy(), vec(amount_strategy(), 1..=3))
+            .prop_map(|(decimals, amounts)| {
+                let mut data = vec![decimals];
+                amounts
+                    .iter()
+                    .for_each(|amount| data.extend_from_slice(&amount.to_le_bytes()));
+                data
+            })
+            .boxed(),
+        _ => Just(Vec::new()).boxed(),
+    };
+
+    prop_oneof![
+        // Structured instruction data.
+        9 => payload.prop_map(move |payload| [&[discriminator][..], &payload].concat()),
+        // Random instruction data with the same discriminator.
+        1 => vec(any::<u8>(), 0..48)
+            .prop_map(move |payload| [&[discriminator][..], &payload].concat()),
+    ]
+    .boxed()
+}
+
+/// Role of an account expected by an instruction.
+#[derive(Clone, Copy)]
+enum Role {
+    Any,
+    Mint,
+    Account,
+    Authority,
+    Rent,
+}
+
+fn role_strategy(role: Role) -> BoxedStrategy<usize> {
+    let any = 0..UNIVERSE_LEN;
+
+    match role {
+        Role::Any => any.boxed(),
+        Role::Mint => prop_oneof![
+            6 => Just(MINT_INDEX),
+            1 => Just(NATIVE_MINT_INDEX),
+            1 => any,
+        ]
+        .boxed(),
+        Role::Account => prop_oneof![
+            9 => select(ACCOUNT_INDICES.to_vec()),
+            1 => any,
+        ]
+        .boxed(),
+        Role::Authority => prop_oneof![
+            6 => Just(WALLET_INDEX),
+            3 => Just(MULTISIG_INDEX),
+            1 => Just(NESTED_MULTISIG_INDEX),
+            1 => any,
+        ]
+        .boxed(),
+        Role::Rent => prop_oneof![8 => Just(RENT_INDEX), 1 => any].boxed(),
+    }
+}
+
+/// Generates the accounts of an instruction with the given discriminator.
+fn accounts_strategy(discriminator: u8) -> BoxedStrategy<Vec<usize>> {
+    use Role::*;
+
+    let roles: &[Role] = match discriminator {
+        0 => &[Any, Rent],
+        1 => &[Any, Mint, Authority, Rent],
+        2 => &[Any, Rent],
+        3 => &[Account, Account, Authority],
+        4 => &[Account, Any, Authority],
+        5 => &[Account, Authority],
+        6 => &[Any, Authority],
+        7 | 14 => &[Mint, Account, Authority],
+        8 | 10 | 11 | 15 => &[Account, Mint, Authority],
+        9 => &[Account, Any, Authority],
+        12 => &[Account, Mint, Account, Authority],
+        13 => &[Account, Mint, Any, Authority],
+        16 => &[Any, Mint, Rent],
+        17 | 20 | 22 => &[Any],
+        18 => &[Any, Mint],
+        19 => &[Any],
+        21 | 23 | 24 | 249 => &[Mint],
+        38 => &[Any, Any, Authority],
+        // Destination accounts are generated with the signers.
+        252 => &[Account, Mint, Authority],
+        251 => &[Mint, Authority],
+        250 => &[Account],
+        _ => &[],
+    };
+
+    let fixed = roles
+        .iter()
+        .map(|role| role_strategy(*role))
+        .collect::<Vec<_>>();
+
+    // Signers of a multisig, or the destinations of `TransferMany` and
+    // `MintToMany`, where any account after the destinations is a signer.
+    let remaining = if matches!(discriminator, 252 | 251) {
+        vec(role_strategy(Account), 3).boxed()
+    } else {
+        prop_oneof![
+            3 => Just(Vec::new()),
+            // A nested multisig followed by its signer.
+            1 => Just(vec![NESTED_MULTISIG_INDEX, WALLET_INDEX]),
+            2 => vec(
+                prop_oneof![
+                    3 => Just(WALLET_INDEX),
+                    2 => select(vec![1usize, 2, NESTED_MULTISIG_INDEX]),
+                    1 => 0..UNIVERSE_LEN,
+                ],
+                1..=3,
+            ),
+        ]
+        .boxed()
+    };
+
+    prop_oneof![
+        9 => (fixed, remaining).prop_map(|(fixed, remaining)| [fixed, remaining].concat()),
+        // Random accounts.
+        1 => vec(0..UNIVERSE_LEN, 0..=7),
+    ]
+    .boxed()
+}
+
+/// Generates a non-batch instruction.
+fn ix_strategy() -> impl Strategy<Value = Ix> {
+    select(DISCRIMINATORS.to_vec()).prop_flat_map(|discriminator| {
+        (
+            data_strategy(discriminator),
+            accounts_strategy(discriminator),
+        )
+            .prop_map(|(data, accounts)| Ix { data, accounts })
+    })
+}
+
+/// Generates an instruction of a test case.
+fn instruction_strategy() -> impl Strategy<Value = Instruction> {
+    prop_oneof![
+        8 => ix_strategy().prop_map(Instruction::Single),
+        // Random instruction data with any discriminator.
+        1 => (vec(any::<u8>(), 0..48), vec(0..UNIVERSE_LEN, 0..=7))
+            .prop_map(|(data, accounts)| Instruction::Single(Ix { data, accounts })),
+        3 => (select(vec![253u8, 254, 255]), vec(ix_strategy(), 1..=3)).prop_map(
+            |(discriminator, items)| Instruction::Batch {
+                discriminator,
+                items,
+            }
+        ),
+    ]
+}
+
+/// Generates a test case.
+pub fn test_case_strategy() -> impl Strategy<Value = TestCase> {
+    (universe_strategy(), instruction_strategy()).prop_map(|(universe, instruction)| TestCase {
+        universe,
+        instruction,
+    })
+}
@@ -0,0 +1,63 @@
+//! Native execution of the program entrypoint, which requires `p-token` to
+//! also be built as a Rust library.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+use {
+    pinocchio_token_interface::error::TokenError, pinocchio_token_program as _,
+    solana_program_error::ProgramError, solana_pubkey::Pubkey, std::ptr::copy_nonoverlapping,
+};
+
+extern "C" {
+    /// Program entrypoint of `p-token`.
+    fn entrypoint(input: *mut u8) -> u64;
+}
+
+/// Native implementation of the `sol_memcpy_` syscall used by `p-token`.
+///
+/// # Safety
+///
+/// `dst` and `src` must be valid for `n` bytes and must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn sol_memcpy_(dst: *mut u8, src: *const u8, n: u64) {
+    copy_nonoverlapping(src, dst, n as usize);
+}
+
+/// Executes the entrypoint without accounts and returns its result.
+fn execute(data: &[u8]) -> u64 {
+    // The input buffer is backed by `u64` values to satisfy the alignment
+    // expected by the entrypoint.
+    let mut input = vec![0u64; 2 + data.len().div_ceil(8) + 4];
+    let bytes = input.as_mut_ptr() as *mut u8;
+
+    // SAFETY: the buffer is large enough for the number of accounts, the
+    // instruction data and the program id.
+    unsafe {
+        bytes.add(8).cast::<u64>().write(data.len() as u64);
+        bytes
+            .add(16)
+            .copy_from_nonoverlapping(data.as_ptr(), data.len());
+        bytes
+            .add(16 + data.len())
+            .copy_from_nonoverlapping(Pubkey::new_unique().as_ref().as_ptr(), 32);
+
+        entrypoint(bytes)
+    }
+}
+
+#[test]
+fn entrypoint_without_instruction_data() {
+    assert_eq!(
+        execute(&[]),
+        u64::from(ProgramError::Custom(TokenError::InvalidInstruction as u32))
+    );
+}
+
+#[test]
+fn entrypoint_without_accounts() {
+    // `GetAccountDataSize` expects the mint account.
+    assert_eq!(
+        execute(&[21]),
+        u64::from(ProgramError::NotEnoughAccountKeys)
+    );
+}
@@ -0,0 +1,127 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::{query::Balance, state::account_state::AccountState},
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+fn get_balance_instruction(account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: spl_token::ID,
+        accounts: vec![AccountMeta::new_readonly(*account, false)],
+        data: vec![250],
+    }
+}
+
+#[tokio::test]
+async fn get_balance() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens and a delegate for 40 of them.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let delegate = Pubkey::new_unique();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate,
+        &owner,
+        40,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When we get the balance of the token account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_balance_instruction(&account)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the return data has the balance of the account.
+
+    assert!(matches!(simulation.result, Some(Ok(()))));
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, TOKEN_PROGRAM_ID);
+    assert_eq!(
+        Balance::from_bytes(&return_data.data).unwrap(),
+        Balance {
+            amount: 100,
+            delegate: Some(delegate.to_bytes()),
+            delegated_amount: 40,
+            state: AccountState::Initialized,
+        }
+    );
+}
+
+#[tokio::test]
+async fn get_balance_with_mint() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &TOKEN_PROGRAM_ID)
+        .await
+        .unwrap();
+
+    // When we get the balance of the mint.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_balance_instruction(&mint)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the transaction fails, since the mint is not a token account.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
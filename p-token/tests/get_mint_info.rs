@@ -0,0 +1,105 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::query::MintInfo,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+fn get_mint_info_instruction(mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: spl_token::ID,
+        accounts: vec![AccountMeta::new_readonly(*mint, false)],
+        data: vec![249],
+    }
+}
+
+#[tokio::test]
+async fn get_mint_info() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When we get the mint information.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_mint_info_instruction(&mint)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the return data has the supply, decimals and authorities of the
+    // mint.
+
+    assert!(matches!(simulation.result, Some(Ok(()))));
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(return_data.program_id, TOKEN_PROGRAM_ID);
+    assert_eq!(
+        MintInfo::from_bytes(&return_data.data).unwrap(),
+        MintInfo {
+            supply: 0,
+            decimals: 4,
+            mint_authority: Some(mint_authority.to_bytes()),
+            freeze_authority: Some(freeze_authority.to_bytes()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn get_mint_info_with_token_account() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account.
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &TOKEN_PROGRAM_ID)
+        .await
+        .unwrap();
+
+    let account = account::initialize(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When we get the mint information of the token account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_mint_info_instruction(&account)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the transaction fails, since the token account is not a mint.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
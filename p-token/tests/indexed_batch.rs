@@ -0,0 +1,228 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+mod setup;
+
+use {
+    assert_matches::assert_matches,
+    pinocchio_token_interface::instruction::MAX_INDEXED_ACCOUNTS,
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, BanksClientError, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+};
+
+fn indexed_batch_instruction(instructions: Vec<Instruction>) -> Result<Instruction, ProgramError> {
+    // Create a `Vec` of unique `AccountMeta`s
+    let mut accounts: Vec<AccountMeta> = vec![];
+    // Start with the indexed batch discriminator
+    let mut data: Vec<u8> = vec![0xfe];
+
+    for instruction in instructions {
+        // Error out on non-token IX.
+        if instruction.program_id.ne(&spl_token::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        data.push(instruction.accounts.len() as u8);
+        data.push(instruction.data.len() as u8);
+
+        for meta in instruction.accounts {
+            let index = match accounts.iter().position(|a| a.pubkey == meta.pubkey) {
+                Some(index) => {
+                    accounts[index].is_signer |= meta.is_signer;
+                    accounts[index].is_writable |= meta.is_writable;
+                    index
+                }
+                None => {
+                    accounts.push(meta);
+                    accounts.len() - 1
+                }
+            };
+            data.push(index as u8);
+        }
+
+        data.extend_from_slice(&instruction.data);
+    }
+
+    Ok(Instruction {
+        program_id: spl_token::ID,
+        data,
+        accounts,
+    })
+}
+
+#[tokio::test]
+async fn indexed_batch() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And 10 destination token accounts.
+
+    let mut destinations = Vec::with_capacity(10);
+
+    for _ in 0..10 {
+        destinations.push(
+            account::initialize(
+                &mut context,
+                &mint,
+                &Pubkey::new_unique(),
+                &TOKEN_PROGRAM_ID,
+            )
+            .await,
+        );
+    }
+
+    // When we transfer 10 tokens to each destination sharing the source,
+    // mint and authority accounts.
+
+    let transfers = destinations
+        .iter()
+        .map(|destination| {
+            spl_token::instruction::transfer_checked(
+                &spl_token::ID,
+                &account,
+                &mint,
+                destination,
+                &owner.pubkey(),
+                &[],
+                10,
+                4,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let batch_ix = indexed_batch_instruction(transfers).unwrap();
+    // source, mint, owner and destinations.
+    assert_eq!(batch_ix.accounts.len(), 13);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are moved to all destinations.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 0);
+
+    for destination in destinations {
+        let destination = context.banks_client.get_account(destination).await.unwrap();
+
+        assert!(destination.is_some());
+
+        let destination = destination.unwrap();
+        let destination = spl_token::state::Account::unpack(&destination.data).unwrap();
+
+        assert!(destination.amount == 10);
+    }
+}
+
+#[tokio::test]
+async fn indexed_batch_account_limit() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &TOKEN_PROGRAM_ID)
+        .await
+        .unwrap();
+
+    // And a `GetAccountDataSize` instruction that references the mint as many
+    // times as the limit, which ignores the extra accounts.
+
+    let get_account_data_size = |accounts: usize| {
+        let mut instruction =
+            spl_token::instruction::get_account_data_size(&spl_token::ID, &mint).unwrap();
+        instruction.accounts = vec![AccountMeta::new_readonly(mint, false); accounts];
+        instruction
+    };
+
+    // When it is executed in an indexed batch, it succeeds.
+
+    let batch_ix =
+        indexed_batch_instruction(vec![get_account_data_size(MAX_INDEXED_ACCOUNTS)]).unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When it references one more account, the batch fails.
+
+    let batch_ix =
+        indexed_batch_instruction(vec![get_account_data_size(MAX_INDEXED_ACCOUNTS + 1)]).unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(12) // TokenError::InvalidInstruction
+        ))
+    );
+}
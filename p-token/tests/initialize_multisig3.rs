@@ -0,0 +1,183 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::{
+        instruction::TokenInstruction,
+        state::{
+            load,
+            multisig::{LargeMultisig, MAX_LARGE_SIGNERS},
+            Initializable, Transmutable,
+        },
+    },
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest, ProgramTestContext},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
+    solana_transaction::Transaction,
+};
+
+/// Creates and initializes a large multisig account with the given signers.
+async fn initialize_large_multisig(
+    context: &mut ProgramTestContext,
+    signers: &[Pubkey],
+    m: u8,
+    allow_nested: bool,
+) -> Pubkey {
+    let multisig = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let initialize_ix = Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: [AccountMeta::new(multisig.pubkey(), false)]
+            .into_iter()
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(*signer, false)),
+            )
+            .collect(),
+        data: vec![
+            TokenInstruction::InitializeMultisig3 as u8,
+            m,
+            allow_nested as u8,
+        ],
+    };
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(LargeMultisig::LEN),
+            LargeMultisig::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    multisig.pubkey()
+}
+
+#[tokio::test]
+async fn initialize_multisig3() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given the maximum number of signers.
+
+    let signers = (0..MAX_LARGE_SIGNERS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+
+    // When a new large multisig account is created and initialized.
+
+    let multisig = initialize_large_multisig(&mut context, &signers, 20, true).await;
+
+    // Then the multisig has the correct data.
+
+    let account = context.banks_client.get_account(multisig).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let multisig = unsafe { load::<LargeMultisig>(&account.data).unwrap() };
+
+    assert!(multisig.is_initialized().unwrap());
+    assert_eq!(multisig.n, MAX_LARGE_SIGNERS);
+    assert_eq!(multisig.m, 20);
+    assert!(multisig.allows_nested());
+    assert!(multisig
+        .signers
+        .iter()
+        .copied()
+        .eq(signers.iter().map(|signer| signer.to_bytes())));
+}
+
+#[tokio::test]
+async fn transfer_with_large_multisig_owner() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a large multisig with 2 of 20 signers.
+
+    let signers = (0..20).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let signer_keys = signers.iter().map(Keypair::pubkey).collect::<Vec<_>>();
+
+    let multisig = initialize_large_multisig(&mut context, &signer_keys, 2, false).await;
+
+    // And a token account owned by the multisig with 100 tokens.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &multisig, &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When the tokens are transferred with the last two signers.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &destination, &TOKEN_PROGRAM_ID).await;
+
+    let transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &destination_account,
+        &multisig,
+        &[&signer_keys[18], &signer_keys[19]],
+        100,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[18], &signers[19]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are transferred.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 0);
+}
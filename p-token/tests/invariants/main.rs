@@ -0,0 +1,207 @@
+//! State-machine tests of the token invariants.
+//!
+//! Each test case is a random sequence of operations (transfers, approvals,
+//! mints, burns, freezes, closes and batches of them) that are executed on
+//! `p-token` through its `process_instruction` entrypoint. After each
+//! operation, the following invariants are checked:
+//!
+//! - the sum of the balances of the accounts of a mint equals its supply;
+//! - a delegate cannot spend more than its delegated amount, which is reduced
+//!   by the amount spent;
+//! - native accounts keep their rent-exempt reserve;
+//! - the balance of frozen accounts does not change;
+//! - the rules enforced by the runtime, e.g., lamports are conserved.
+//!
+//! Note that the delegated amount of an account can exceed its balance, since
+//! `Approve` does not check the balance of the account and the owner can still
+//! spend the delegated tokens.
+//!
+//! The number of test cases can be increased with the `PROPTEST_CASES`
+//! environment variable:
+//!
+//! ```text
+//! PROPTEST_CASES=10000 cargo test -p pinocchio-token-program --test invariants
+//! ```
+
+#![allow(clippy::arithmetic_side_effects)]
+
+mod model;
+#[path = "../differential/runtime.rs"]
+#[allow(dead_code)]
+mod runtime;
+
+use {
+    model::{Op, ACCOUNT_INDICES, NON_NATIVE_MINT_INDICES},
+    pinocchio_token_program as _,
+    proptest::{collection::vec, prelude::*, test_runner::TestCaseError},
+    runtime::{check_runtime_rules, process_p_token, AccountState},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    spl_token::state::{Account, AccountState as TokenAccountState, Mint},
+};
+
+/// Maximum number of operations of a test sequence.
+const MAX_OPS: usize = 32;
+
+proptest! {
+    #[test]
+    fn invariants(ops in vec(model::op_strategy(), 1..=MAX_OPS)) {
+        let mut universe = model::universe();
+        check_invariants(&universe, &universe)?;
+
+        for op in &ops {
+            step(&mut universe, op)?;
+        }
+    }
+}
+
+/// Executes an operation and checks the invariants.
+fn step(universe: &mut [AccountState], op: &Op) -> Result<(), TestCaseError> {
+    let pre = universe.to_vec();
+
+    let Some(ix) = op.instruction() else {
+        if let Op::Deposit { account, lamports } = op {
+            model::deposit(universe, *account, *lamports);
+        }
+        return check_invariants(&pre, universe);
+    };
+
+    match process_p_token(universe, &ix.accounts, &ix.data) {
+        Ok(()) => {
+            // Closing accounts is a no-op outside of the SVM, so it is
+            // emulated once the instruction succeeded.
+            if let Op::Close { account, .. } = op {
+                universe[*account].close();
+            }
+
+            prop_assert_eq!(
+                check_runtime_rules(&pre, universe),
+                Ok(()),
+                "runtime rules violated by {:?}",
+                op
+            );
+            check_delegated_spending(&pre, universe, op)?;
+            check_invariants(&pre, universe)
+        }
+        Err(_) => {
+            // The runtime discards the changes of a failed instruction.
+            universe.clone_from_slice(&pre);
+            Ok(())
+        }
+    }
+}
+
+/// Returns the token account at `index`, unless it was closed.
+fn token_account(universe: &[AccountState], index: usize) -> Option<Account> {
+    Account::unpack(&universe[index].data).ok()
+}
+
+/// Checks that an operation signed by the delegate of its source account
+/// spends at most the delegated amount, which is reduced by the amount spent.
+fn check_delegated_spending(
+    pre: &[AccountState],
+    post: &[AccountState],
+    op: &Op,
+) -> Result<(), TestCaseError> {
+    let (source, authority) = match op {
+        Op::Transfer {
+            source, authority, ..
+        }
+        | Op::TransferMany {
+            source, authority, ..
+        }
+        | Op::Burn {
+            account: source,
+            authority,
+            ..
+        } => (*source, authority),
+        _ => return Ok(()),
+    };
+
+    let (Some(previous), Some(account)) = (token_account(pre, source), token_account(post, source))
+    else {
+        return Ok(());
+    };
+
+    if previous.delegate == COption::Some(authority.key()) {
+        let spent = previous.amount.checked_sub(account.amount);
+
+        prop_assert_eq!(
+            spent.and_then(|spent| previous.delegated_amount.checked_sub(spent)),
+            Some(account.delegated_amount),
+            "delegate of account {} spent more than its delegated amount",
+            source
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks the invariants of the universe, given its state before (`pre`) and
+/// after (`post`) an operation.
+fn check_invariants(pre: &[AccountState], post: &[AccountState]) -> Result<(), TestCaseError> {
+    for index in NON_NATIVE_MINT_INDICES {
+        let mint = Mint::unpack(&post[index].data).unwrap();
+        let balances = ACCOUNT_INDICES
+            .into_iter()
+            .filter_map(|index| token_account(post, index))
+            .filter(|account| account.mint == post[index].key)
+            .map(|account| account.amount as u128)
+            .sum::<u128>();
+
+        prop_assert_eq!(
+            balances,
+            mint.supply as u128,
+            "balances of mint {} differ from its supply",
+            index
+        );
+    }
+
+    for index in ACCOUNT_INDICES {
+        let Some(account) = token_account(post, index) else {
+            continue;
+        };
+
+        if account.delegate.is_none() {
+            prop_assert_eq!(
+                account.delegated_amount,
+                0,
+                "account {} has a delegated amount without delegate",
+                index
+            );
+        }
+
+        if let COption::Some(rent_exempt_reserve) = account.is_native {
+            prop_assert_eq!(
+                rent_exempt_reserve,
+                model::minimum_balance(Account::LEN),
+                "rent-exempt reserve of account {} changed",
+                index
+            );
+            prop_assert!(
+                post[index].lamports as u128
+                    >= rent_exempt_reserve as u128 + account.amount as u128,
+                "native account {} does not keep its rent-exempt reserve",
+                index
+            );
+        }
+
+        if let Some(previous) = token_account(pre, index) {
+            if previous.state == TokenAccountState::Frozen {
+                // Native accounts can still be synchronized with their lamports.
+                let synced = account.is_native.is_some()
+                    && account.amount as u128
+                        == post[index].lamports as u128
+                            - account.is_native.unwrap_or_default() as u128;
+
+                prop_assert!(
+                    account.amount == previous.amount || synced,
+                    "balance of frozen account {} changed",
+                    index
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
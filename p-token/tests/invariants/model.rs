@@ -0,0 +1,732 @@
+//! Model of the universe of accounts and the operations of a test sequence.
+//!
+//! The universe has a fixed layout: two mints (one controlled by a wallet and
+//! the other by a multisig), the native mint, token accounts of each mint,
+//! wallets and a multisig. It is initialized through `p-token` itself and
+//! operations reference accounts by their index in the universe.
+
+use {
+    crate::runtime::{process_p_token, AccountState},
+    proptest::{
+        collection::vec,
+        prelude::*,
+        sample::{select, subsequence},
+    },
+    solana_instruction::Instruction,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    spl_token::{
+        instruction, native_mint,
+        state::{Account, Mint, Multisig},
+    },
+};
+
+/// Number of accounts in the universe.
+const UNIVERSE_LEN: usize = 14;
+
+/// Indices of the mints (including the native mint) in the universe.
+const MINT_INDICES: [usize; 3] = [0, 1, NATIVE_MINT_INDEX];
+
+/// Indices of the mints that are not the native mint.
+pub const NON_NATIVE_MINT_INDICES: [usize; 2] = [0, 1];
+
+/// Index of the native mint in the universe.
+const NATIVE_MINT_INDEX: usize = 2;
+
+/// Indices of the token accounts in the universe.
+pub const ACCOUNT_INDICES: [usize; 6] = [3, 4, 5, 6, 7, 8];
+
+/// Mint and owner of each token account.
+const ACCOUNTS: [(usize, usize); 6] = [
+    (0, 9),
+    (0, MULTISIG_INDEX),
+    (1, 10),
+    (1, 9),
+    (NATIVE_MINT_INDEX, 9),
+    (NATIVE_MINT_INDEX, 10),
+];
+
+/// Indices of the wallets in the universe, which are always signers.
+const WALLET_INDICES: [usize; 3] = [9, 10, 11];
+
+/// Index of the multisig in the universe, a 2-of-3 of the wallets.
+const MULTISIG_INDEX: usize = 12;
+
+/// Index of the rent sysvar in the universe.
+const RENT_INDEX: usize = 13;
+
+/// Decimals of each mint.
+const DECIMALS: [u8; 3] = [2, 6, 9];
+
+/// Mint authority of each mint.
+const MINT_AUTHORITIES: [usize; 3] = [9, MULTISIG_INDEX, 9];
+
+/// Freeze authority of each mint, except the native mint which has none.
+const FREEZE_AUTHORITIES: [usize; 3] = [11, MULTISIG_INDEX, 11];
+
+/// Initial lamports of each wallet.
+const WALLET_LAMPORTS: u64 = 1_000_000_000;
+
+/// Initial token amount of the native accounts.
+const NATIVE_AMOUNT: u64 = 1_000;
+
+/// Returns the key of the account at `index` in the universe.
+pub fn key(index: usize) -> Pubkey {
+    match index {
+        NATIVE_MINT_INDEX => native_mint::id(),
+        RENT_INDEX => solana_rent::sysvar::id(),
+        _ => Pubkey::new_from_array([index as u8 + 1; 32]),
+    }
+}
+
+/// Returns the minimum balance for an account to be rent exempt.
+pub fn minimum_balance(data_len: usize) -> u64 {
+    solana_rent::Rent::default().minimum_balance(data_len)
+}
+
+/// Returns the index of the mint of the token account at `index`.
+fn mint_of(index: usize) -> usize {
+    ACCOUNTS[index - ACCOUNT_INDICES[0]].0
+}
+
+/// Instruction data and accounts, referencing accounts of the universe by
+/// index.
+#[derive(Clone, Debug)]
+pub struct Ix {
+    pub data: Vec<u8>,
+    pub accounts: Vec<usize>,
+}
+
+impl From<Instruction> for Ix {
+    fn from(instruction: Instruction) -> Self {
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                (0..UNIVERSE_LEN)
+                    .position(|index| key(index) == meta.pubkey)
+                    .expect("account of the universe")
+            })
+            .collect();
+
+        Self {
+            data: instruction.data,
+            accounts,
+        }
+    }
+}
+
+/// Returns the initialized universe.
+///
+/// Accounts are initialized by executing the initialization instructions on
+/// `p-token`.
+pub fn universe() -> Vec<AccountState> {
+    let mut universe = (0..UNIVERSE_LEN)
+        .map(|index| {
+            let (owner, lamports, data_len) = match index {
+                _ if MINT_INDICES.contains(&index) => {
+                    (spl_token::ID, minimum_balance(Mint::LEN), Mint::LEN)
+                }
+                _ if ACCOUNT_INDICES.contains(&index) => {
+                    let mut lamports = minimum_balance(Account::LEN);
+                    if mint_of(index) == NATIVE_MINT_INDEX {
+                        lamports += NATIVE_AMOUNT;
+                    }
+                    (spl_token::ID, lamports, Account::LEN)
+                }
+                MULTISIG_INDEX => (spl_token::ID, minimum_balance(Multisig::LEN), Multisig::LEN),
+                RENT_INDEX => (
+                    Pubkey::from_str_const("Sysvar1111111111111111111111111111111111111"),
+                    1,
+                    0,
+                ),
+                _ => (Pubkey::default(), WALLET_LAMPORTS, 0),
+            };
+
+            AccountState {
+                key: key(index),
+                owner,
+                lamports,
+                data: vec![0; data_len],
+                is_signer: WALLET_INDICES.contains(&index),
+                is_writable: index != RENT_INDEX,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    universe[RENT_INDEX].data = rent_data();
+
+    let mut instructions = vec![instruction::initialize_multisig(
+        &spl_token::ID,
+        &key(MULTISIG_INDEX),
+        &WALLET_INDICES.map(key).iter().collect::<Vec<_>>(),
+        2,
+    )];
+
+    for index in MINT_INDICES {
+        let freeze_authority = key(FREEZE_AUTHORITIES[index]);
+        instructions.push(instruction::initialize_mint(
+            &spl_token::ID,
+            &key(index),
+            &key(MINT_AUTHORITIES[index]),
+            (index != NATIVE_MINT_INDEX).then_some(&freeze_authority),
+            DECIMALS[index],
+        ));
+    }
+
+    for (index, (mint, owner)) in ACCOUNT_INDICES.into_iter().zip(ACCOUNTS) {
+        instructions.push(instruction::initialize_account(
+            &spl_token::ID,
+            &key(index),
+            &key(mint),
+            &key(owner),
+        ));
+    }
+
+    for instruction in instructions {
+        let ix = Ix::from(instruction.unwrap());
+        process_p_token(&mut universe, &ix.accounts, &ix.data).expect("initialization");
+    }
+
+    universe
+}
+
+/// Serialized `Rent` sysvar with the default values.
+fn rent_data() -> Vec<u8> {
+    let rent = solana_rent::Rent::default();
+    let mut data = Vec::with_capacity(17);
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+/// Authority signing an operation.
+#[derive(Clone, Debug)]
+pub enum Authority {
+    /// A wallet.
+    Wallet(usize),
+
+    /// The multisig, with the wallets that sign for it.
+    Multisig(Vec<usize>),
+}
+
+impl Authority {
+    pub fn key(&self) -> Pubkey {
+        match self {
+            Authority::Wallet(index) => key(*index),
+            Authority::Multisig(_) => key(MULTISIG_INDEX),
+        }
+    }
+
+    fn signers(&self) -> Vec<Pubkey> {
+        match self {
+            Authority::Wallet(_) => Vec::new(),
+            Authority::Multisig(signers) => signers.iter().copied().map(key).collect(),
+        }
+    }
+}
+
+/// Operation of a test sequence.
+#[derive(Clone, Debug)]
+pub enum Op {
+    Transfer {
+        source: usize,
+        destination: usize,
+        authority: Authority,
+        amount: u64,
+        checked: bool,
+    },
+    Approve {
+        source: usize,
+        delegate: usize,
+        authority: Authority,
+        amount: u64,
+        checked: bool,
+    },
+    Revoke {
+        source: usize,
+        authority: Authority,
+    },
+    MintTo {
+        mint: usize,
+        destination: usize,
+        authority: Authority,
+        amount: u64,
+        checked: bool,
+    },
+    Burn {
+        account: usize,
+        authority: Authority,
+        amount: u64,
+        checked: bool,
+    },
+    Freeze {
+        account: usize,
+        authority: Authority,
+        thaw: bool,
+    },
+    Close {
+        account: usize,
+        destination: usize,
+        authority: Authority,
+    },
+    SyncNative {
+        account: usize,
+    },
+    TransferMany {
+        source: usize,
+        destinations: Vec<(usize, u64)>,
+        authority: Authority,
+    },
+    MintToMany {
+        mint: usize,
+        destinations: Vec<(usize, u64)>,
+        authority: Authority,
+    },
+    /// A `Batch` instruction.
+    Batch(Vec<Op>),
+
+    /// A system transfer of lamports from a wallet to an account, which does
+    /// not involve the token program.
+    Deposit {
+        account: usize,
+        lamports: u64,
+    },
+}
+
+impl Op {
+    /// Returns the instruction of the operation, or `None` for operations
+    /// that do not involve the token program.
+    pub fn instruction(&self) -> Option<Ix> {
+        let id = &spl_token::ID;
+
+        let instruction = match self {
+            Op::Transfer {
+                source,
+                destination,
+                authority,
+                amount,
+                checked: false,
+            } => instruction::transfer(
+                id,
+                &key(*source),
+                &key(*destination),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+            ),
+            Op::Transfer {
+                source,
+                destination,
+                authority,
+                amount,
+                checked: true,
+            } => instruction::transfer_checked(
+                id,
+                &key(*source),
+                &key(mint_of(*source)),
+                &key(*destination),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+                DECIMALS[mint_of(*source)],
+            ),
+            Op::Approve {
+                source,
+                delegate,
+                authority,
+                amount,
+                checked: false,
+            } => instruction::approve(
+                id,
+                &key(*source),
+                &key(*delegate),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+            ),
+            Op::Approve {
+                source,
+                delegate,
+                authority,
+                amount,
+                checked: true,
+            } => instruction::approve_checked(
+                id,
+                &key(*source),
+                &key(mint_of(*source)),
+                &key(*delegate),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+                DECIMALS[mint_of(*source)],
+            ),
+            Op::Revoke { source, authority } => instruction::revoke(
+                id,
+                &key(*source),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+            ),
+            Op::MintTo {
+                mint,
+                destination,
+                authority,
+                amount,
+                checked: false,
+            } => instruction::mint_to(
+                id,
+                &key(*mint),
+                &key(*destination),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+            ),
+            Op::MintTo {
+                mint,
+                destination,
+                authority,
+                amount,
+                checked: true,
+            } => instruction::mint_to_checked(
+                id,
+                &key(*mint),
+                &key(*destination),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+                DECIMALS[*mint],
+            ),
+            Op::Burn {
+                account,
+                authority,
+                amount,
+                checked: false,
+            } => instruction::burn(
+                id,
+                &key(*account),
+                &key(mint_of(*account)),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+            ),
+            Op::Burn {
+                account,
+                authority,
+                amount,
+                checked: true,
+            } => instruction::burn_checked(
+                id,
+                &key(*account),
+                &key(mint_of(*account)),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+                *amount,
+                DECIMALS[mint_of(*account)],
+            ),
+            Op::Freeze {
+                account,
+                authority,
+                thaw,
+            } => {
+                let process = if *thaw {
+                    instruction::thaw_account
+                } else {
+                    instruction::freeze_account
+                };
+                process(
+                    id,
+                    &key(*account),
+                    &key(mint_of(*account)),
+                    &authority.key(),
+                    &authority.signers().iter().collect::<Vec<_>>(),
+                )
+            }
+            Op::Close {
+                account,
+                destination,
+                authority,
+            } => instruction::close_account(
+                id,
+                &key(*account),
+                &key(*destination),
+                &authority.key(),
+                &authority.signers().iter().collect::<Vec<_>>(),
+            ),
+            Op::SyncNative { account } => instruction::sync_native(id, &key(*account)),
+            Op::TransferMany {
+                source,
+                destinations,
+                authority,
+            } => {
+                // TransferMany: source, mint, authority, destinations and signers.
+                let mut accounts = vec![*source, mint_of(*source), authority_index(authority)];
+                accounts.extend(destinations.iter().map(|(destination, _)| *destination));
+                accounts.extend(authority_signers(authority));
+
+                return Some(Ix {
+                    data: many_data(252, DECIMALS[mint_of(*source)], destinations),
+                    accounts,
+                });
+            }
+            Op::MintToMany {
+                mint,
+                destinations,
+                authority,
+            } => {
+                // MintToMany: mint, authority, destinations and signers.
+                let mut accounts = vec![*mint, authority_index(authority)];
+                accounts.extend(destinations.iter().map(|(destination, _)| *destination));
+                accounts.extend(authority_signers(authority));
+
+                return Some(Ix {
+                    data: many_data(251, DECIMALS[*mint], destinations),
+                    accounts,
+                });
+            }
+            Op::Batch(ops) => {
+                let mut data = vec![255];
+                let mut accounts = Vec::new();
+
+                for ix in ops.iter().filter_map(Op::instruction) {
+                    data.push(ix.accounts.len() as u8);
+                    data.push(ix.data.len() as u8);
+                    data.extend_from_slice(&ix.data);
+                    accounts.extend_from_slice(&ix.accounts);
+                }
+
+                return Some(Ix { data, accounts });
+            }
+            Op::Deposit { .. } => return None,
+        };
+
+        Some(Ix::from(instruction.unwrap()))
+    }
+}
+
+fn authority_index(authority: &Authority) -> usize {
+    match authority {
+        Authority::Wallet(index) => *index,
+        Authority::Multisig(_) => MULTISIG_INDEX,
+    }
+}
+
+fn authority_signers(authority: &Authority) -> Vec<usize> {
+    match authority {
+        Authority::Wallet(_) => Vec::new(),
+        Authority::Multisig(signers) => signers.clone(),
+    }
+}
+
+/// Instruction data of `TransferMany` and `MintToMany`.
+fn many_data(discriminator: u8, decimals: u8, destinations: &[(usize, u64)]) -> Vec<u8> {
+    let mut data = vec![discriminator, decimals];
+    destinations
+        .iter()
+        .for_each(|(_, amount)| data.extend_from_slice(&amount.to_le_bytes()));
+    data
+}
+
+fn account_strategy() -> impl Strategy<Value = usize> {
+    select(ACCOUNT_INDICES.to_vec())
+}
+
+/// Generates a token account, usually of the same mint as the account at
+/// `index`.
+fn related_account_strategy(index: usize) -> impl Strategy<Value = usize> {
+    let related = ACCOUNT_INDICES
+        .into_iter()
+        .filter(|other| mint_of(*other) == mint_of(index))
+        .collect::<Vec<_>>();
+
+    prop_oneof![
+        4 => select(related),
+        1 => account_strategy(),
+    ]
+}
+
+fn mint_strategy() -> impl Strategy<Value = usize> {
+    select(MINT_INDICES.to_vec())
+}
+
+/// Generates a token account, usually of the mint at `index`.
+fn mint_account_strategy(index: usize) -> impl Strategy<Value = usize> {
+    let accounts = ACCOUNT_INDICES
+        .into_iter()
+        .filter(|account| mint_of(*account) == index)
+        .collect::<Vec<_>>();
+
+    prop_oneof![
+        4 => select(accounts),
+        1 => account_strategy(),
+    ]
+}
+
+fn amount_strategy() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        6 => 0..100u64,
+        2 => 0..10_000u64,
+        1 => Just(u64::MAX),
+    ]
+}
+
+/// Generates the wallets signing for the multisig, usually enough of them.
+fn multisig_authority_strategy() -> impl Strategy<Value = Authority> {
+    prop_oneof![
+        3 => subsequence(WALLET_INDICES.to_vec(), 2..=WALLET_INDICES.len()),
+        1 => subsequence(WALLET_INDICES.to_vec(), 0..=WALLET_INDICES.len()),
+    ]
+    .prop_map(Authority::Multisig)
+}
+
+/// Generates an authority, usually the account at `index` (a wallet or the
+/// multisig) and otherwise a wallet, which might be a delegate.
+fn authority_strategy(index: usize) -> BoxedStrategy<Authority> {
+    let authority = if index == MULTISIG_INDEX {
+        multisig_authority_strategy().boxed()
+    } else {
+        Just(Authority::Wallet(index)).boxed()
+    };
+
+    prop_oneof![
+        6 => authority,
+        3 => select(WALLET_INDICES.to_vec()).prop_map(Authority::Wallet),
+        1 => multisig_authority_strategy(),
+    ]
+    .boxed()
+}
+
+/// Generates an authority, usually the owner of the account at `index`.
+fn owner_strategy(index: usize) -> BoxedStrategy<Authority> {
+    authority_strategy(ACCOUNTS[index - ACCOUNT_INDICES[0]].1)
+}
+
+fn destinations_strategy(
+    account: impl Strategy<Value = usize>,
+) -> impl Strategy<Value = Vec<(usize, u64)>> {
+    vec((account, amount_strategy()), 1..=3)
+}
+
+/// Generates an operation that can be included in a batch.
+///
+/// Closing accounts is excluded, since closed accounts keep their state
+/// outside of the SVM until the end of the instruction.
+fn batch_item_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => account_strategy().prop_flat_map(|source| (
+            Just(source),
+            related_account_strategy(source),
+            owner_strategy(source),
+            amount_strategy(),
+            any::<bool>(),
+        ))
+        .prop_map(|(source, destination, authority, amount, checked)| Op::Transfer {
+            source,
+            destination,
+            authority,
+            amount,
+            checked,
+        }),
+        2 => account_strategy().prop_flat_map(|source| (
+            Just(source),
+            prop_oneof![select(WALLET_INDICES.to_vec()), Just(MULTISIG_INDEX)],
+            owner_strategy(source),
+            amount_strategy(),
+            any::<bool>(),
+        ))
+        .prop_map(|(source, delegate, authority, amount, checked)| Op::Approve {
+            source,
+            delegate,
+            authority,
+            amount,
+            checked,
+        }),
+        1 => account_strategy()
+            .prop_flat_map(|source| (Just(source), owner_strategy(source)))
+            .prop_map(|(source, authority)| Op::Revoke { source, authority }),
+        4 => mint_strategy().prop_flat_map(|mint| (
+            Just(mint),
+            mint_account_strategy(mint),
+            authority_strategy(MINT_AUTHORITIES[mint]),
+            amount_strategy(),
+            any::<bool>(),
+        ))
+        .prop_map(|(mint, destination, authority, amount, checked)| Op::MintTo {
+            mint,
+            destination,
+            authority,
+            amount,
+            checked,
+        }),
+        2 => account_strategy().prop_flat_map(|account| (
+            Just(account),
+            owner_strategy(account),
+            amount_strategy(),
+            any::<bool>(),
+        ))
+        .prop_map(|(account, authority, amount, checked)| Op::Burn {
+            account,
+            authority,
+            amount,
+            checked,
+        }),
+        1 => account_strategy().prop_flat_map(|account| (
+            Just(account),
+            authority_strategy(FREEZE_AUTHORITIES[mint_of(account)]),
+            any::<bool>(),
+        ))
+        .prop_map(|(account, authority, thaw)| Op::Freeze {
+            account,
+            authority,
+            thaw,
+        }),
+        1 => account_strategy().prop_map(|account| Op::SyncNative { account }),
+        2 => account_strategy().prop_flat_map(|source| (
+            Just(source),
+            destinations_strategy(related_account_strategy(source)),
+            owner_strategy(source),
+        ))
+        .prop_map(|(source, destinations, authority)| Op::TransferMany {
+            source,
+            destinations,
+            authority,
+        }),
+        2 => mint_strategy().prop_flat_map(|mint| (
+            Just(mint),
+            destinations_strategy(mint_account_strategy(mint)),
+            authority_strategy(MINT_AUTHORITIES[mint]),
+        ))
+        .prop_map(|(mint, destinations, authority)| Op::MintToMany {
+            mint,
+            destinations,
+            authority,
+        }),
+    ]
+}
+
+/// Generates an operation of a test sequence.
+pub fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        16 => batch_item_strategy(),
+        1 => account_strategy().prop_flat_map(|account| (
+            Just(account),
+            prop_oneof![select(WALLET_INDICES.to_vec()), account_strategy()],
+            owner_strategy(account),
+        ))
+        .prop_map(|(account, destination, authority)| Op::Close {
+            account,
+            destination,
+            authority,
+        }),
+        1 => (account_strategy(), 0..1_000_000u64)
+            .prop_map(|(account, lamports)| Op::Deposit { account, lamports }),
+        2 => vec(batch_item_strategy(), 1..=3).prop_map(Op::Batch),
+    ]
+}
+
+/// Transfers lamports from a wallet to the account at `index`, as a system
+/// transfer would.
+pub fn deposit(universe: &mut [AccountState], index: usize, lamports: u64) {
+    universe[WALLET_INDICES[0]].lamports -= lamports;
+    universe[index].lamports += lamports;
+}
@@ -0,0 +1,109 @@
+mod setup;
+
+use {
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+fn mint_to_many_instruction(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    destinations: &[(Pubkey, u64)],
+    decimals: u8,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    // Start with the mint to many discriminator and decimals
+    let mut data: Vec<u8> = vec![251, decimals];
+
+    for (destination, amount) in destinations {
+        accounts.push(AccountMeta::new(*destination, false));
+        data.extend_from_slice(&amount.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: spl_token::ID,
+        data,
+        accounts,
+    }
+}
+
+#[tokio::test]
+async fn mint_to_many() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And 3 token accounts.
+
+    let mut destinations = Vec::with_capacity(3);
+
+    for amount in [10, 20, 30] {
+        let destination = account::initialize(
+            &mut context,
+            &mint,
+            &Pubkey::new_unique(),
+            &TOKEN_PROGRAM_ID,
+        )
+        .await;
+        destinations.push((destination, amount));
+    }
+
+    // When we mint tokens to all of them.
+
+    let mint_ix = mint_to_many_instruction(&mint, &mint_authority.pubkey(), &destinations, 4);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint supply is updated with the total amount.
+
+    let mint = context.banks_client.get_account(mint).await.unwrap();
+
+    assert!(mint.is_some());
+
+    let mint = mint.unwrap();
+    let mint = spl_token::state::Mint::unpack(&mint.data).unwrap();
+
+    assert!(mint.supply == 60);
+
+    // And each account has the correct amount.
+
+    for (destination, amount) in destinations {
+        let destination = context.banks_client.get_account(destination).await.unwrap();
+
+        assert!(destination.is_some());
+
+        let destination = destination.unwrap();
+        let destination = spl_token::state::Account::unpack(&destination.data).unwrap();
+
+        assert!(destination.amount == amount);
+    }
+}
@@ -0,0 +1,121 @@
+//! Differential tests of the return data of the UI amount conversions.
+//!
+//! Return data is only available in the SVM, so `AmountToUiAmount` and
+//! `UiAmountToAmount` are executed on both programs with `mollusk` and the
+//! return data is compared byte-for-byte, together with the program result.
+//!
+//! Both programs need to be built first, since the tests load them from
+//! `SBF_OUT_DIR`:
+//!
+//! ```text
+//! pnpm programs:build
+//! pnpm p-token:build
+//! pnpm p-token:test --test return_data
+//! ```
+
+use {
+    mollusk_svm::Mollusk,
+    pinocchio_token_program as _,
+    proptest::prelude::*,
+    solana_account::Account as SolanaAccount,
+    solana_instruction::Instruction,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token::{instruction, state::Mint},
+};
+
+fn mint_account(decimals: u8) -> SolanaAccount {
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(
+        Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(Mint::LEN),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Executes `instruction` on both programs and compares the results.
+fn check(
+    p_token: &Mollusk,
+    spl_token: &Mollusk,
+    instruction: &Instruction,
+    accounts: &[(Pubkey, SolanaAccount)],
+) -> Result<(), TestCaseError> {
+    let expected = spl_token.process_instruction(instruction, accounts);
+    let result = p_token.process_instruction(instruction, accounts);
+
+    prop_assert_eq!(result.program_result, expected.program_result);
+    prop_assert_eq!(result.return_data, expected.return_data);
+
+    Ok(())
+}
+
+/// Decimals biased towards the boundaries of the conversion.
+fn decimals_strategy() -> impl Strategy<Value = u8> {
+    prop_oneof![0u8..=20, 20u8..=30, Just(u8::MAX), any::<u8>()]
+}
+
+/// UI amounts, mostly well-formed with a few arbitrary strings.
+fn ui_amount_strategy() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        4 => "[+]?[0-9]{0,25}(\\.[0-9]{0,30})?".prop_map(String::into_bytes),
+        1 => "[+0-9.]{0,8}".prop_map(String::into_bytes),
+        1 => proptest::collection::vec(any::<u8>(), 0..16),
+    ]
+}
+
+#[test]
+fn amount_to_ui_amount() {
+    let p_token = Mollusk::new(&spl_token::ID, "pinocchio_token_program");
+    let spl_token = Mollusk::new(&spl_token::ID, "spl_token");
+
+    proptest!(|(amount in any::<u64>(), decimals in decimals_strategy())| {
+        let mint = Pubkey::new_unique();
+        let instruction =
+            instruction::amount_to_ui_amount(&spl_token::ID, &mint, amount).unwrap();
+
+        check(
+            &p_token,
+            &spl_token,
+            &instruction,
+            &[(mint, mint_account(decimals))],
+        )?;
+    });
+}
+
+#[test]
+fn ui_amount_to_amount() {
+    let p_token = Mollusk::new(&spl_token::ID, "pinocchio_token_program");
+    let spl_token = Mollusk::new(&spl_token::ID, "spl_token");
+
+    proptest!(|(ui_amount in ui_amount_strategy(), decimals in decimals_strategy())| {
+        let mint = Pubkey::new_unique();
+        // The builder only accepts `&str`, so the instruction data is written
+        // directly to also cover invalid UTF-8.
+        let mut instruction =
+            instruction::ui_amount_to_amount(&spl_token::ID, &mint, "").unwrap();
+        instruction.data.extend_from_slice(&ui_amount);
+
+        check(
+            &p_token,
+            &spl_token,
+            &instruction,
+            &[(mint, mint_account(decimals))],
+        )?;
+    });
+}
@@ -0,0 +1,182 @@
+mod setup;
+
+use {
+    pinocchio_token_interface::instruction::TokenInstruction,
+    setup::TOKEN_PROGRAM_ID,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
+    solana_transaction::Transaction,
+    spl_token::state::Multisig,
+};
+
+/// Creates a `SetMultisigSigners` instruction.
+fn set_multisig_signers(
+    multisig: &Pubkey,
+    new_signers: &[Pubkey],
+    m: u8,
+    signers: &[Pubkey],
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: [AccountMeta::new(*multisig, false)]
+            .into_iter()
+            .chain(
+                new_signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(*signer, false)),
+            )
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(*signer, true)),
+            )
+            .collect(),
+        data: vec![
+            TokenInstruction::SetMultisigSigners as u8,
+            m,
+            new_signers.len() as u8,
+        ],
+    }
+}
+
+#[tokio::test]
+async fn set_multisig_signers_rotates_signers() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let multisig = Keypair::new();
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(Keypair::pubkey).collect::<Vec<_>>();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        spl_token::instruction::initialize_multisig2(
+            &spl_token::ID,
+            &multisig.pubkey(),
+            &signer_keys.iter().collect::<Vec<_>>(),
+            2,
+        )
+        .unwrap(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When 2 of the current signers replace the signers with a 1 of 2
+    // multisig.
+
+    let new_signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_multisig_signers(
+            &multisig.pubkey(),
+            &new_signers,
+            1,
+            &signer_keys[1..],
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[1], &signers[2]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the multisig has the new signers.
+
+    let account = context
+        .banks_client
+        .get_account(multisig.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let multisig = Multisig::unpack(&account.data).unwrap();
+
+    assert!(multisig.is_initialized);
+    assert_eq!(multisig.m, 1);
+    assert_eq!(multisig.n, 2);
+    assert_eq!(multisig.signers[..2], new_signers);
+    assert!(multisig.signers[2..]
+        .iter()
+        .all(|signer| *signer == Pubkey::default()));
+}
+
+#[tokio::test]
+async fn set_multisig_signers_requires_m_signers() {
+    let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let multisig = Keypair::new();
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(Keypair::pubkey).collect::<Vec<_>>();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        spl_token::instruction::initialize_multisig2(
+            &spl_token::ID,
+            &multisig.pubkey(),
+            &signer_keys.iter().collect::<Vec<_>>(),
+            2,
+        )
+        .unwrap(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a single signer tries to replace the signers.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_multisig_signers(
+            &multisig.pubkey(),
+            &[signer_keys[0]],
+            1,
+            &signer_keys[..1],
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[0]],
+        context.last_blockhash,
+    );
+
+    // Then the transaction fails.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
@@ -1,7 +1,14 @@
 use {
-    solana_keypair::Keypair, solana_program_test::ProgramTestContext, solana_pubkey::Pubkey,
-    solana_signer::Signer, solana_system_interface::instruction::create_account,
+    pinocchio_token_interface::instruction::TokenInstruction,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack_v3::Pack,
+    solana_program_test::ProgramTestContext,
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
     solana_transaction::Transaction,
+    spl_token_interface::state::{DelegationRecord, Expiry},
 };
 
 pub async fn initialize(
@@ -70,6 +77,65 @@ pub async fn approve(
     context.banks_client.process_transaction(tx).await.unwrap();
 }
 
+/// Approves `delegate` with an expiry, returning the key of the new delegation
+/// record.
+#[allow(clippy::too_many_arguments)]
+pub async fn approve_with_expiry(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Keypair,
+    amount: u64,
+    decimals: u8,
+    expiry: Expiry,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let delegation_record = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let (expiry_kind, expiry) = expiry.pack();
+
+    let approve_ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(delegation_record.pubkey(), false),
+            AccountMeta::new_readonly(*delegate, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data: [
+            &[TokenInstruction::ApproveWithExpiry as u8][..],
+            &amount.to_le_bytes(),
+            &[decimals, expiry_kind],
+            &expiry,
+        ]
+        .concat(),
+    };
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &delegation_record.pubkey(),
+            rent.minimum_balance(DelegationRecord::LEN),
+            DelegationRecord::LEN as u64,
+            program_id,
+        ),
+        approve_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegation_record, owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    delegation_record.pubkey()
+}
+
 pub async fn freeze(
     context: &mut ProgramTestContext,
     account: &Pubkey,
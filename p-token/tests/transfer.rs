@@ -1,15 +1,114 @@
 mod setup;
 
 use {
+    assert_matches::assert_matches,
+    pinocchio_token_interface::{
+        instruction::TokenInstruction,
+        state::{multisig::LargeMultisig, Transmutable},
+    },
     setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
     solana_keypair::Keypair,
     solana_program_pack::Pack,
-    solana_program_test::{tokio, ProgramTest},
+    solana_program_pack_v3::Pack as _,
+    solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext},
     solana_pubkey::Pubkey,
     solana_signer::Signer,
+    solana_system_interface::instruction::create_account,
     solana_transaction::Transaction,
+    solana_transaction_error::TransactionError,
+    spl_token::state::Multisig,
+    spl_token_interface::{instruction::transfer_checked_with_plan, signer_plan::SignerPlan},
 };
 
+/// Creates and initializes a multisig account with the given signers.
+async fn initialize_multisig(
+    context: &mut ProgramTestContext,
+    signers: &[&Pubkey],
+    m: u8,
+) -> Pubkey {
+    let multisig = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        spl_token::instruction::initialize_multisig2(
+            &spl_token::ID,
+            &multisig.pubkey(),
+            signers,
+            m,
+        )
+        .unwrap(),
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    multisig.pubkey()
+}
+
+/// Creates and initializes a large multisig account with the given signers.
+async fn initialize_large_multisig(
+    context: &mut ProgramTestContext,
+    signers: &[&Pubkey],
+    m: u8,
+    allow_nested: bool,
+) -> Pubkey {
+    let multisig = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let initialize_ix = Instruction {
+        program_id: TOKEN_PROGRAM_ID,
+        accounts: [AccountMeta::new(multisig.pubkey(), false)]
+            .into_iter()
+            .chain(
+                signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(**signer, false)),
+            )
+            .collect(),
+        data: vec![
+            TokenInstruction::InitializeMultisig3 as u8,
+            m,
+            allow_nested as u8,
+        ],
+    };
+
+    let instructions = vec![
+        create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(LargeMultisig::LEN),
+            LargeMultisig::LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    multisig.pubkey()
+}
+
 #[tokio::test]
 async fn transfer() {
     let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
@@ -84,3 +183,315 @@ async fn transfer() {
 
     assert!(account.amount == 0);
 }
+
+#[tokio::test]
+async fn transfer_with_nested_multisig_owner() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 1 of 2 team multisig, member of a 2 of 2 treasury multisig that
+    // allows nested members.
+
+    let team_signers = [Keypair::new(), Keypair::new()];
+    let team = initialize_multisig(
+        &mut context,
+        &[&team_signers[0].pubkey(), &team_signers[1].pubkey()],
+        1,
+    )
+    .await;
+
+    let treasury_signer = Keypair::new();
+    let treasury =
+        initialize_large_multisig(&mut context, &[&treasury_signer.pubkey(), &team], 2, true).await;
+
+    // And a token account owned by the treasury with 100 tokens.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &treasury, &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When the tokens are transferred with the treasury signer and the team
+    // multisig, followed by one of its signers.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &destination, &TOKEN_PROGRAM_ID).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &destination_account,
+        &treasury,
+        &[&treasury_signer.pubkey(), &team, &team_signers[1].pubkey()],
+        100,
+    )
+    .unwrap();
+    // The team multisig does not sign.
+    transfer_ix.accounts[4].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &treasury_signer, &team_signers[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are transferred.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 0);
+}
+
+#[tokio::test]
+async fn transfer_checked_with_signer_plan() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 1 of 2 team multisig, member of a 2 of 2 treasury multisig that
+    // allows nested members.
+
+    let team_signers = [Keypair::new(), Keypair::new()];
+    let team = initialize_multisig(
+        &mut context,
+        &[&team_signers[0].pubkey(), &team_signers[1].pubkey()],
+        1,
+    )
+    .await;
+
+    let treasury_signer = Keypair::new();
+    let treasury =
+        initialize_large_multisig(&mut context, &[&treasury_signer.pubkey(), &team], 2, true).await;
+
+    // And a token account owned by the treasury with 100 tokens.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &treasury, &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When the signers are planned from the multisig accounts, with the team
+    // multisig authorizing through one of its signers.
+
+    let treasury_state = context
+        .banks_client
+        .get_account(treasury)
+        .await
+        .unwrap()
+        .unwrap();
+    let treasury_state =
+        spl_token_interface::state::LargeMultisig::unpack(&treasury_state.data).unwrap();
+
+    let team_state = context
+        .banks_client
+        .get_account(team)
+        .await
+        .unwrap()
+        .unwrap();
+    let team_state = spl_token_interface::state::Multisig::unpack(&team_state.data).unwrap();
+
+    // The treasury signer and the team multisig are available, as well as the
+    // second team signer.
+    let [treasury_signer_key, team_key] = [treasury_state.signers[0], treasury_state.signers[1]];
+
+    let mut plan =
+        SignerPlan::new_large(&treasury_state, &[treasury_signer_key, team_key]).unwrap();
+    plan.nest(
+        &team_key,
+        SignerPlan::new(&team_state, &team_state.signers[1..2]).unwrap(),
+    )
+    .unwrap();
+
+    assert!(plan.is_complete());
+
+    // And the tokens are transferred with an instruction built from the plan.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &destination, &TOKEN_PROGRAM_ID).await;
+
+    let plan_ix = transfer_checked_with_plan(
+        &spl_token_interface::id(),
+        &account.to_bytes().into(),
+        &mint.to_bytes().into(),
+        &destination_account.to_bytes().into(),
+        &treasury.to_bytes().into(),
+        &plan,
+        100,
+        4,
+    )
+    .unwrap();
+    // The interface uses newer SDK types, so the instruction is converted.
+    let transfer_ix = Instruction {
+        program_id: plan_ix.program_id.to_bytes().into(),
+        accounts: plan_ix
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey.to_bytes().into(),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: plan_ix.data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &treasury_signer, &team_signers[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are transferred.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 0);
+}
+
+#[tokio::test]
+async fn fail_transfer_with_nested_multisig_owner_not_allowed() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 1 of 2 team multisig, member of a 2 of 2 treasury multisig that
+    // does not allow nested members.
+
+    let team_signers = [Keypair::new(), Keypair::new()];
+    let team = initialize_multisig(
+        &mut context,
+        &[&team_signers[0].pubkey(), &team_signers[1].pubkey()],
+        1,
+    )
+    .await;
+
+    let treasury_signer = Keypair::new();
+    let treasury =
+        initialize_large_multisig(&mut context, &[&treasury_signer.pubkey(), &team], 2, false)
+            .await;
+
+    // And a token account owned by the treasury with 100 tokens.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &treasury, &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // When the tokens are transferred with the treasury signer and the team
+    // multisig, followed by one of its signers.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &destination, &TOKEN_PROGRAM_ID).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &destination_account,
+        &treasury,
+        &[&treasury_signer.pubkey(), &team, &team_signers[1].pubkey()],
+        100,
+    )
+    .unwrap();
+    // The team multisig does not sign.
+    transfer_ix.accounts[4].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &treasury_signer, &team_signers[1]],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the team multisig cannot authorize without signing.
+
+    assert_matches!(
+        error,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::MissingRequiredSignature
+        ))
+    );
+}
@@ -0,0 +1,198 @@
+mod setup;
+
+use {
+    setup::{account, mint, TOKEN_PROGRAM_ID},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::Keypair,
+    solana_program_pack::Pack,
+    solana_program_test::{tokio, ProgramTest},
+    solana_pubkey::Pubkey,
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+fn transfer_many_instruction(
+    source: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    destinations: &[(Pubkey, u64)],
+    decimals: u8,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*source, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    // Start with the transfer many discriminator and decimals
+    let mut data: Vec<u8> = vec![252, decimals];
+
+    for (destination, amount) in destinations {
+        accounts.push(AccountMeta::new(*destination, false));
+        data.extend_from_slice(&amount.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: spl_token::ID,
+        data,
+        accounts,
+    }
+}
+
+#[tokio::test]
+async fn transfer_many() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And 3 destination token accounts.
+
+    let mut destinations = Vec::with_capacity(3);
+
+    for amount in [10, 20, 30] {
+        let destination = account::initialize(
+            &mut context,
+            &mint,
+            &Pubkey::new_unique(),
+            &TOKEN_PROGRAM_ID,
+        )
+        .await;
+        destinations.push((destination, amount));
+    }
+
+    // When we transfer the tokens to all destinations.
+
+    let transfer_many_ix =
+        transfer_many_instruction(&account, &mint, &owner.pubkey(), &destinations, 4);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_many_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source account is debited with the total amount.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 40);
+
+    // And each destination account is credited with its amount.
+
+    for (destination, amount) in destinations {
+        let destination = context.banks_client.get_account(destination).await.unwrap();
+
+        assert!(destination.is_some());
+
+        let destination = destination.unwrap();
+        let destination = spl_token::state::Account::unpack(&destination.data).unwrap();
+
+        assert!(destination.amount == amount);
+    }
+}
+
+#[tokio::test]
+async fn fail_transfer_many_with_insufficient_funds() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    let destination = account::initialize(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        &TOKEN_PROGRAM_ID,
+    )
+    .await;
+
+    // When the total amount exceeds the source account amount, even though
+    // each individual amount does not.
+
+    let transfer_many_ix = transfer_many_instruction(
+        &account,
+        &mint,
+        &owner.pubkey(),
+        &[(destination, 60), (destination, 60)],
+        4,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_many_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    // Then the transfer fails.
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
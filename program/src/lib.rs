@@ -36,70 +36,107 @@ pub mod solana_program {
         pub use solana_pubkey::{Pubkey, PUBKEY_BYTES};
     }
 }
-use solana_program_error::ProgramError;
 // Re-export spl_token_interface items
 pub use spl_token_interface::{check_id, check_program_account, id, ID};
+use {pinocchio_token_interface::ui_amount::UiAmount, solana_program_error::ProgramError};
 
 /// Convert the UI representation of a token amount (using the decimals field
 /// defined in its mint) to the raw amount
+///
+/// Digits beyond the precision of the mint are truncated, negative amounts
+/// are converted to zero and amounts that overflow saturate to `u64::MAX`.
 pub fn ui_amount_to_amount(ui_amount: f64, decimals: u8) -> u64 {
-    (ui_amount * 10_usize.pow(decimals as u32) as f64) as u64
+    if ui_amount.is_nan() || ui_amount <= 0.0 {
+        return 0;
+    }
+
+    // The shortest decimal representation of the float, which is parsed back
+    // to the same float.
+    let ui_amount = ui_amount.to_string();
+    let ui_amount = match ui_amount.split_once('.') {
+        Some((integer, fraction)) if fraction.len() > decimals as usize => {
+            format!("{integer}.{}", &fraction[..decimals as usize])
+        }
+        _ => ui_amount,
+    };
+
+    ui_amount
+        .parse::<UiAmount>()
+        .ok()
+        .and_then(|ui_amount| ui_amount.to_amount(decimals))
+        .unwrap_or(u64::MAX)
 }
 
 /// Convert a raw amount to its UI representation (using the decimals field
 /// defined in its mint)
 pub fn amount_to_ui_amount(amount: u64, decimals: u8) -> f64 {
-    amount as f64 / 10_usize.pow(decimals as u32) as f64
+    // Parsing the exact decimal representation rounds to the nearest float.
+    amount_to_ui_amount_string(amount, decimals)
+        .parse()
+        .unwrap()
 }
 
 /// Convert a raw amount to its UI representation (using the decimals field
 /// defined in its mint)
 pub fn amount_to_ui_amount_string(amount: u64, decimals: u8) -> String {
-    let decimals = decimals as usize;
-    if decimals > 0 {
-        // Left-pad zeros to decimals + 1, so we at least have an integer zero
-        let mut s = format!("{:01$}", amount, decimals + 1);
-        // Add the decimal point (Sorry, "," locales!)
-        s.insert(s.len() - decimals, '.');
-        s
-    } else {
-        amount.to_string()
-    }
+    UiAmount::new(amount, decimals).to_string()
 }
 
 /// Convert a raw amount to its UI representation using the given decimals field
 /// Excess zeroes or unneeded decimal point are trimmed.
 pub fn amount_to_ui_amount_string_trimmed(amount: u64, decimals: u8) -> String {
-    let mut s = amount_to_ui_amount_string(amount, decimals);
-    if decimals > 0 {
-        let zeros_trimmed = s.trim_end_matches('0');
-        s = zeros_trimmed.trim_end_matches('.').to_string();
-    }
-    s
+    format!("{:#}", UiAmount::new(amount, decimals))
 }
 
 /// Try to convert a UI representation of a token amount to its raw amount using
 /// the given decimals field
 pub fn try_ui_amount_into_amount(ui_amount: String, decimals: u8) -> Result<u64, ProgramError> {
-    let decimals = decimals as usize;
-    let mut parts = ui_amount.split('.');
-    // splitting a string, even an empty one, will always yield an iterator of
-    // at least length == 1
-    let mut amount_str = parts.next().unwrap().to_string();
-    let after_decimal = parts.next().unwrap_or("");
-    let after_decimal = after_decimal.trim_end_matches('0');
-    if (amount_str.is_empty() && after_decimal.is_empty())
-        || parts.next().is_some()
-        || after_decimal.len() > decimals
-    {
-        return Err(ProgramError::InvalidArgument);
+    ui_amount
+        .parse::<UiAmount>()
+        .ok()
+        .and_then(|ui_amount| ui_amount.to_amount(decimals))
+        .ok_or(ProgramError::InvalidArgument)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_amount_to_amount() {
+        assert_eq!(ui_amount_to_amount(0.29, 2), 29);
+        assert_eq!(ui_amount_to_amount(1.239, 2), 123);
+        assert_eq!(ui_amount_to_amount(42.0, 0), 42);
+        assert_eq!(ui_amount_to_amount(1e-30, 30), 1);
+        assert_eq!(ui_amount_to_amount(-1.0, 2), 0);
+        assert_eq!(ui_amount_to_amount(f64::NAN, 2), 0);
+        assert_eq!(ui_amount_to_amount(1.0, 25), u64::MAX);
+        assert_eq!(ui_amount_to_amount(f64::INFINITY, 0), u64::MAX);
     }
 
-    amount_str.push_str(after_decimal);
-    for _ in 0..decimals.saturating_sub(after_decimal.len()) {
-        amount_str.push('0');
+    #[test]
+    fn test_amount_to_ui_amount() {
+        assert_eq!(amount_to_ui_amount(29, 2), 0.29);
+        assert_eq!(amount_to_ui_amount(1, 30), 1e-30);
+        assert_eq!(amount_to_ui_amount(u64::MAX, 0), u64::MAX as f64);
+
+        assert_eq!(amount_to_ui_amount_string(1, 30).len(), 32);
+        assert_eq!(
+            amount_to_ui_amount_string_trimmed(1, 30),
+            "0.000000000000000000000000000001"
+        );
+        assert_eq!(
+            try_ui_amount_into_amount("0.000000000000000000000000000001".to_string(), 30),
+            Ok(1)
+        );
+        assert_eq!(
+            try_ui_amount_into_amount(u64::MAX.to_string(), 1),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(try_ui_amount_into_amount("+1.5".to_string(), 1), Ok(15));
+        assert_eq!(
+            try_ui_amount_into_amount("+".to_string(), 1),
+            Err(ProgramError::InvalidArgument)
+        );
     }
-    amount_str
-        .parse::<u64>()
-        .map_err(|_| ProgramError::InvalidArgument)
 }
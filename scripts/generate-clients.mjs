@@ -1,8 +1,13 @@
 #!/usr/bin/env zx
 import 'zx/globals';
-import { createFromRoot } from 'codama';
+import {
+  bottomUpTransformerVisitor,
+  createFromRoot,
+  definedTypeLinkNode,
+  isNode,
+} from 'codama';
 import { renderVisitor as renderJavaScriptVisitor } from '@codama/renderers-js';
-// import { renderVisitor as renderRustVisitor } from "@codama/renderers-rust";
+import { renderVisitor as renderRustVisitor } from '@codama/renderers-rust';
 import { workingDirectory } from './utils.mjs';
 
 // Instanciate Codama.
@@ -19,10 +24,27 @@ codama.accept(
 );
 
 // Render Rust.
-// const rustClient = path.join(__dirname, "..", "clients", "rust");
-// codama.accept(
-//   renderRustVisitor(path.join(rustClient, "src", "generated"), {
-//     formatCode: true,
-//     crateFolder: rustClient,
-//   })
-// );
+//
+// Borsh cannot represent the `COption` fields of the accounts, which have a
+// `u32` prefix and a fixed size, so they use the hooked types of the client.
+const rustClient = path.join(__dirname, '..', 'clients', 'rust');
+codama.update(
+  bottomUpTransformerVisitor([
+    {
+      select: '[accountNode].[optionTypeNode]',
+      transform: (node) =>
+        isNode(node.item, 'publicKeyTypeNode')
+          ? definedTypeLinkNode('cOptionPubkey')
+          : definedTypeLinkNode('cOptionU64'),
+    },
+  ])
+);
+codama.accept(
+  renderRustVisitor(path.join(rustClient, 'src', 'generated'), {
+    formatCode: true,
+    crateFolder: rustClient,
+    linkOverrides: {
+      definedTypes: { cOptionPubkey: 'hooked', cOptionU64: 'hooked' },
+    },
+  })
+);